@@ -2,10 +2,10 @@
 //! - Constants for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-08
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
@@ -13,3 +13,21 @@
 
 #[cfg(test)]
 pub mod test;
+
+/// The width and height of a cell in the SVG image of a grid search
+pub const DEFAULT_GRID_SEARCH_CELL_SIZE: f64 = 10.0;
+
+/// How much the heuristic weight is lowered after each anytime solution
+pub const DEFAULT_HEURISTIC_WEIGHT_DECREMENT: f64 = 0.5;
+
+/// The default number of sides of the polygon which approximates a full
+/// circle when inflating the visibility graph obstacles
+pub const DEFAULT_VISIBILITY_SIDE_COUNT: usize = 16;
+
+/// Relative tolerance when comparing a D* Lite key to the start key.
+/// Keys which are equal except for floating point rounding keep expanding.
+pub const D_STAR_LITE_KEY_TOLERANCE: f64 = 1e-9;
+
+/// Distance within which a point on the boundary of a visibility graph
+/// obstacle is considered outside of the obstacle
+pub const VISIBILITY_GRAPH_TOLERANCE: f64 = 1e-9;
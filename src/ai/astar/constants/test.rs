@@ -2,10 +2,10 @@
 //! - Constants for the A* algorithm unit tests
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-08
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
@@ -13,8 +13,14 @@
 
 #[cfg(test)]
 use crate::{
-  ai::astar::structures::{GradientCartographer, GridCartographer, NodeInfo},
+  ai::astar::structures::{
+    GradientCartographer, GridCartographer, HexCartographer,
+    JumpPointCartographer, NodeInfo, VoxelCartographer, VoxelConnectivity,
+    VoxelHeuristic,
+  },
+  math::geom::hex_coordinate::HexCoordinate,
   math::geom::point_2dd::Point2DD,
+  math::geom::point_3dd::Point3DD,
   math::geom::rectangle::Rectangle,
 };
 
@@ -48,6 +54,18 @@ pub const TEST_GOAL_NODE_INFO: NodeInfo = NodeInfo {
   total_cost: 0.0,
 };
 
+#[cfg(test)]
+pub const TEST_HEX_BLOCKED_NODE: HexCoordinate = HexCoordinate {
+  q: 1,
+  r: 0,
+};
+
+#[cfg(test)]
+pub const TEST_HEX_GOAL_NODE: HexCoordinate = HexCoordinate {
+  q: 3,
+  r: -1,
+};
+
 #[cfg(test)]
 pub const TEST_NODE_FACTORY: Point2DD = Point2DD {
   x: 0.0,
@@ -97,5 +115,48 @@ pub const TEST_SUBJECT_GRID_CARTOGRAPHER: GridCartographer<Point2DD> =
     step_size: TEST_STEP_SIZE,
   };
 
+#[cfg(test)]
+pub const TEST_SUBJECT_HEX_CARTOGRAPHER: HexCartographer = HexCartographer {
+  goal_node: TEST_HEX_GOAL_NODE,
+  is_space_available_fn: |hex_coordinate| {
+    *hex_coordinate != TEST_HEX_BLOCKED_NODE
+      && hex_coordinate.distance_to(&HexCoordinate::default())
+        <= TEST_DISTANCE_TO_BORDER as i64
+  },
+};
+
+#[cfg(test)]
+pub const TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER: JumpPointCartographer<
+  Point2DD,
+> = JumpPointCartographer {
+  goal_node: TEST_GOAL_NODE,
+  is_space_available_fn: |p| TEST_SPACE_TESTER.contains(p),
+  jump_limit: 100,
+  make_node_fn: Point2DD::new,
+  step_size: TEST_STEP_SIZE,
+};
+
+#[cfg(test)]
+pub const TEST_SUBJECT_VOXEL_CARTOGRAPHER: VoxelCartographer<Point3DD> =
+  VoxelCartographer {
+    goal_node: TEST_VOXEL_GOAL_NODE,
+    is_space_available_fn: |p| {
+      p.x.abs() <= TEST_DISTANCE_TO_BORDER
+        && p.y.abs() <= TEST_DISTANCE_TO_BORDER
+        && p.z.abs() <= TEST_DISTANCE_TO_BORDER
+    },
+    make_node_fn: Point3DD::new,
+    step_size: TEST_STEP_SIZE,
+    voxel_connectivity: VoxelConnectivity::TwentySix,
+    voxel_heuristic: VoxelHeuristic::Octile,
+  };
+
 #[cfg(test)]
 pub const TEST_TOLERANCE: f64 = 0.01;
+
+#[cfg(test)]
+pub const TEST_VOXEL_GOAL_NODE: Point3DD = Point3DD {
+  x: 1.0,
+  y: 2.0,
+  z: 4.0,
+};
@@ -2,12 +2,12 @@
 //! - Methods for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-05-09
 //! - Rust created: 2022-10-28
-//! - Rust updated: 2024-07-29
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the classes in the Java-based [`CroftSoft Core Library`]
@@ -21,13 +21,28 @@
 #[cfg(test)]
 mod test;
 
-use super::structures::{AStar, NodeInfo};
-use super::traits::Cartographer;
+use super::constants::{D_STAR_LITE_KEY_TOLERANCE, VISIBILITY_GRAPH_TOLERANCE};
+use super::structures::{
+  AStar, BidirectionalAStar, DStarLite, DStarLiteKey, DStarLiteOpenNode,
+  GoalSetCartographer, GridSearchCell, GridSearchRenderer,
+  IterativeDeepeningAStar, JumpPointCartographer, NodeInfo, OpenNode,
+  ReverseCartographer, SearchBudget, SearchEvent, SearchOutcome,
+  SearchStatistics, VisibilityGraphCartographer, VisibilityObstacle,
+};
+use super::traits::{Cartographer, PathCost};
+use crate::math::geom::point_2dd::Point2DD;
+use crate::math::geom::point_xy::PointXY;
+use core::f64::consts::TAU;
 use core::hash::Hash;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
 
-impl<N: Copy + Eq + Hash> AStar<N> {
+impl<N: Copy + Eq + Hash, C: PathCost> AStar<N, C> {
   pub fn get_first_step(&self) -> Option<N> {
     let mut node_option: Option<N> = self.goal_node_option;
     if node_option.is_none() {
@@ -46,6 +61,19 @@ impl<N: Copy + Eq + Hash> AStar<N> {
     child_node_option
   }
 
+  /// The goal node reached by the search, which is the start node when the
+  /// start node is a goal
+  pub fn get_goal_node(&self) -> Option<N> {
+    self.goal_node_option
+  }
+
+  /// The number of open nodes without the stale entries of the open node
+  /// priority queue since each node reached is either open or closed
+  /// until an anytime search phase finishes and clears the closed nodes
+  fn get_open_node_count(&self) -> usize {
+    self.node_to_node_info_map.len() - self.closed_node_set.len()
+  }
+
   pub fn get_path(&self) -> VecDeque<N> {
     let mut path_list = VecDeque::new();
     let mut node_option: Option<N> = self.goal_node_option;
@@ -72,45 +100,96 @@ impl<N: Copy + Eq + Hash> AStar<N> {
 
   pub fn loop_once(
     &mut self,
-    cartographer: &dyn Cartographer<N>,
+    cartographer: &dyn Cartographer<N, C>,
+  ) -> bool {
+    self.loop_once_observed(cartographer, &mut |_| {})
+  }
+
+  /// Same as loop_once but reports the expand and push events to the
+  /// observer
+  pub fn loop_once_observed(
+    &mut self,
+    cartographer: &dyn Cartographer<N, C>,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
+  ) -> bool {
+    if self.statistics_option.is_none() {
+      return self.expand_once(cartographer, observer);
+    }
+    let start_instant = Instant::now();
+    let looping: bool = self.expand_once(cartographer, observer);
+    if let Some(statistics) = &mut self.statistics_option {
+      statistics.wall_time += start_instant.elapsed();
+    }
+    looping
+  }
+
+  /// Expands the open node with the lowest total cost
+  fn expand_once(
+    &mut self,
+    cartographer: &dyn Cartographer<N, C>,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
   ) -> bool {
-    let Some(node) = self.open_node_sorted_list.pop_front() else {
+    let Some((node, node_info)) = self.pop_open_node() else {
+      if self.goal_node_option.is_some()
+        && self.finish_search_phase(cartographer, observer)
+      {
+        return true;
+      }
       self.list_empty = true;
       return false;
     };
-    let node_info: NodeInfo = *self.node_to_node_info_map.get(&node).unwrap();
+    observer(&SearchEvent::Expand {
+      node,
+      node_info,
+    });
+    if let Some(statistics) = &mut self.statistics_option {
+      statistics.nodes_expanded += 1;
+    }
+    self.closed_node_set.insert(node);
     if cartographer.is_goal_node(&node) {
-      if let Some(goal_node) = self.goal_node_option {
-        let goal_node_info =
-          self.node_to_node_info_map.get(&goal_node).unwrap();
-        if goal_node_info.cost_from_start <= node_info.cost_from_start {
-          return false;
-        }
+      let goal_improved: bool = self.goal_node_option.is_none_or(|goal_node| {
+        node_info.cost_from_start
+          < self.node_to_node_info_map[&goal_node].cost_from_start
+      });
+      if goal_improved {
+        self.goal_node_option = Some(node);
       }
-      self.goal_node_option = Some(node);
-      return false;
+      return self.finish_search_phase(cartographer, observer);
+    }
+    let parent_node_option: Option<N> =
+      self.node_to_parent_node_map.get(&node).copied();
+    let adjacent_nodes: Vec<N> =
+      cartographer.get_successor_nodes(&node, parent_node_option.as_ref());
+    if let Some(statistics) = &mut self.statistics_option {
+      statistics.nodes_generated += adjacent_nodes.len();
     }
-    let adjacent_nodes: Vec<N> = cartographer.get_adjacent_nodes(&node);
     for adjacent_node in adjacent_nodes {
-      let new_cost_from_start: f64 = node_info.cost_from_start
-        + cartographer.get_cost_to_adjacent_node(&node, &adjacent_node);
-      let adjacent_node_info_option: Option<&NodeInfo> =
+      let mut parent_node: N = node;
+      let mut parent_cost_from_start: C = node_info.cost_from_start;
+      if self.any_angle {
+        if let Some(grandparent_node) = parent_node_option {
+          if cartographer.has_line_of_sight(&grandparent_node, &adjacent_node) {
+            parent_node = grandparent_node;
+            parent_cost_from_start =
+              self.node_to_node_info_map[&grandparent_node].cost_from_start;
+          }
+        }
+      }
+      let new_cost_from_start: C = parent_cost_from_start.saturating_add(
+        cartographer.get_cost_to_adjacent_node(&parent_node, &adjacent_node),
+      );
+      let adjacent_node_info_option: Option<&NodeInfo<C>> =
         self.node_to_node_info_map.get(&adjacent_node);
       if let Some(adjacent_node_info) = adjacent_node_info_option {
         if adjacent_node_info.cost_from_start <= new_cost_from_start {
           continue;
         }
-        // TODO: Do something better here, maybe a sorted set
-        let position_option = self
-          .open_node_sorted_list
-          .iter()
-          .position(|&node| node == adjacent_node);
-        if let Some(position) = position_option {
-          self.open_node_sorted_list.remove(position);
-        }
       }
-      let total_cost: f64 = new_cost_from_start
-        + cartographer.estimate_cost_to_goal(&adjacent_node);
+      let total_cost: C = new_cost_from_start.saturating_add(
+        cartographer
+          .estimate_cost_to_goal(&adjacent_node)
+          .weight(self.search_heuristic_weight),
+      );
       let adjacent_node_info = NodeInfo {
         cost_from_start: new_cost_from_start,
         total_cost,
@@ -118,16 +197,22 @@ impl<N: Copy + Eq + Hash> AStar<N> {
       self
         .node_to_node_info_map
         .insert(adjacent_node, adjacent_node_info);
-      self.open_node_sorted_list.push_back(adjacent_node);
+      let closed: bool = self.closed_node_set.contains(&adjacent_node);
+      if closed {
+        if let Some(statistics) = &mut self.statistics_option {
+          statistics.reopenings += 1;
+        }
+      }
+      if self.anytime && closed {
+        self.inconsistent_node_list.push(adjacent_node);
+      } else {
+        // Reopens the node; any older open entry for it is now stale
+        self.closed_node_set.remove(&adjacent_node);
+        self.push_open_node(adjacent_node, adjacent_node_info, observer);
+      }
       self
-        .open_node_sorted_list
-        .make_contiguous()
-        .sort_by(|a, b| {
-          let node_info_a = self.node_to_node_info_map.get(a).unwrap();
-          let node_info_b = self.node_to_node_info_map.get(b).unwrap();
-          node_info_a.cmp(node_info_b)
-        });
-      self.node_to_parent_node_map.insert(adjacent_node, node);
+        .node_to_parent_node_map
+        .insert(adjacent_node, parent_node);
       if total_cost < self.best_total_cost {
         self.best_node_option = Some(adjacent_node);
         self.best_total_cost = total_cost;
@@ -136,20 +221,1271 @@ impl<N: Copy + Eq + Hash> AStar<N> {
     true
   }
 
+  /// The cost of the path to the goal found so far
+  pub fn get_best_cost(&self) -> Option<C> {
+    self
+      .goal_node_option
+      .map(|goal_node| self.node_to_node_info_map[&goal_node].cost_from_start)
+  }
+
+  /// Records the suboptimality bound of the path found by the current search
+  /// phase.
+  /// In anytime mode, starts the next phase with a lower heuristic weight by
+  /// reopening the inconsistent nodes and re-prioritizing the open nodes.
+  /// Returns false if there is no next phase.
+  fn finish_search_phase(
+    &mut self,
+    cartographer: &dyn Cartographer<N, C>,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
+  ) -> bool {
+    self.suboptimality_bound = self
+      .suboptimality_bound
+      .min(self.search_heuristic_weight.max(1.0));
+    if !self.anytime || self.search_heuristic_weight <= 1.0 {
+      return false;
+    }
+    self.search_heuristic_weight = if self.heuristic_weight_decrement > 0.0 {
+      (self.search_heuristic_weight - self.heuristic_weight_decrement).max(1.0)
+    } else {
+      1.0
+    };
+    let mut reopened_node_list: Vec<N> =
+      std::mem::take(&mut self.inconsistent_node_list);
+    for open_node in std::mem::take(&mut self.open_node_heap).into_vec() {
+      if !self.closed_node_set.contains(&open_node.node) {
+        reopened_node_list.push(open_node.node);
+      }
+    }
+    // The goal is reopened so that the phase ends when it is popped again
+    reopened_node_list.extend(self.goal_node_option);
+    self.closed_node_set.clear();
+    let mut reopened_node_set: HashSet<N> = HashSet::new();
+    for node in reopened_node_list {
+      if !reopened_node_set.insert(node) {
+        continue;
+      }
+      let cost_from_start: C =
+        self.node_to_node_info_map[&node].cost_from_start;
+      let node_info = NodeInfo {
+        cost_from_start,
+        total_cost: cost_from_start.saturating_add(
+          cartographer
+            .estimate_cost_to_goal(&node)
+            .weight(self.search_heuristic_weight),
+        ),
+      };
+      self.node_to_node_info_map.insert(node, node_info);
+      self.push_open_node(node, node_info, observer);
+    }
+    true
+  }
+
+  /// The open node with the lowest total cost
+  pub fn peek_open_node(&mut self) -> Option<N> {
+    self.discard_stale_open_nodes();
+    self.open_node_heap.peek().map(|open_node| open_node.node)
+  }
+
+  /// The node info of the open node with the lowest total cost
+  pub fn peek_open_node_info(&mut self) -> Option<NodeInfo<C>> {
+    self.discard_stale_open_nodes();
+    self
+      .open_node_heap
+      .peek()
+      .map(|open_node| open_node.node_info)
+  }
+
+  /// Removes stale entries from the top of the open node priority queue
+  fn discard_stale_open_nodes(&mut self) {
+    while let Some(open_node) = self.open_node_heap.peek() {
+      let stale: bool = self.closed_node_set.contains(&open_node.node)
+        || self
+          .node_to_node_info_map
+          .get(&open_node.node)
+          .unwrap()
+          .cost_from_start
+          < open_node.node_info.cost_from_start;
+      if !stale {
+        return;
+      }
+      self.open_node_heap.pop();
+    }
+  }
+
+  /// Pops the open node with the lowest total cost, skipping stale entries
+  fn pop_open_node(&mut self) -> Option<(N, NodeInfo<C>)> {
+    self.discard_stale_open_nodes();
+    let open_node: OpenNode<N, C> = self.open_node_heap.pop()?;
+    Some((open_node.node, open_node.node_info))
+  }
+
+  fn push_open_node(
+    &mut self,
+    node: N,
+    node_info: NodeInfo<C>,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
+  ) {
+    self.open_node_heap.push(OpenNode {
+      node,
+      node_info,
+      sequence: self.open_node_sequence,
+    });
+    self.open_node_sequence += 1;
+    if let Some(statistics) = &mut self.statistics_option {
+      statistics.peak_open_list_size = statistics
+        .peak_open_list_size
+        .max(self.open_node_heap.len());
+    }
+    observer(&SearchEvent::Push {
+      node,
+      node_info,
+    });
+  }
+
   pub fn reset(
     &mut self,
     start_node: N,
   ) {
+    self.reset_observed(start_node, &mut |_| {});
+  }
+
+  /// Same as reset but reports the push of the start node to the observer
+  pub fn reset_observed(
+    &mut self,
+    start_node: N,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
+  ) {
+    self.best_node_option = None;
+    self.closed_node_set = HashSet::new();
     self.goal_node_option = None;
+    self.inconsistent_node_list = Vec::new();
     self.list_empty = false;
-    self.open_node_sorted_list = VecDeque::new();
+    self.open_node_heap = BinaryHeap::new();
+    self.open_node_sequence = 0;
     self.node_to_node_info_map = HashMap::new();
     self.node_to_parent_node_map = HashMap::new();
-    let start_node_info = NodeInfo::default();
+    if self.statistics_option.is_some() {
+      self.statistics_option = Some(SearchStatistics::default());
+    }
+    let start_node_info = NodeInfo {
+      cost_from_start: C::ZERO,
+      total_cost: C::ZERO,
+    };
     self
       .node_to_node_info_map
       .insert(start_node, start_node_info);
-    self.open_node_sorted_list.push_front(start_node);
-    self.best_total_cost = f64::INFINITY;
+    self.push_open_node(start_node, start_node_info, observer);
+    self.best_total_cost = C::INFINITY;
+    self.search_heuristic_weight = self.heuristic_weight;
+    self.suboptimality_bound = f64::INFINITY;
+  }
+
+  /// Resets and then loops until the goal is found, the open list is empty,
+  /// or the search budget is exhausted
+  pub fn search(
+    &mut self,
+    start_node: N,
+    cartographer: &dyn Cartographer<N, C>,
+    search_budget: &SearchBudget<C>,
+  ) -> SearchOutcome<N, C> {
+    self.search_observed(start_node, cartographer, search_budget, &mut |_| {})
+  }
+
+  /// Same as search but reports the expand and push events to the observer
+  pub fn search_observed(
+    &mut self,
+    start_node: N,
+    cartographer: &dyn Cartographer<N, C>,
+    search_budget: &SearchBudget<C>,
+    observer: &mut dyn FnMut(&SearchEvent<N, C>),
+  ) -> SearchOutcome<N, C> {
+    self.reset_observed(start_node, observer);
+    let mut expansions: usize = 0;
+    loop {
+      if expansions >= search_budget.max_expansions
+        || self.node_to_node_info_map.len() >= search_budget.max_nodes
+      {
+        break;
+      }
+      if let Some(node_info) = self.peek_open_node_info() {
+        if node_info.total_cost > search_budget.max_path_cost {
+          break;
+        }
+      }
+      if !self.loop_once_observed(cartographer, observer) {
+        break;
+      }
+      expansions += 1;
+    }
+    if let Some(goal_node) = self.goal_node_option {
+      return SearchOutcome::GoalFound {
+        cost: self.node_to_node_info_map[&goal_node].cost_from_start,
+        path: self.get_path(),
+      };
+    }
+    if self.list_empty {
+      return SearchOutcome::Unreachable;
+    }
+    let cost: C = self.best_node_option.map_or(C::INFINITY, |node| {
+      self.node_to_node_info_map[&node].cost_from_start
+    });
+    SearchOutcome::BudgetExhausted {
+      cost,
+      path: self.get_path(),
+    }
+  }
+}
+
+impl<N: Copy + Eq + Hash> BidirectionalAStar<N> {
+  pub fn get_first_step(&self) -> Option<N> {
+    self.get_path().pop_front()
+  }
+
+  /// The path from the start through the meeting node to the goal.
+  /// The start node is excluded.
+  pub fn get_path(&self) -> VecDeque<N> {
+    let mut path_list = VecDeque::new();
+    let Some(meeting_node) = self.meeting_node_option else {
+      return path_list;
+    };
+    let mut node: N = meeting_node;
+    while let Some(parent_node) =
+      self.forward_astar.node_to_parent_node_map.get(&node)
+    {
+      path_list.push_front(node);
+      node = *parent_node;
+    }
+    let mut node: N = meeting_node;
+    while let Some(child_node) =
+      self.backward_astar.node_to_parent_node_map.get(&node)
+    {
+      path_list.push_back(*child_node);
+      node = *child_node;
+    }
+    path_list
+  }
+
+  pub fn is_goal_found(&self) -> bool {
+    self.meeting_node_option.is_some()
+  }
+
+  /// Expands one node from the frontier with fewer open nodes.
+  /// Returns false when the search is finished.
+  pub fn loop_once(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+  ) -> bool {
+    let Some(start_node) = self.start_node_option else {
+      return false;
+    };
+    let (Some(forward_node_info), Some(backward_node_info)) = (
+      self.forward_astar.peek_open_node_info(),
+      self.backward_astar.peek_open_node_info(),
+    ) else {
+      self.list_empty = true;
+      return false;
+    };
+    if forward_node_info.total_cost >= self.best_path_cost
+      || backward_node_info.total_cost >= self.best_path_cost
+    {
+      return false;
+    }
+    let forward: bool = self.forward_astar.get_open_node_count()
+      <= self.backward_astar.get_open_node_count();
+    let (node, node_cost_map, other_node_cost_map) = if forward {
+      let node: N = self.forward_astar.peek_open_node().unwrap();
+      self.forward_astar.loop_once(cartographer);
+      (
+        node,
+        &self.forward_astar.node_to_node_info_map,
+        &self.backward_astar.node_to_node_info_map,
+      )
+    } else {
+      let node: N = self.backward_astar.peek_open_node().unwrap();
+      let reverse_cartographer = ReverseCartographer {
+        cartographer,
+        start_node,
+      };
+      self.backward_astar.loop_once(&reverse_cartographer);
+      (
+        node,
+        &self.backward_astar.node_to_node_info_map,
+        &self.forward_astar.node_to_node_info_map,
+      )
+    };
+    if let Some(other_node_info) = other_node_cost_map.get(&node) {
+      let path_cost: f64 = node_cost_map.get(&node).unwrap().cost_from_start
+        + other_node_info.cost_from_start;
+      if path_cost < self.best_path_cost {
+        self.best_path_cost = path_cost;
+        self.meeting_node_option = Some(node);
+      }
+    }
+    true
+  }
+
+  pub fn reset(
+    &mut self,
+    start_node: N,
+    goal_node: N,
+  ) {
+    self.backward_astar.reset(goal_node);
+    self.best_path_cost = f64::INFINITY;
+    self.forward_astar.reset(start_node);
+    self.list_empty = false;
+    self.meeting_node_option = None;
+    self.start_node_option = Some(start_node);
+  }
+}
+
+impl<N: Copy + Eq + Hash> DStarLite<N> {
+  fn calculate_key(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+    node: &N,
+  ) -> DStarLiteKey {
+    let cost: f64 = self.get_cost(node).min(self.get_lookahead_cost(node));
+    DStarLiteKey {
+      primary: cost
+        + self.estimate_cost_from_start(cartographer, node)
+        + self.key_modifier,
+      secondary: cost,
+    }
+  }
+
+  /// Expands until the start is consistent or max_expansions is reached.
+  /// Returns false if stopped by max_expansions before it was finished.
+  pub fn compute_shortest_path(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+    max_expansions: usize,
+  ) -> bool {
+    let mut expansions: usize = 0;
+    while let Some(open_node) = self.peek_open_node() {
+      let start_node: N = self.start_node;
+      let start_key: DStarLiteKey =
+        self.calculate_key(cartographer, &start_node);
+      let tolerance: f64 =
+        D_STAR_LITE_KEY_TOLERANCE * start_key.primary.abs().max(1.0);
+      if open_node.key.primary > start_key.primary + tolerance
+        && self.get_lookahead_cost(&start_node) == self.get_cost(&start_node)
+      {
+        return true;
+      }
+      if expansions >= max_expansions {
+        return false;
+      }
+      expansions += 1;
+      let node: N = open_node.node;
+      let new_key: DStarLiteKey = self.calculate_key(cartographer, &node);
+      if open_node.key < new_key {
+        self.insert_open_node(node, new_key);
+        continue;
+      }
+      self.node_to_open_key_map.remove(&node);
+      let lookahead_cost: f64 = self.get_lookahead_cost(&node);
+      if self.get_cost(&node) > lookahead_cost {
+        self.node_to_cost_map.insert(node, lookahead_cost);
+      } else {
+        self.node_to_cost_map.insert(node, f64::INFINITY);
+        self.update_node(cartographer, &node);
+      }
+      for predecessor_node in cartographer.get_predecessor_nodes(&node) {
+        self.update_node(cartographer, &predecessor_node);
+      }
+    }
+    true
+  }
+
+  /// Notifies the planner that the edges into or out of these nodes changed.
+  /// Call compute_shortest_path() afterwards to repair the solution.
+  pub fn edges_changed(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+    changed_nodes: &[N],
+  ) {
+    for changed_node in changed_nodes {
+      self.update_node(cartographer, changed_node);
+      for predecessor_node in cartographer.get_predecessor_nodes(changed_node) {
+        self.update_node(cartographer, &predecessor_node);
+      }
+      for adjacent_node in cartographer.get_adjacent_nodes(changed_node) {
+        self.update_node(cartographer, &adjacent_node);
+      }
+    }
+  }
+
+  /// A consistent heuristic for the cost from the start derived from the
+  /// cartographer estimates to the goal using the triangle inequality
+  fn estimate_cost_from_start(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+    node: &N,
+  ) -> f64 {
+    (cartographer.estimate_cost_to_goal(&self.start_node)
+      - cartographer.estimate_cost_to_goal(node))
+    .max(0.0)
+  }
+
+  /// The best adjacent node to move to from a node and its cost to the goal
+  fn get_best_adjacent_node(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+    node: &N,
+  ) -> Option<(N, f64)> {
+    let mut best_option: Option<(N, f64)> = None;
+    for adjacent_node in cartographer.get_adjacent_nodes(node) {
+      let cost: f64 = cartographer
+        .get_cost_to_adjacent_node(node, &adjacent_node)
+        + self.get_cost(&adjacent_node);
+      if cost < best_option.map_or(f64::INFINITY, |(_, best_cost)| best_cost) {
+        best_option = Some((adjacent_node, cost));
+      }
+    }
+    best_option
+  }
+
+  /// The cost to the goal or infinity if unknown
+  pub fn get_cost(
+    &self,
+    node: &N,
+  ) -> f64 {
+    *self.node_to_cost_map.get(node).unwrap_or(&f64::INFINITY)
+  }
+
+  pub fn get_first_step(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+  ) -> Option<N> {
+    self.get_path(cartographer).pop_front()
+  }
+
+  /// The one-step lookahead cost to the goal or infinity if unknown
+  pub fn get_lookahead_cost(
+    &self,
+    node: &N,
+  ) -> f64 {
+    if *node == self.goal_node {
+      return 0.0;
+    }
+    *self
+      .node_to_lookahead_cost_map
+      .get(node)
+      .unwrap_or(&f64::INFINITY)
+  }
+
+  /// Follows the cheapest adjacent nodes from the start to the goal.
+  /// As with AStar::get_path(), the start node is not included.
+  /// Returns an empty path if the goal is unreachable.
+  pub fn get_path(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+  ) -> VecDeque<N> {
+    let mut path_list = VecDeque::new();
+    if !self.is_path_found() {
+      return path_list;
+    }
+    let mut node: N = self.start_node;
+    while node != self.goal_node {
+      if path_list.len() > self.node_to_cost_map.len() {
+        return VecDeque::new();
+      }
+      let Some((next_node, cost)) =
+        self.get_best_adjacent_node(cartographer, &node)
+      else {
+        return VecDeque::new();
+      };
+      if cost == f64::INFINITY {
+        return VecDeque::new();
+      }
+      path_list.push_back(next_node);
+      node = next_node;
+    }
+    path_list
+  }
+
+  /// The cost of the path from the start to the goal
+  pub fn get_path_cost(&self) -> f64 {
+    self.get_cost(&self.start_node)
+  }
+
+  fn insert_open_node(
+    &mut self,
+    node: N,
+    key: DStarLiteKey,
+  ) {
+    self.node_to_open_key_map.insert(node, key);
+    self.open_node_heap.push(DStarLiteOpenNode {
+      key,
+      node,
+    });
+  }
+
+  pub fn is_path_found(&self) -> bool {
+    self.get_path_cost() < f64::INFINITY
+  }
+
+  /// Moves the start as the agent follows the path.
+  /// Call compute_shortest_path() afterwards.
+  pub fn move_start(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+    start_node: N,
+  ) {
+    self.key_modifier +=
+      self.estimate_cost_from_start(cartographer, &start_node);
+    self.start_node = start_node;
+  }
+
+  pub fn new(
+    start_node: N,
+    goal_node: N,
+  ) -> Self {
+    let mut d_star_lite = DStarLite {
+      goal_node,
+      key_modifier: 0.0,
+      node_to_cost_map: HashMap::new(),
+      node_to_lookahead_cost_map: HashMap::new(),
+      node_to_open_key_map: HashMap::new(),
+      open_node_heap: BinaryHeap::new(),
+      start_node,
+    };
+    d_star_lite.reset(start_node, goal_node);
+    d_star_lite
+  }
+
+  /// The open node with the lowest key after discarding stale entries
+  fn peek_open_node(&mut self) -> Option<DStarLiteOpenNode<N>> {
+    while let Some(open_node) = self.open_node_heap.peek() {
+      if self.node_to_open_key_map.get(&open_node.node) == Some(&open_node.key)
+      {
+        return Some(*open_node);
+      }
+      self.open_node_heap.pop();
+    }
+    None
+  }
+
+  /// Discards the previous solution and starts over
+  pub fn reset(
+    &mut self,
+    start_node: N,
+    goal_node: N,
+  ) {
+    self.goal_node = goal_node;
+    self.key_modifier = 0.0;
+    self.node_to_cost_map = HashMap::new();
+    self.node_to_lookahead_cost_map = HashMap::new();
+    self.node_to_open_key_map = HashMap::new();
+    self.open_node_heap = BinaryHeap::new();
+    self.start_node = start_node;
+    // The key is an underestimate which is corrected when it is first popped
+    self.insert_open_node(
+      goal_node,
+      DStarLiteKey {
+        primary: 0.0,
+        secondary: 0.0,
+      },
+    );
+  }
+
+  fn update_node(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+    node: &N,
+  ) {
+    if *node != self.goal_node {
+      let lookahead_cost: f64 = self
+        .get_best_adjacent_node(cartographer, node)
+        .map_or(f64::INFINITY, |(_, cost)| cost);
+      self
+        .node_to_lookahead_cost_map
+        .insert(*node, lookahead_cost);
+    }
+    self.node_to_open_key_map.remove(node);
+    if self.get_cost(node) != self.get_lookahead_cost(node) {
+      let key: DStarLiteKey = self.calculate_key(cartographer, node);
+      self.insert_open_node(*node, key);
+    }
+  }
+}
+
+impl<N: PointXY, C: PathCost> GoalSetCartographer<'_, N, C> {
+  /// The index of the nearest goal node within the goal radius of the node.
+  /// Use this with the last node of the path to find which goal was reached.
+  pub fn get_goal_index(
+    &self,
+    node: &N,
+  ) -> Option<usize> {
+    self
+      .goal_nodes
+      .iter()
+      .map(|goal_node| node.distance_xy(goal_node))
+      .enumerate()
+      .filter(|(_, distance)| *distance <= self.goal_radius)
+      .min_by(|(_, distance_0), (_, distance_1)| {
+        distance_0.total_cmp(distance_1)
+      })
+      .map(|(goal_index, _)| goal_index)
+  }
+
+  /// The index of the goal node reached by the search, which is the goal
+  /// node of the start node when the start node is a goal
+  pub fn get_reached_goal_index(
+    &self,
+    astar: &AStar<N, C>,
+  ) -> Option<usize>
+  where
+    N: Copy + Eq + Hash,
+  {
+    self.get_goal_index(&astar.get_goal_node()?)
+  }
+}
+
+impl GridSearchCell {
+  /// The character for the cell in ASCII art
+  pub fn to_char(&self) -> char {
+    match self {
+      GridSearchCell::Blocked => '#',
+      GridSearchCell::Closed => 'x',
+      GridSearchCell::Free => '.',
+      GridSearchCell::Goal => 'G',
+      GridSearchCell::Open => 'o',
+      GridSearchCell::Path => '*',
+      GridSearchCell::Start => 'S',
+    }
+  }
+
+  /// The fill color for the cell in an SVG image
+  pub fn to_color(&self) -> &'static str {
+    match self {
+      GridSearchCell::Blocked => "black",
+      GridSearchCell::Closed => "lightsteelblue",
+      GridSearchCell::Free => "white",
+      GridSearchCell::Goal => "red",
+      GridSearchCell::Open => "palegreen",
+      GridSearchCell::Path => "gold",
+      GridSearchCell::Start => "green",
+    }
+  }
+}
+
+impl<N, S, M> GridSearchRenderer<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  /// The column and row of the cell nearest to a node within the grid
+  pub fn get_cell_position(
+    &self,
+    node: &N,
+  ) -> Option<(usize, usize)> {
+    let column: f64 =
+      ((node.get_x() - self.origin_node.get_x()) / self.step_size).round();
+    let row: f64 =
+      ((node.get_y() - self.origin_node.get_y()) / self.step_size).round();
+    if column < 0.0
+      || row < 0.0
+      || column >= self.column_count as f64
+      || row >= self.row_count as f64
+    {
+      return None;
+    }
+    Some((column as usize, row as usize))
+  }
+
+  /// One line of characters per row
+  pub fn to_ascii<C: PathCost>(
+    &self,
+    astar: &AStar<N, C>,
+    start_node: &N,
+  ) -> String {
+    let mut ascii = String::new();
+    for row_cells in self.to_cells(astar, start_node) {
+      ascii.extend(row_cells.iter().map(GridSearchCell::to_char));
+      ascii.push('\n');
+    }
+    ascii
+  }
+
+  /// The cells by row and then by column.
+  /// The path is the path to the goal or to the best node found so far.
+  pub fn to_cells<C: PathCost>(
+    &self,
+    astar: &AStar<N, C>,
+    start_node: &N,
+  ) -> Vec<Vec<GridSearchCell>> {
+    let mut cells: Vec<Vec<GridSearchCell>> = (0..self.row_count)
+      .map(|row| {
+        (0..self.column_count)
+          .map(|column| {
+            let node: N = (self.make_node_fn)(
+              self.origin_node.get_x() + column as f64 * self.step_size,
+              self.origin_node.get_y() + row as f64 * self.step_size,
+            );
+            if (self.is_space_available_fn)(&node) {
+              GridSearchCell::Free
+            } else {
+              GridSearchCell::Blocked
+            }
+          })
+          .collect()
+      })
+      .collect();
+    let mut mark = |node: &N, cell: GridSearchCell| {
+      if let Some((column, row)) = self.get_cell_position(node) {
+        cells[row][column] = cell;
+      }
+    };
+    for open_node in astar.open_node_heap.iter() {
+      if !astar.closed_node_set.contains(&open_node.node) {
+        mark(&open_node.node, GridSearchCell::Open);
+      }
+    }
+    for node in astar.closed_node_set.iter() {
+      mark(node, GridSearchCell::Closed);
+    }
+    for node in astar.get_path().iter() {
+      mark(node, GridSearchCell::Path);
+    }
+    mark(start_node, GridSearchCell::Start);
+    if let Some(goal_node) = astar.goal_node_option {
+      mark(&goal_node, GridSearchCell::Goal);
+    }
+    cells
+  }
+
+  /// A rectangle per cell plus a line through the centers of the path cells
+  pub fn to_svg<C: PathCost>(
+    &self,
+    astar: &AStar<N, C>,
+    start_node: &N,
+  ) -> String {
+    let width: f64 = self.column_count as f64 * self.cell_size;
+    let height: f64 = self.row_count as f64 * self.cell_size;
+    let mut svg: String = format!(
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" \
+        height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+    for (row, row_cells) in self.to_cells(astar, start_node).iter().enumerate()
+    {
+      for (column, cell) in row_cells.iter().enumerate() {
+        let _ = writeln!(
+          svg,
+          "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+            fill=\"{}\"/>",
+          column as f64 * self.cell_size,
+          row as f64 * self.cell_size,
+          self.cell_size,
+          self.cell_size,
+          cell.to_color(),
+        );
+      }
+    }
+    let point_list: Vec<String> = core::iter::once(*start_node)
+      .chain(astar.get_path())
+      .filter_map(|node| self.get_cell_position(&node))
+      .map(|(column, row)| {
+        format!(
+          "{},{}",
+          (column as f64 + 0.5) * self.cell_size,
+          (row as f64 + 0.5) * self.cell_size,
+        )
+      })
+      .collect();
+    if point_list.len() > 1 {
+      let _ = writeln!(
+        svg,
+        "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" \
+          stroke-width=\"{}\"/>",
+        point_list.join(" "),
+        self.cell_size / 5.0,
+      );
+    }
+    svg.push_str("</svg>\n");
+    svg
+  }
+
+  /// Writes the SVG image to a file
+  pub fn write_svg<C: PathCost>(
+    &self,
+    astar: &AStar<N, C>,
+    start_node: &N,
+    file_path: impl AsRef<Path>,
+  ) -> std::io::Result<()> {
+    std::fs::write(file_path, self.to_svg(astar, start_node))
+  }
+}
+
+impl<N: Copy + PartialEq> IterativeDeepeningAStar<N> {
+  pub fn get_first_step(&self) -> Option<N> {
+    self.path_node_list.get(1).copied()
+  }
+
+  /// The path to the goal excluding the start node
+  pub fn get_path(&self) -> VecDeque<N> {
+    self.path_node_list.iter().skip(1).copied().collect()
+  }
+
+  pub fn is_goal_found(&self) -> bool {
+    !self.path_node_list.is_empty()
+  }
+
+  /// Runs one depth-first iteration bounded by the cost threshold.
+  /// Returns false when the goal is found or cannot be reached.
+  pub fn loop_once(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+  ) -> bool {
+    let Some(start_node) = self.start_node_option else {
+      return false;
+    };
+    if self.is_goal_found() || self.cost_threshold == f64::INFINITY {
+      return false;
+    }
+    // The first threshold is the cost estimate from the start to the goal
+    if self.iteration_count == 0 {
+      self.cost_threshold = cartographer.estimate_cost_to_goal(&start_node);
+    }
+    self.iteration_count += 1;
+    let mut next_cost_threshold: f64 = f64::INFINITY;
+    let mut node_list: Vec<N> = vec![start_node];
+    let mut cost_from_start_list: Vec<f64> = vec![0.0];
+    // The adjacent nodes not yet visited for each node on the path
+    let mut adjacent_nodes_list: Vec<Vec<N>> = Vec::new();
+    while let Some(&node) = node_list.last() {
+      let depth: usize = node_list.len() - 1;
+      let cost_from_start: f64 = cost_from_start_list[depth];
+      if adjacent_nodes_list.len() == depth {
+        let total_cost: f64 =
+          cost_from_start + cartographer.estimate_cost_to_goal(&node);
+        if total_cost > self.cost_threshold {
+          next_cost_threshold = next_cost_threshold.min(total_cost);
+          node_list.pop();
+          cost_from_start_list.pop();
+          continue;
+        }
+        if cartographer.is_goal_node(&node) {
+          self.path_cost = cost_from_start;
+          self.path_node_list = node_list;
+          return false;
+        }
+        let parent_node_option: Option<&N> = depth
+          .checked_sub(1)
+          .map(|parent_depth| &node_list[parent_depth]);
+        let mut adjacent_nodes: Vec<N> =
+          cartographer.get_successor_nodes(&node, parent_node_option);
+        // Reversed so that the adjacent nodes are popped in order
+        adjacent_nodes.reverse();
+        adjacent_nodes_list.push(adjacent_nodes);
+        continue;
+      }
+      let Some(adjacent_node) = adjacent_nodes_list[depth].pop() else {
+        adjacent_nodes_list.pop();
+        node_list.pop();
+        cost_from_start_list.pop();
+        continue;
+      };
+      // Skips cycles back to a node already on the path
+      if node_list.contains(&adjacent_node) {
+        continue;
+      }
+      cost_from_start_list.push(
+        cost_from_start
+          + cartographer.get_cost_to_adjacent_node(&node, &adjacent_node),
+      );
+      node_list.push(adjacent_node);
+    }
+    self.cost_threshold = next_cost_threshold;
+    next_cost_threshold != f64::INFINITY
+  }
+
+  pub fn reset(
+    &mut self,
+    start_node: N,
+  ) {
+    self.cost_threshold = 0.0;
+    self.iteration_count = 0;
+    self.path_cost = f64::INFINITY;
+    self.path_node_list = Vec::new();
+    self.start_node_option = Some(start_node);
+  }
+}
+
+impl<N, S, M> JumpPointCartographer<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  /// Expands a path of jump points into a cell-by-cell path.
+  /// As with AStar::get_path(), the start node is not included.
+  pub fn expand_jump_points(
+    &self,
+    start_node: &N,
+    jump_point_path: &VecDeque<N>,
+  ) -> VecDeque<N> {
+    let mut cell_path = VecDeque::new();
+    let (mut cell_x, mut cell_y) = self.to_cell(start_node);
+    for jump_point in jump_point_path {
+      let (jump_point_cell_x, jump_point_cell_y) = self.to_cell(jump_point);
+      let (delta_x, delta_y) =
+        self.to_direction(cell_x, cell_y, jump_point_cell_x, jump_point_cell_y);
+      let steps: f64 = (jump_point_cell_x - cell_x)
+        .abs()
+        .max((jump_point_cell_y - cell_y).abs());
+      for step in 1..(steps as usize) {
+        let step: f64 = step as f64;
+        cell_path.push_back(
+          self.make_cell_node(cell_x + step * delta_x, cell_y + step * delta_y),
+        );
+      }
+      cell_path.push_back(*jump_point);
+      (cell_x, cell_y) = (jump_point_cell_x, jump_point_cell_y);
+    }
+    cell_path
+  }
+
+  pub fn is_cell_available(
+    &self,
+    cell_x: f64,
+    cell_y: f64,
+  ) -> bool {
+    (self.is_space_available_fn)(&self.make_cell_node(cell_x, cell_y))
+  }
+
+  /// True if the node is close enough to jump straight to the goal
+  pub fn is_near_goal(
+    &self,
+    node: &N,
+  ) -> bool {
+    node.distance_xy(&self.goal_node) <= self.step_size
+  }
+
+  /// Steps from a cell in a direction until a jump point is found.
+  /// Returns None if the way is blocked before a jump point is found.
+  pub fn jump(
+    &self,
+    cell_x: f64,
+    cell_y: f64,
+    delta_x: f64,
+    delta_y: f64,
+  ) -> Option<N> {
+    let mut x: f64 = cell_x;
+    let mut y: f64 = cell_y;
+    for step_count in 1..=self.jump_limit {
+      x += delta_x;
+      y += delta_y;
+      let node: N = self.make_cell_node(x, y);
+      if !(self.is_space_available_fn)(&node) {
+        return None;
+      }
+      if self.is_near_goal(&node) || step_count == self.jump_limit {
+        return Some(node);
+      }
+      if delta_x != 0.0 && delta_y != 0.0 {
+        if self.is_forced(x - delta_x, y, x - delta_x, y + delta_y)
+          || self.is_forced(x, y - delta_y, x + delta_x, y - delta_y)
+          || self.jump(x, y, delta_x, 0.0).is_some()
+          || self.jump(x, y, 0.0, delta_y).is_some()
+        {
+          return Some(node);
+        }
+      } else if delta_x != 0.0 {
+        if self.is_forced(x, y + 1.0, x + delta_x, y + 1.0)
+          || self.is_forced(x, y - 1.0, x + delta_x, y - 1.0)
+        {
+          return Some(node);
+        }
+      } else if self.is_forced(x + 1.0, y, x + 1.0, y + delta_y)
+        || self.is_forced(x - 1.0, y, x - 1.0, y + delta_y)
+      {
+        return Some(node);
+      }
+    }
+    None
+  }
+
+  /// A neighbor is forced if it is only reachable optimally through the
+  /// current cell because of the blocked cell next to it
+  fn is_forced(
+    &self,
+    blocked_cell_x: f64,
+    blocked_cell_y: f64,
+    neighbor_cell_x: f64,
+    neighbor_cell_y: f64,
+  ) -> bool {
+    !self.is_cell_available(blocked_cell_x, blocked_cell_y)
+      && self.is_cell_available(neighbor_cell_x, neighbor_cell_y)
+  }
+
+  pub fn make_cell_node(
+    &self,
+    cell_x: f64,
+    cell_y: f64,
+  ) -> N {
+    (self.make_node_fn)(cell_x * self.step_size, cell_y * self.step_size)
+  }
+
+  pub fn to_cell(
+    &self,
+    node: &N,
+  ) -> (f64, f64) {
+    (
+      (node.get_x() / self.step_size).trunc(),
+      (node.get_y() / self.step_size).trunc(),
+    )
+  }
+
+  /// The unit step in cell coordinates from one cell towards another
+  pub fn to_direction(
+    &self,
+    from_cell_x: f64,
+    from_cell_y: f64,
+    to_cell_x: f64,
+    to_cell_y: f64,
+  ) -> (f64, f64) {
+    let delta_x: f64 = to_cell_x - from_cell_x;
+    let delta_y: f64 = to_cell_y - from_cell_y;
+    (
+      if delta_x == 0.0 {
+        0.0
+      } else {
+        delta_x.signum()
+      },
+      if delta_y == 0.0 {
+        0.0
+      } else {
+        delta_y.signum()
+      },
+    )
+  }
+}
+
+impl VisibilityGraphCartographer {
+  /// The vertices of the inflated obstacles which are visible from the node
+  /// and which are not inside of another inflated obstacle
+  pub fn get_visible_vertices(
+    &self,
+    node: &Point2DD,
+  ) -> Vec<Point2DD> {
+    self
+      .polygon_list
+      .iter()
+      .flatten()
+      .filter(|vertex| {
+        *vertex != node
+          && !self.is_inside_obstacle(vertex)
+          && self.is_visible(node, vertex)
+      })
+      .copied()
+      .collect()
+  }
+
+  /// True if the point is in the interior of an inflated obstacle
+  pub fn is_inside_obstacle(
+    &self,
+    point: &Point2DD,
+  ) -> bool {
+    self
+      .polygon_list
+      .iter()
+      .any(|polygon| Self::is_crossing_polygon(polygon, point, point))
+  }
+
+  /// True if the line segment passes through the interior of the convex
+  /// polygon with counterclockwise vertices.
+  /// Touching a vertex or sliding along an edge is not crossing.
+  fn is_crossing_polygon(
+    polygon: &[Point2DD],
+    from_point: &Point2DD,
+    to_point: &Point2DD,
+  ) -> bool {
+    if polygon.len() < 3 {
+      return false;
+    }
+    // The fractions of the way along the segment between which the segment
+    // is inside of every edge
+    let mut enter_fraction: f64 = 0.0;
+    let mut exit_fraction: f64 = 1.0;
+    for (index, vertex) in polygon.iter().enumerate() {
+      let next_vertex: &Point2DD = &polygon[(index + 1) % polygon.len()];
+      let edge_x: f64 = next_vertex.x - vertex.x;
+      let edge_y: f64 = next_vertex.y - vertex.y;
+      let edge_length: f64 = edge_x.hypot(edge_y);
+      if edge_length == 0.0 {
+        continue;
+      }
+      // The distance inside of the edge at the start of the segment and the
+      // change in that distance along the segment
+      let distance: f64 = (edge_x * (from_point.y - vertex.y)
+        - edge_y * (from_point.x - vertex.x))
+        / edge_length;
+      let rate: f64 = (edge_x * (to_point.y - from_point.y)
+        - edge_y * (to_point.x - from_point.x))
+        / edge_length;
+      if rate == 0.0 {
+        if distance <= VISIBILITY_GRAPH_TOLERANCE {
+          return false;
+        }
+        continue;
+      }
+      let fraction: f64 = (VISIBILITY_GRAPH_TOLERANCE - distance) / rate;
+      if rate > 0.0 {
+        enter_fraction = enter_fraction.max(fraction);
+      } else {
+        exit_fraction = exit_fraction.min(fraction);
+      }
+      if enter_fraction >= exit_fraction {
+        return false;
+      }
+    }
+    true
+  }
+
+  /// True if the straight line between the points does not pass through the
+  /// interior of an inflated obstacle
+  pub fn is_visible(
+    &self,
+    from_point: &Point2DD,
+    to_point: &Point2DD,
+  ) -> bool {
+    !self
+      .polygon_list
+      .iter()
+      .any(|polygon| Self::is_crossing_polygon(polygon, from_point, to_point))
+  }
+
+  /// Inflates the obstacles by the agent radius and connects the visible
+  /// vertices.
+  /// The side count is the number of sides of the polygon which approximates
+  /// a full circle.
+  pub fn new(
+    agent_radius: f64,
+    goal_node: Point2DD,
+    obstacle_list: &[VisibilityObstacle],
+    side_count: usize,
+  ) -> Self {
+    let mut visibility_graph_cartographer = VisibilityGraphCartographer {
+      goal_node,
+      polygon_list: obstacle_list
+        .iter()
+        .map(|obstacle| obstacle.to_inflated_polygon(agent_radius, side_count))
+        .collect(),
+      vertex_to_visible_vertices_map: HashMap::new(),
+    };
+    let vertex_list: Vec<Point2DD> = visibility_graph_cartographer
+      .polygon_list
+      .iter()
+      .flatten()
+      .filter(|vertex| {
+        !visibility_graph_cartographer.is_inside_obstacle(vertex)
+      })
+      .copied()
+      .collect();
+    for vertex in vertex_list {
+      let visible_vertex_list: Vec<Point2DD> =
+        visibility_graph_cartographer.get_visible_vertices(&vertex);
+      visibility_graph_cartographer
+        .vertex_to_visible_vertices_map
+        .insert(vertex, visible_vertex_list);
+    }
+    visibility_graph_cartographer
+  }
+}
+
+impl VisibilityObstacle {
+  /// A convex polygon with counterclockwise vertices which contains the
+  /// obstacle grown by the agent radius.
+  /// The rounded parts are approximated by circumscribed polygon sides so
+  /// that the agent never clips the obstacle.
+  pub fn to_inflated_polygon(
+    &self,
+    agent_radius: f64,
+    side_count: usize,
+  ) -> Vec<Point2DD> {
+    let side_angle: f64 = TAU / side_count.max(3) as f64;
+    match self {
+      VisibilityObstacle::Circle(circle) => {
+        let circumscribed_radius: f64 =
+          (circle.radius + agent_radius) / (side_angle / 2.0).cos();
+        (0..side_count.max(3))
+          .map(|index| {
+            let angle: f64 = side_angle * (index as f64 + 0.5);
+            Point2DD::new(
+              circle.center_x + circumscribed_radius * angle.cos(),
+              circle.center_y + circumscribed_radius * angle.sin(),
+            )
+          })
+          .collect()
+      },
+      VisibilityObstacle::ConvexPolygon(vertex_list) => {
+        let mut vertex_list: Vec<Point2DD> = vertex_list.clone();
+        let doubled_area: f64 = vertex_list
+          .iter()
+          .zip(vertex_list.iter().cycle().skip(1))
+          .map(|(vertex, next_vertex)| {
+            vertex.x * next_vertex.y - next_vertex.x * vertex.y
+          })
+          .sum();
+        if doubled_area < 0.0 {
+          vertex_list.reverse();
+        }
+        Self::inflate_polygon(&vertex_list, agent_radius, side_angle)
+      },
+      VisibilityObstacle::Rectangle(rectangle) => Self::inflate_polygon(
+        &[
+          Point2DD::new(rectangle.x_min, rectangle.y_min),
+          Point2DD::new(rectangle.x_max, rectangle.y_min),
+          Point2DD::new(rectangle.x_max, rectangle.y_max),
+          Point2DD::new(rectangle.x_min, rectangle.y_max),
+        ],
+        agent_radius,
+        side_angle,
+      ),
+    }
+  }
+
+  /// Replaces each vertex of the counterclockwise convex polygon with the
+  /// circumscribed polygon sides of the arc around the vertex
+  fn inflate_polygon(
+    vertex_list: &[Point2DD],
+    agent_radius: f64,
+    side_angle: f64,
+  ) -> Vec<Point2DD> {
+    if agent_radius <= 0.0 {
+      return vertex_list.to_vec();
+    }
+    let vertex_count: usize = vertex_list.len();
+    // The angle of the outward normal of the edge from each vertex
+    let normal_angle_list: Vec<f64> = (0..vertex_count)
+      .map(|index| {
+        let vertex: &Point2DD = &vertex_list[index];
+        let next_vertex: &Point2DD = &vertex_list[(index + 1) % vertex_count];
+        (vertex.x - next_vertex.x).atan2(next_vertex.y - vertex.y)
+      })
+      .collect();
+    let mut inflated_list: Vec<Point2DD> = Vec::new();
+    for (index, vertex) in vertex_list.iter().enumerate() {
+      let start_angle: f64 =
+        normal_angle_list[(index + vertex_count - 1) % vertex_count];
+      let turn_angle: f64 =
+        (normal_angle_list[index] - start_angle).rem_euclid(TAU);
+      let arc_side_count: usize =
+        ((turn_angle / side_angle).ceil() as usize).max(1);
+      let arc_side_angle: f64 = turn_angle / arc_side_count as f64;
+      let circumscribed_radius: f64 =
+        agent_radius / (arc_side_angle / 2.0).cos();
+      for arc_side_index in 0..arc_side_count {
+        let angle: f64 =
+          start_angle + arc_side_angle * (arc_side_index as f64 + 0.5);
+        inflated_list.push(Point2DD::new(
+          vertex.x + circumscribed_radius * angle.cos(),
+          vertex.y + circumscribed_radius * angle.sin(),
+        ));
+      }
+    }
+    inflated_list
   }
 }
@@ -2,32 +2,220 @@
 //! - Unit tests for the A* algorithm associated functions
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-08
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
 #[cfg(test)]
-use crate::ai::astar::constants::test::{TEST_BEST_NODE, TEST_GOAL_NODE};
+use crate::ai::astar::constants::test::{
+  TEST_BEST_NODE, TEST_DISTANCE_TO_GOAL, TEST_GOAL_NODE,
+};
+#[cfg(test)]
+use crate::ai::astar::traits::Cartographer;
 #[cfg(test)]
 use crate::{
   ai::astar::{
-    constants::test::{TEST_ORIGIN_NODE, TEST_SUBJECT_GRID_CARTOGRAPHER},
-    structures::{AStar, NodeInfo},
+    constants::test::{
+      TEST_ORIGIN_NODE, TEST_SUBJECT_GRID_CARTOGRAPHER,
+      TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER, TEST_TOLERANCE,
+    },
+    structures::{
+      AStar, BidirectionalAStar, DStarLite, GoalSetCartographer,
+      GridSearchCell, GridSearchRenderer, IterativeDeepeningAStar, NodeInfo,
+      OpenNode, SearchBudget, SearchEvent, SearchOutcome, SearchStatistics,
+      VisibilityGraphCartographer, VisibilityObstacle,
+    },
   },
+  math::geom::circle::Circle,
   math::geom::point_2dd::Point2DD,
+  math::geom::rectangle::Rectangle,
 };
 #[cfg(test)]
-use core::f64::INFINITY;
+use std::collections::BinaryHeap;
 #[cfg(test)]
 use std::collections::HashMap;
 #[cfg(test)]
+use std::collections::HashSet;
+#[cfg(test)]
 use std::collections::VecDeque;
 
+/// A line of nodes from zero to four with integer move points.
+/// A single step costs one except for the step from one to two which costs
+/// four.
+/// A double step costs three except from zero where it costs four.
+#[cfg(test)]
+struct IntegerCostCartographer;
+
+#[cfg(test)]
+impl Cartographer<i64, u32> for IntegerCostCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &i64,
+  ) -> u32 {
+    (4 - node) as u32
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &i64,
+  ) -> Vec<i64> {
+    [
+      node + 1,
+      node + 2,
+    ]
+    .into_iter()
+    .filter(|adjacent_node| *adjacent_node <= 4)
+    .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &i64,
+    to_node: &i64,
+  ) -> u32 {
+    match (from_node, to_node - from_node) {
+      (1, 1) => 4,
+      (_, 1) => 1,
+      (0, _) => 4,
+      _ => 3,
+    }
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &i64,
+  ) -> bool {
+    *node == 4
+  }
+}
+
+#[test]
+fn test_bidirectional_astar_loop_once() {
+  let mut test_subject_bidirectional_astar: BidirectionalAStar<Point2DD> =
+    BidirectionalAStar::default();
+  assert!(
+    !test_subject_bidirectional_astar
+      .loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER)
+  );
+  test_subject_bidirectional_astar.reset(TEST_ORIGIN_NODE, TEST_GOAL_NODE);
+  while test_subject_bidirectional_astar
+    .loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER)
+  {}
+  assert!(test_subject_bidirectional_astar.is_goal_found());
+  assert_eq!(
+    test_subject_bidirectional_astar.best_path_cost,
+    TEST_DISTANCE_TO_GOAL
+  );
+  assert_eq!(
+    test_subject_bidirectional_astar.get_path(),
+    VecDeque::from([
+      TEST_BEST_NODE,
+      Point2DD::new(2.0, 0.0),
+      Point2DD::new(3.0, 0.0),
+      TEST_GOAL_NODE,
+    ])
+  );
+  assert_eq!(
+    test_subject_bidirectional_astar.get_first_step(),
+    Some(TEST_BEST_NODE)
+  );
+}
+
+#[test]
+fn test_bidirectional_astar_reset() {
+  let mut test_subject_bidirectional_astar: BidirectionalAStar<Point2DD> =
+    BidirectionalAStar::default();
+  test_subject_bidirectional_astar.reset(TEST_ORIGIN_NODE, TEST_GOAL_NODE);
+  assert_eq!(
+    test_subject_bidirectional_astar
+      .forward_astar
+      .peek_open_node(),
+    Some(TEST_ORIGIN_NODE)
+  );
+  assert_eq!(
+    test_subject_bidirectional_astar
+      .backward_astar
+      .peek_open_node(),
+    Some(TEST_GOAL_NODE)
+  );
+  assert_eq!(
+    test_subject_bidirectional_astar.best_path_cost,
+    f64::INFINITY
+  );
+  assert!(!test_subject_bidirectional_astar.is_goal_found());
+  assert_eq!(test_subject_bidirectional_astar.get_first_step(), None);
+}
+
+#[test]
+fn test_d_star_lite_compute_shortest_path() {
+  let mut test_subject_d_star_lite =
+    DStarLite::new(TEST_ORIGIN_NODE, TEST_GOAL_NODE);
+  assert!(!test_subject_d_star_lite.is_path_found());
+  assert!(
+    !test_subject_d_star_lite
+      .compute_shortest_path(&TEST_SUBJECT_GRID_CARTOGRAPHER, 1)
+  );
+  assert!(
+    test_subject_d_star_lite
+      .compute_shortest_path(&TEST_SUBJECT_GRID_CARTOGRAPHER, usize::MAX)
+  );
+  assert!(test_subject_d_star_lite.is_path_found());
+  assert_eq!(
+    test_subject_d_star_lite.get_path_cost(),
+    TEST_DISTANCE_TO_GOAL
+  );
+  let path = test_subject_d_star_lite.get_path(&TEST_SUBJECT_GRID_CARTOGRAPHER);
+  assert_eq!(path.len(), 4);
+  assert_eq!(path.back(), Some(&TEST_GOAL_NODE));
+  assert_eq!(
+    test_subject_d_star_lite.get_first_step(&TEST_SUBJECT_GRID_CARTOGRAPHER),
+    Some(TEST_BEST_NODE)
+  );
+}
+
+#[test]
+fn test_d_star_lite_move_start() {
+  let mut test_subject_d_star_lite =
+    DStarLite::new(TEST_ORIGIN_NODE, TEST_GOAL_NODE);
+  test_subject_d_star_lite
+    .compute_shortest_path(&TEST_SUBJECT_GRID_CARTOGRAPHER, usize::MAX);
+  test_subject_d_star_lite
+    .move_start(&TEST_SUBJECT_GRID_CARTOGRAPHER, TEST_BEST_NODE);
+  assert_eq!(test_subject_d_star_lite.key_modifier, 1.0);
+  test_subject_d_star_lite
+    .compute_shortest_path(&TEST_SUBJECT_GRID_CARTOGRAPHER, usize::MAX);
+  assert_eq!(
+    test_subject_d_star_lite.get_path_cost(),
+    TEST_DISTANCE_TO_GOAL - 1.0
+  );
+}
+
+#[test]
+fn test_expand_jump_points() {
+  let jump_point_path = VecDeque::from([
+    Point2DD::new(2.0, 2.0),
+    Point2DD::new(4.0, 2.0),
+    TEST_GOAL_NODE,
+  ]);
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER
+      .expand_jump_points(&TEST_ORIGIN_NODE, &jump_point_path),
+    VecDeque::from([
+      Point2DD::new(1.0, 1.0),
+      Point2DD::new(2.0, 2.0),
+      Point2DD::new(3.0, 2.0),
+      Point2DD::new(4.0, 2.0),
+      Point2DD::new(4.0, 1.0),
+      TEST_GOAL_NODE,
+    ])
+  );
+}
+
 #[test]
 fn test_get_first_step() {
   let test_subject_astar = AStar::<Point2DD>::default();
@@ -35,6 +223,74 @@ fn test_get_first_step() {
   assert_eq!(first_step_option, None);
 }
 
+#[test]
+fn test_get_best_cost() {
+  let mut test_subject_astar = AStar::<Point2DD>::default();
+  assert_eq!(test_subject_astar.get_best_cost(), None);
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  while test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER) {}
+  assert_eq!(
+    test_subject_astar.get_best_cost(),
+    Some(TEST_DISTANCE_TO_GOAL)
+  );
+}
+
+#[test]
+fn test_get_goal_index() {
+  let mut test_subject = GoalSetCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_nodes: vec![
+      Point2DD::new(3.0, 0.0),
+      Point2DD::new(0.0, -2.0),
+    ],
+    goal_radius: 0.0,
+    goal_radius_cost: 0.0,
+  };
+  assert_eq!(
+    test_subject.get_goal_index(&Point2DD::new(3.0, 0.0)),
+    Some(0)
+  );
+  assert_eq!(
+    test_subject.get_goal_index(&Point2DD::new(0.0, -2.0)),
+    Some(1)
+  );
+  assert_eq!(test_subject.get_goal_index(&TEST_ORIGIN_NODE), None);
+  test_subject.goal_radius = 0.5;
+  assert_eq!(
+    test_subject.get_goal_index(&Point2DD::new(0.0, -2.5)),
+    Some(1)
+  );
+  assert_eq!(test_subject.get_goal_index(&TEST_ORIGIN_NODE), None);
+  // The nearest of the goal nodes within the goal radius
+  test_subject.goal_radius = 5.0;
+  assert_eq!(test_subject.get_goal_index(&TEST_ORIGIN_NODE), Some(1));
+  assert_eq!(
+    test_subject.get_goal_index(&Point2DD::new(2.0, 0.0)),
+    Some(0)
+  );
+}
+
+#[test]
+fn test_get_open_node_count() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_astar.get_open_node_count(), 1);
+  for _ in 0..4 {
+    test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER);
+    // Each open node is counted once however many entries it has in the heap
+    let open_node_set: HashSet<Point2DD> = test_subject_astar
+      .open_node_heap
+      .iter()
+      .map(|open_node| open_node.node)
+      .filter(|node| !test_subject_astar.closed_node_set.contains(node))
+      .collect();
+    assert_eq!(
+      test_subject_astar.get_open_node_count(),
+      open_node_set.len()
+    );
+  }
+}
+
 #[test]
 fn test_get_path() {
   let test_subject_astar = AStar::<Point2DD>::default();
@@ -42,47 +298,648 @@ fn test_get_path() {
   assert_eq!(path, Vec::<Point2DD>::default());
 }
 
+#[test]
+fn test_grid_search_renderer() {
+  let mut astar: AStar<Point2DD> = AStar::default();
+  astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  let test_subject = GridSearchRenderer {
+    cell_size: 10.0,
+    column_count: 6,
+    is_space_available_fn: |node: &Point2DD| node.x < 5.0,
+    make_node_fn: Point2DD::new,
+    origin_node: Point2DD::new(0.0, -1.0),
+    row_count: 3,
+    step_size: 1.0,
+  };
+  assert_eq!(
+    test_subject.get_cell_position(&TEST_GOAL_NODE),
+    Some((4, 1))
+  );
+  assert_eq!(
+    test_subject.get_cell_position(&Point2DD::new(-1.0, 0.0)),
+    None
+  );
+  assert_eq!(
+    test_subject.to_cells(&astar, &TEST_ORIGIN_NODE)[1],
+    vec![
+      GridSearchCell::Start,
+      GridSearchCell::Path,
+      GridSearchCell::Path,
+      GridSearchCell::Path,
+      GridSearchCell::Goal,
+      GridSearchCell::Blocked,
+    ]
+  );
+  assert_eq!(
+    test_subject.to_ascii(&astar, &TEST_ORIGIN_NODE),
+    "oooo.#\nS***G#\noooo.#\n"
+  );
+  let svg: String = test_subject.to_svg(&astar, &TEST_ORIGIN_NODE);
+  assert!(svg.starts_with("<svg "));
+  assert!(svg.ends_with("</svg>\n"));
+  assert_eq!(svg.matches("<rect ").count(), 18);
+  assert!(svg.contains("points=\"5,15 15,15 25,15 35,15 45,15\""));
+}
+
 #[test]
 fn test_is_goal_found() {
   let test_subject_astar = AStar::<Point2DD>::default();
   assert!(!test_subject_astar.is_goal_found());
 }
 
+#[test]
+fn test_iterative_deepening_astar_loop_once() {
+  let mut test_subject_iterative_deepening_astar =
+    IterativeDeepeningAStar::<Point2DD>::default();
+  assert!(
+    !test_subject_iterative_deepening_astar
+      .loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER)
+  );
+  test_subject_iterative_deepening_astar.reset(TEST_ORIGIN_NODE);
+  assert!(
+    !test_subject_iterative_deepening_astar
+      .loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER)
+  );
+  assert!(test_subject_iterative_deepening_astar.is_goal_found());
+  assert_eq!(test_subject_iterative_deepening_astar.iteration_count, 1);
+  assert_eq!(
+    test_subject_iterative_deepening_astar.cost_threshold,
+    TEST_DISTANCE_TO_GOAL
+  );
+  assert_eq!(
+    test_subject_iterative_deepening_astar.path_cost,
+    TEST_DISTANCE_TO_GOAL
+  );
+  assert_eq!(
+    test_subject_iterative_deepening_astar.get_path(),
+    VecDeque::from([
+      TEST_BEST_NODE,
+      Point2DD::new(2.0, 0.0),
+      Point2DD::new(3.0, 0.0),
+      TEST_GOAL_NODE,
+    ])
+  );
+  assert_eq!(
+    test_subject_iterative_deepening_astar.get_first_step(),
+    Some(TEST_BEST_NODE)
+  );
+}
+
+#[test]
+fn test_iterative_deepening_astar_reset() {
+  let mut test_subject_iterative_deepening_astar = IterativeDeepeningAStar {
+    cost_threshold: 1.0,
+    iteration_count: 1,
+    path_cost: 1.0,
+    path_node_list: vec![
+      TEST_ORIGIN_NODE,
+      TEST_BEST_NODE,
+    ],
+    start_node_option: None,
+  };
+  test_subject_iterative_deepening_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_iterative_deepening_astar.cost_threshold, 0.0);
+  assert_eq!(test_subject_iterative_deepening_astar.iteration_count, 0);
+  assert_eq!(
+    test_subject_iterative_deepening_astar.path_cost,
+    f64::INFINITY
+  );
+  assert!(!test_subject_iterative_deepening_astar.is_goal_found());
+  assert_eq!(
+    test_subject_iterative_deepening_astar.start_node_option,
+    Some(TEST_ORIGIN_NODE)
+  );
+}
+
+#[test]
+fn test_jump() {
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER.jump(0.0, 0.0, 1.0, 0.0),
+    Some(Point2DD::new(3.0, 0.0))
+  );
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER.jump(0.0, 0.0, -1.0, 0.0),
+    None
+  );
+}
+
 #[test]
 fn test_loop_once() {
   let mut test_subject_astar: AStar<Point2DD> = AStar::default();
   assert!(!test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER));
 }
 
+#[test]
+fn test_loop_once_anytime() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar {
+    anytime: true,
+    heuristic_weight: 3.0,
+    heuristic_weight_decrement: 1.0,
+    ..Default::default()
+  };
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  let mut suboptimality_bounds: Vec<f64> = Vec::new();
+  while test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER) {
+    if test_subject_astar.is_goal_found()
+      && suboptimality_bounds.last()
+        != Some(&test_subject_astar.suboptimality_bound)
+    {
+      suboptimality_bounds.push(test_subject_astar.suboptimality_bound);
+    }
+  }
+  assert_eq!(
+    suboptimality_bounds,
+    vec![
+      3.0, 2.0
+    ]
+  );
+  assert_eq!(test_subject_astar.search_heuristic_weight, 1.0);
+  assert_eq!(test_subject_astar.suboptimality_bound, 1.0);
+  assert_eq!(
+    test_subject_astar.get_best_cost(),
+    Some(TEST_DISTANCE_TO_GOAL)
+  );
+  assert!(test_subject_astar.inconsistent_node_list.is_empty());
+}
+
+#[test]
+fn test_loop_once_weighted() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar {
+    heuristic_weight: 2.0,
+    ..Default::default()
+  };
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_astar.suboptimality_bound, f64::INFINITY);
+  while test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER) {}
+  assert_eq!(
+    test_subject_astar.get_best_cost(),
+    Some(TEST_DISTANCE_TO_GOAL)
+  );
+  assert_eq!(test_subject_astar.suboptimality_bound, 2.0);
+  let node_info: &NodeInfo =
+    &test_subject_astar.node_to_node_info_map[&TEST_BEST_NODE];
+  assert_eq!(node_info.total_cost, 1.0 + 2.0 * 3.0);
+}
+
+#[test]
+fn test_loop_once_any_angle() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar {
+    any_angle: true,
+    ..Default::default()
+  };
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert!(test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER));
+  assert!(test_subject_astar.loop_once(&TEST_SUBJECT_GRID_CARTOGRAPHER));
+  let node = Point2DD::new(2.0, 1.0);
+  assert_eq!(
+    test_subject_astar.node_to_parent_node_map.get(&node),
+    Some(&TEST_ORIGIN_NODE)
+  );
+}
+
+#[test]
+fn test_peek_open_node() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  assert_eq!(test_subject_astar.peek_open_node(), None);
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_astar.peek_open_node(), Some(TEST_ORIGIN_NODE));
+}
+
+#[test]
+fn test_peek_open_node_info() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  assert_eq!(test_subject_astar.peek_open_node_info(), None);
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(
+    test_subject_astar.peek_open_node_info(),
+    Some(NodeInfo::default())
+  );
+}
+
 #[test]
 fn test_reset() {
   let mut test_subject_astar: AStar<Point2DD> = AStar {
+    any_angle: true,
+    anytime: true,
     best_node_option: Some(TEST_BEST_NODE),
     best_total_cost: 0.0,
+    closed_node_set: HashSet::from([TEST_BEST_NODE]),
     goal_node_option: Some(TEST_GOAL_NODE),
+    heuristic_weight: 2.0,
+    heuristic_weight_decrement: 0.5,
+    inconsistent_node_list: vec![TEST_BEST_NODE],
     list_empty: true,
     node_to_node_info_map: HashMap::new(),
     node_to_parent_node_map: HashMap::new(),
-    open_node_sorted_list: VecDeque::new(),
+    open_node_heap: BinaryHeap::new(),
+    open_node_sequence: 1,
+    search_heuristic_weight: 1.0,
+    statistics_option: Some(SearchStatistics {
+      nodes_expanded: 1,
+      ..Default::default()
+    }),
+    suboptimality_bound: 1.0,
   };
   test_subject_astar.reset(TEST_ORIGIN_NODE);
   let AStar {
+    any_angle,
+    anytime,
     best_node_option: _,
     best_total_cost,
+    closed_node_set,
     goal_node_option,
+    heuristic_weight,
+    heuristic_weight_decrement,
+    inconsistent_node_list,
     list_empty,
     node_to_node_info_map,
     node_to_parent_node_map,
-    open_node_sorted_list,
+    open_node_heap,
+    open_node_sequence,
+    search_heuristic_weight,
+    statistics_option,
+    suboptimality_bound,
   } = test_subject_astar;
-  assert_eq!(best_total_cost, INFINITY);
+  assert!(any_angle);
+  assert!(anytime);
+  assert_eq!(heuristic_weight, 2.0);
+  assert_eq!(heuristic_weight_decrement, 0.5);
+  assert!(inconsistent_node_list.is_empty());
+  assert_eq!(search_heuristic_weight, 2.0);
+  assert_eq!(
+    statistics_option,
+    Some(SearchStatistics {
+      peak_open_list_size: 1,
+      ..Default::default()
+    })
+  );
+  assert_eq!(suboptimality_bound, f64::INFINITY);
+  assert_eq!(best_total_cost, f64::INFINITY);
+  assert!(closed_node_set.is_empty());
   assert_eq!(goal_node_option, None);
   assert!(!list_empty);
-  let mut expected_open_node_sorted_list = VecDeque::new();
-  expected_open_node_sorted_list.push_front(TEST_ORIGIN_NODE);
-  assert_eq!(open_node_sorted_list, expected_open_node_sorted_list);
+  let open_nodes: Vec<OpenNode<Point2DD>> = open_node_heap.into_vec();
+  assert_eq!(open_nodes.len(), 1);
+  assert_eq!(open_nodes[0].node, TEST_ORIGIN_NODE);
+  assert_eq!(open_nodes[0].node_info, NodeInfo::default());
+  assert_eq!(open_nodes[0].sequence, 0);
+  assert_eq!(open_node_sequence, 1);
   let mut expected_node_to_node_info_map = HashMap::new();
   expected_node_to_node_info_map.insert(TEST_ORIGIN_NODE, NodeInfo::default());
   assert_eq!(node_to_node_info_map, expected_node_to_node_info_map);
   assert!(node_to_parent_node_map.is_empty());
 }
+
+#[test]
+fn test_reset_best_node() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar {
+    best_node_option: Some(TEST_BEST_NODE),
+    ..Default::default()
+  };
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_astar.best_node_option, None);
+  assert_eq!(test_subject_astar.get_first_step(), None);
+}
+
+#[test]
+fn test_search() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  let search_outcome = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = search_outcome
+  else {
+    panic!("unexpected search outcome {search_outcome:?}");
+  };
+  assert_eq!(cost, TEST_DISTANCE_TO_GOAL);
+  assert_eq!(path.len(), 4);
+  assert_eq!(path.back(), Some(&TEST_GOAL_NODE));
+}
+
+#[test]
+fn test_search_budget_exhausted() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  let search_outcome = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget {
+      max_expansions: 1,
+      ..Default::default()
+    },
+  );
+  assert_eq!(
+    search_outcome,
+    SearchOutcome::BudgetExhausted {
+      cost: 1.0,
+      path: VecDeque::from([TEST_BEST_NODE]),
+    }
+  );
+  let search_outcome = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget {
+      max_path_cost: TEST_DISTANCE_TO_GOAL - 1.0,
+      ..Default::default()
+    },
+  );
+  assert!(matches!(
+    search_outcome,
+    SearchOutcome::BudgetExhausted { .. }
+  ));
+  // No node was reached so there is no partial path
+  let search_outcome = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget {
+      max_expansions: 0,
+      ..Default::default()
+    },
+  );
+  assert_eq!(
+    search_outcome,
+    SearchOutcome::BudgetExhausted {
+      cost: f64::INFINITY,
+      path: VecDeque::new(),
+    }
+  );
+}
+
+#[cfg(test)]
+const TEST_SQUARE_OBSTACLE: VisibilityObstacle =
+  VisibilityObstacle::Rectangle(Rectangle {
+    x_max: 2.0,
+    x_min: 0.0,
+    y_max: 2.0,
+    y_min: 0.0,
+  });
+
+#[test]
+fn test_search_goal_set() {
+  let mut goal_set_cartographer = GoalSetCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_nodes: vec![
+      Point2DD::new(-3.0, 0.0),
+      Point2DD::new(0.0, 2.0),
+    ],
+    goal_radius: 0.0,
+    goal_radius_cost: 0.0,
+  };
+  let mut test_subject_astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &goal_set_cartographer,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("goal not found");
+  };
+  assert_eq!(cost, 2.0);
+  assert_eq!(
+    goal_set_cartographer.get_goal_index(path.back().unwrap()),
+    Some(1)
+  );
+  assert_eq!(
+    goal_set_cartographer.get_reached_goal_index(&test_subject_astar),
+    Some(1)
+  );
+  // Arriving within the goal radius
+  goal_set_cartographer.goal_radius = 1.0;
+  goal_set_cartographer.goal_radius_cost = 1.0;
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &goal_set_cartographer,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("goal not found");
+  };
+  assert_eq!(cost, 1.0);
+  assert_eq!(path.back(), Some(&Point2DD::new(0.0, 1.0)));
+  // The start node is a goal so the path is empty
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = test_subject_astar.search(
+    Point2DD::new(-3.0, 0.0),
+    &goal_set_cartographer,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("goal not found");
+  };
+  assert_eq!(cost, 0.0);
+  assert!(path.is_empty());
+  assert_eq!(
+    test_subject_astar.get_goal_node(),
+    Some(Point2DD::new(-3.0, 0.0))
+  );
+  assert_eq!(
+    goal_set_cartographer.get_reached_goal_index(&test_subject_astar),
+    Some(0)
+  );
+  // Nothing is reached before the search
+  test_subject_astar.reset(TEST_ORIGIN_NODE);
+  assert_eq!(test_subject_astar.get_goal_node(), None);
+  assert_eq!(
+    goal_set_cartographer.get_reached_goal_index(&test_subject_astar),
+    None
+  );
+}
+
+#[test]
+fn test_search_integer_cost() {
+  let mut test_subject_astar: AStar<i64, u32> = AStar::default();
+  let search_outcome = test_subject_astar.search(
+    0,
+    &IntegerCostCartographer,
+    &SearchBudget::default(),
+  );
+  // Skips the expensive single step with a double step
+  assert_eq!(
+    search_outcome,
+    SearchOutcome::GoalFound {
+      cost: 5,
+      path: VecDeque::from([
+        1, 3, 4
+      ]),
+    }
+  );
+  let search_outcome = test_subject_astar.search(
+    0,
+    &IntegerCostCartographer,
+    &SearchBudget {
+      max_path_cost: 2,
+      ..Default::default()
+    },
+  );
+  assert!(matches!(
+    search_outcome,
+    SearchOutcome::BudgetExhausted { .. }
+  ));
+}
+
+#[test]
+fn test_search_observed() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  let mut expanded_node_list: Vec<Point2DD> = Vec::new();
+  let mut push_count: usize = 0;
+  test_subject_astar.search_observed(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+    &mut |search_event| match search_event {
+      SearchEvent::Expand {
+        node,
+        ..
+      } => expanded_node_list.push(*node),
+      SearchEvent::Push {
+        ..
+      } => push_count += 1,
+    },
+  );
+  assert_eq!(
+    expanded_node_list,
+    vec![
+      TEST_ORIGIN_NODE,
+      TEST_BEST_NODE,
+      Point2DD::new(2.0, 0.0),
+      Point2DD::new(3.0, 0.0),
+      TEST_GOAL_NODE,
+    ]
+  );
+  assert_eq!(push_count, test_subject_astar.open_node_sequence as usize);
+}
+
+#[test]
+fn test_search_statistics() {
+  let mut test_subject_astar: AStar<Point2DD> = AStar::default();
+  test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  assert_eq!(test_subject_astar.statistics_option, None);
+  test_subject_astar.statistics_option = Some(SearchStatistics::default());
+  test_subject_astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  let statistics: SearchStatistics =
+    test_subject_astar.statistics_option.unwrap();
+  assert_eq!(statistics.nodes_expanded, 5);
+  // Eight adjacent nodes for each expanded node until the goal is adjacent
+  assert_eq!(statistics.nodes_generated, 8 * 3 + 1);
+  // The goal was popped after the last push
+  assert_eq!(
+    statistics.peak_open_list_size,
+    test_subject_astar.open_node_heap.len() + 1
+  );
+  assert_eq!(statistics.reopenings, 0);
+}
+
+#[test]
+fn test_is_visible() {
+  let test_subject = VisibilityGraphCartographer::new(
+    0.0,
+    Point2DD::new(3.0, 1.0),
+    &[TEST_SQUARE_OBSTACLE],
+    4,
+  );
+  assert!(
+    !test_subject
+      .is_visible(&Point2DD::new(-1.0, 1.0), &Point2DD::new(3.0, 1.0))
+  );
+  // Through opposite corners
+  assert!(
+    !test_subject
+      .is_visible(&Point2DD::new(0.0, 0.0), &Point2DD::new(2.0, 2.0))
+  );
+  // Along an edge and touching a corner
+  assert!(
+    test_subject
+      .is_visible(&Point2DD::new(-1.0, 0.0), &Point2DD::new(3.0, 0.0))
+  );
+  assert!(
+    test_subject
+      .is_visible(&Point2DD::new(-1.0, 1.0), &Point2DD::new(1.0, 3.0))
+  );
+  assert!(test_subject.is_inside_obstacle(&Point2DD::new(1.0, 1.0)));
+  assert!(!test_subject.is_inside_obstacle(&Point2DD::new(0.0, 1.0)));
+  // Each corner sees the two adjacent corners but not the opposite corner
+  assert_eq!(test_subject.vertex_to_visible_vertices_map.len(), 4);
+  assert_eq!(
+    test_subject.vertex_to_visible_vertices_map[&Point2DD::new(0.0, 0.0)],
+    vec![
+      Point2DD::new(2.0, 0.0),
+      Point2DD::new(0.0, 2.0),
+    ]
+  );
+}
+
+#[test]
+fn test_to_inflated_polygon() {
+  assert_eq!(
+    TEST_SQUARE_OBSTACLE.to_inflated_polygon(0.0, 4),
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(2.0, 0.0),
+      Point2DD::new(2.0, 2.0),
+      Point2DD::new(0.0, 2.0),
+    ]
+  );
+  // The corners of a quarter turn are each replaced by one circumscribed
+  // corner
+  let expected_list = [
+    Point2DD::new(-1.0, -1.0),
+    Point2DD::new(3.0, -1.0),
+    Point2DD::new(3.0, 3.0),
+    Point2DD::new(-1.0, 3.0),
+  ];
+  let inflated_list = TEST_SQUARE_OBSTACLE.to_inflated_polygon(1.0, 4);
+  assert_eq!(inflated_list.len(), 4);
+  for (inflated_vertex, expected_vertex) in
+    inflated_list.iter().zip(expected_list.iter())
+  {
+    assert!(inflated_vertex.is_near(expected_vertex, TEST_TOLERANCE));
+  }
+  // Clockwise vertices are reversed
+  let triangle_obstacle = VisibilityObstacle::ConvexPolygon(vec![
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(0.0, 1.0),
+    Point2DD::new(1.0, 0.0),
+  ]);
+  assert_eq!(
+    triangle_obstacle.to_inflated_polygon(0.0, 4),
+    vec![
+      Point2DD::new(1.0, 0.0),
+      Point2DD::new(0.0, 1.0),
+      Point2DD::new(0.0, 0.0),
+    ]
+  );
+  // A circle is circumscribed by the polygon
+  let circle_obstacle = VisibilityObstacle::Circle(Circle {
+    center_x: 1.0,
+    center_y: 1.0,
+    radius: 0.5,
+  });
+  let inflated_list = circle_obstacle.to_inflated_polygon(0.5, 4);
+  assert_eq!(inflated_list.len(), 4);
+  assert!(inflated_list[0].is_near(&Point2DD::new(2.0, 2.0), TEST_TOLERANCE));
+  assert!(inflated_list[2].is_near(&Point2DD::new(0.0, 0.0), TEST_TOLERANCE));
+}
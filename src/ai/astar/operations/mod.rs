@@ -2,12 +2,12 @@
 //! - Operation implementations for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-05-09
 //! - Rust created: 2022-10-22
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the classes in the Java-based [`CroftSoft Core Library`]
@@ -18,50 +18,74 @@
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
-use super::structures::NodeInfo;
+use super::structures::{
+  DStarLiteKey, DStarLiteOpenNode, NodeInfo, OpenNode, SearchEvent,
+};
+use super::traits::PathCost;
 use std::cmp::Ordering;
 
 #[cfg(test)]
 mod test;
 
-impl PartialEq for NodeInfo {
+impl PartialOrd for DStarLiteKey {
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<Ordering> {
+    match self.primary.partial_cmp(&other.primary) {
+      Some(Ordering::Equal) => self.secondary.partial_cmp(&other.secondary),
+      ordering => ordering,
+    }
+  }
+}
+
+impl<N> PartialEq for DStarLiteOpenNode<N> {
   fn eq(
     &self,
     other: &Self,
   ) -> bool {
-    self.total_cost.eq(&other.total_cost)
+    self.cmp(other) == Ordering::Equal
   }
 }
 
-impl PartialOrd for NodeInfo {
-  fn lt(
+impl<N> PartialOrd for DStarLiteOpenNode<N> {
+  fn partial_cmp(
     &self,
     other: &Self,
-  ) -> bool {
-    self.total_cost.lt(&other.total_cost)
+  ) -> Option<Ordering> {
+    Some(self.cmp(other))
   }
+}
 
-  fn le(
+/// Consistent with the total order of Ord so that NaN equals itself
+impl<C: PathCost> PartialEq for NodeInfo<C> {
+  fn eq(
     &self,
     other: &Self,
   ) -> bool {
-    self.total_cost.le(&other.total_cost)
+    self.cmp(other) == Ordering::Equal
   }
+}
 
-  fn gt(
+impl<C: PathCost> PartialOrd for NodeInfo<C> {
+  fn partial_cmp(
     &self,
     other: &Self,
-  ) -> bool {
-    self.total_cost.gt(&other.total_cost)
+  ) -> Option<Ordering> {
+    Some(self.cmp(other))
   }
+}
 
-  fn ge(
+impl<N, C: PathCost> PartialEq for OpenNode<N, C> {
+  fn eq(
     &self,
     other: &Self,
   ) -> bool {
-    self.total_cost.ge(&other.total_cost)
+    self.cmp(other) == Ordering::Equal
   }
+}
 
+impl<N, C: PathCost> PartialOrd for OpenNode<N, C> {
   fn partial_cmp(
     &self,
     other: &Self,
@@ -69,3 +93,34 @@ impl PartialOrd for NodeInfo {
     Some(self.cmp(other))
   }
 }
+
+impl<N: PartialEq, C: PathCost> PartialEq for SearchEvent<N, C> {
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool {
+    match (self, other) {
+      (
+        SearchEvent::Expand {
+          node,
+          node_info,
+        },
+        SearchEvent::Expand {
+          node: other_node,
+          node_info: other_node_info,
+        },
+      )
+      | (
+        SearchEvent::Push {
+          node,
+          node_info,
+        },
+        SearchEvent::Push {
+          node: other_node,
+          node_info: other_node_info,
+        },
+      ) => node == other_node && node_info == other_node_info,
+      _ => false,
+    }
+  }
+}
@@ -2,16 +2,16 @@
 //! - Unit tests for the A* algorithm operations
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-10-22
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
-use crate::ai::astar::structures::NodeInfo;
+use crate::ai::astar::structures::{DStarLiteKey, NodeInfo};
 use std::cmp::Ordering;
 
 #[cfg(test)]
@@ -55,3 +55,22 @@ fn test_lt() {
 fn test_partial_cmp() {
   assert_eq!(NODE_INFO_0.partial_cmp(&NODE_INFO_1), Some(Ordering::Less));
 }
+
+#[test]
+fn test_partial_cmp_d_star_lite_key() {
+  let key_0 = DStarLiteKey {
+    primary: 0.0,
+    secondary: 1.0,
+  };
+  let key_1 = DStarLiteKey {
+    primary: 1.0,
+    secondary: 0.0,
+  };
+  let key_2 = DStarLiteKey {
+    primary: 1.0,
+    secondary: 1.0,
+  };
+  assert!(key_0 < key_1);
+  assert!(key_1 < key_2);
+  assert_eq!(key_2.partial_cmp(&key_2), Some(Ordering::Equal));
+}
@@ -2,12 +2,12 @@
 //! - Structures for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-05-10
 //! - Rust created: 2022-10-22
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the classes in the Java-based [`CroftSoft Core Library`]
@@ -19,45 +19,410 @@
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
-use super::types::{IsSpaceAvailableFunction, MakeNodeFunction};
+use super::traits::{Cartographer, PathCost};
+use super::types::{
+  IsSpaceAvailableFunction, MakeNodeFunction, MakeNodeXYZFunction,
+  TerrainMultiplierFunction,
+};
+use crate::math::geom::circle::Circle;
+use crate::math::geom::hex_coordinate::HexCoordinate;
+use crate::math::geom::point_2dd::Point2DD;
+use crate::math::geom::rectangle::Rectangle;
 use core::hash::Hash;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::time::Duration;
 
-pub struct AStar<N: Eq + Hash> {
+/// A* search.
+/// The cost type defaults to f64.
+pub struct AStar<N: Eq + Hash, C: PathCost = f64> {
+  /// Theta* any-angle mode which links a node to its grandparent when the
+  /// cartographer reports a line of sight between them
+  pub any_angle: bool,
+  /// Anytime repairing A* (ARA*) mode which keeps improving the path found
+  /// with the inflated heuristic by lowering the weight after each solution
+  /// until the weight is one
+  pub anytime: bool,
   pub best_node_option: Option<N>,
-  pub best_total_cost: f64,
+  pub best_total_cost: C,
+  pub closed_node_set: HashSet<N>,
   pub goal_node_option: Option<N>,
+  /// Inflation factor for the cost estimate to the goal (weighted A*).
+  /// A path found costs at most this many times the optimal cost.
+  pub heuristic_weight: f64,
+  /// How much the weight is lowered after each solution in anytime mode
+  pub heuristic_weight_decrement: f64,
+  /// Closed nodes with an improved cost to be reopened in the next phase of
+  /// an anytime search
+  pub inconsistent_node_list: Vec<N>,
   pub list_empty: bool,
-  pub node_to_node_info_map: HashMap<N, NodeInfo>,
+  pub node_to_node_info_map: HashMap<N, NodeInfo<C>>,
   pub node_to_parent_node_map: HashMap<N, N>,
-  pub open_node_sorted_list: VecDeque<N>,
+  /// Priority queue with lazy deletion of stale entries
+  pub open_node_heap: BinaryHeap<OpenNode<N, C>>,
+  /// Insertion counter used to break ties in first-in first-out order
+  pub open_node_sequence: u64,
+  /// The heuristic weight of the current search phase
+  pub search_heuristic_weight: f64,
+  /// Statistics for the current search which are collected only when this is
+  /// Some.
+  /// Reset to zero when the search is reset.
+  pub statistics_option: Option<SearchStatistics>,
+  /// The path found costs at most this many times the optimal cost.
+  /// Infinite until the goal is found.
+  pub suboptimality_bound: f64,
+}
+
+/// Bidirectional A* search.
+/// Grows one frontier from the start and one from the goal and stops when
+/// the best meeting point found cannot be improved.
+pub struct BidirectionalAStar<N: Eq + Hash> {
+  /// Searches backward from the goal using the predecessor nodes
+  pub backward_astar: AStar<N>,
+  /// The cost of the best path through a meeting node found so far
+  pub best_path_cost: f64,
+  pub forward_astar: AStar<N>,
+  pub list_empty: bool,
+  pub meeting_node_option: Option<N>,
+  pub start_node_option: Option<N>,
+}
+
+/// D* Lite incremental planner.
+/// Searches backward from the goal so that the previous solution can be
+/// repaired when edges change or the start moves instead of replanning from
+/// scratch.
+/// The heuristic is derived from the cartographer estimates to the goal so
+/// the cartographer goal should be the same as the planner goal.
+pub struct DStarLite<N: Eq + Hash> {
+  pub goal_node: N,
+  /// Accumulated heuristic change as the start moves, known as km
+  pub key_modifier: f64,
+  /// The cost to the goal, known as g
+  pub node_to_cost_map: HashMap<N, f64>,
+  /// The one-step lookahead cost to the goal, known as rhs
+  pub node_to_lookahead_cost_map: HashMap<N, f64>,
+  /// The current open node keys; heap entries not matching are stale
+  pub node_to_open_key_map: HashMap<N, DStarLiteKey>,
+  pub open_node_heap: BinaryHeap<DStarLiteOpenNode<N>>,
+  pub start_node: N,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// D* Lite priority key compared lexicographically
+pub struct DStarLiteKey {
+  pub primary: f64,
+  pub secondary: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// An entry in the D* Lite open node priority queue
+pub struct DStarLiteOpenNode<N> {
+  pub key: DStarLiteKey,
+  pub node: N,
+}
+
+/// Replaces the goal of a cartographer so that a cartographer can be shared
+/// by searches with different goals.
+/// The estimates come from the estimate_cost_between of the cartographer.
+/// The adjacent nodes come from the get_goal_free_adjacent_nodes of the
+/// cartographer so that it never jumps to its own goal.
+/// The successor nodes are not pruned since the pruning can depend on the goal.
+pub struct GoalCartographer<'a, N, C: PathCost = f64> {
+  pub cartographer: &'a dyn Cartographer<N, C>,
+  pub goal_node: N,
+}
+
+/// Replaces the goal of a cartographer with several goals so that a single
+/// search finds the path to the nearest goal.
+/// A node is a goal if it is within the goal radius of any of the goal nodes.
+/// The adjacent nodes and the successor nodes are those of the
+/// GoalCartographer.
+/// The estimate is the minimum over the goal nodes of the estimate_cost_between
+/// of the cartographer less the goal radius cost.
+/// This assumes that the estimates are admissible and obey the triangle
+/// inequality.
+pub struct GoalSetCartographer<'a, N, C: PathCost = f64> {
+  pub cartographer: &'a dyn Cartographer<N, C>,
+  /// Zero if only the goal nodes themselves are goals
+  pub goal_radius: f64,
+  /// The most that the estimate_cost_between a goal node and a node within
+  /// the goal radius of it can be, such as the goal radius times the
+  /// min_terrain_multiplier of a TerrainCartographer.
+  /// Zero if the goal radius is zero.
+  pub goal_radius_cost: C,
+  pub goal_nodes: Vec<N>,
 }
 
 /// Gradient cartographer for continuous space.
 /// The adjacent nodes are spaced farther apart as you move away from the
 /// starting point.
-pub struct GradientCartographer<N> {
+/// The functions can be plain function pointers or capturing closures.
+pub struct GradientCartographer<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeFunction<N>,
+> {
   pub directions: u64,
   pub goal_node: N,
   pub init_step_size: f64,
-  pub is_space_available_fn: IsSpaceAvailableFunction<N>,
-  pub make_node_fn: MakeNodeFunction<N>,
+  pub is_space_available_fn: S,
+  pub make_node_fn: M,
   pub start_node: N,
 }
 
 /// Grid cartographer for continuous space.
 /// The nodes are spaced equally apart in the eight cardinal directions.
-pub struct GridCartographer<N> {
+/// The functions can be plain function pointers or capturing closures.
+pub struct GridCartographer<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeFunction<N>,
+> {
+  pub goal_node: N,
+  pub is_space_available_fn: S,
+  pub make_node_fn: M,
+  pub step_size: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// A cell of a grid search rendered by the GridSearchRenderer
+pub enum GridSearchCell {
+  /// A cell which is not available
+  Blocked,
+  /// A node which was expanded
+  Closed,
+  /// An available cell which the search did not reach
+  Free,
+  Goal,
+  /// A node which is still on the open list
+  Open,
+  /// A node on the path to the goal or to the best node found so far
+  Path,
+  Start,
+}
+
+/// Renders the explored region, the open nodes, and the path of a grid
+/// search as ASCII art or as an SVG image.
+/// The cells are spaced step_size apart starting from the origin node with
+/// the rows in the order of increasing y.
+/// The functions can be plain function pointers or capturing closures.
+pub struct GridSearchRenderer<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeFunction<N>,
+> {
+  /// The width and height of a cell in the SVG image
+  pub cell_size: f64,
+  pub column_count: usize,
+  pub is_space_available_fn: S,
+  pub make_node_fn: M,
+  /// The node in the first column of the first row
+  pub origin_node: N,
+  pub row_count: usize,
+  pub step_size: f64,
+}
+
+/// Hexagonal grid cartographer using axial coordinates.
+/// Each hex has six adjacent hexes at a cost of one.
+/// The function can be a plain function pointer or a capturing closure.
+pub struct HexCartographer<S = IsSpaceAvailableFunction<HexCoordinate>> {
+  pub goal_node: HexCoordinate,
+  pub is_space_available_fn: S,
+}
+
+/// Iterative-deepening A* (IDA*).
+/// Repeats a depth-first search with a cost threshold which grows to the
+/// lowest total cost which exceeded it in the previous iteration.
+/// Memory use is linear in the depth of the path.
+pub struct IterativeDeepeningAStar<N> {
+  /// The maximum total cost of the nodes expanded in the next iteration
+  pub cost_threshold: f64,
+  pub iteration_count: usize,
+  /// Infinite until the goal is found
+  pub path_cost: f64,
+  /// The nodes from the start to the goal once the goal is found
+  pub path_node_list: Vec<N>,
+  pub start_node_option: Option<N>,
+}
+
+/// Jump Point Search (JPS) cartographer for uniform-cost grids.
+/// Uses the same grid conventions as the GridCartographer but returns only
+/// the jump points, pruning the symmetric paths between them.
+/// A jump stops after jump_limit steps so that unbounded grids terminate.
+pub struct JumpPointCartographer<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeFunction<N>,
+> {
   pub goal_node: N,
-  pub is_space_available_fn: IsSpaceAvailableFunction<N>,
-  pub make_node_fn: MakeNodeFunction<N>,
+  pub is_space_available_fn: S,
+  pub jump_limit: usize,
+  pub make_node_fn: M,
   pub step_size: f64,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
-/// A* algorithm node information
-pub struct NodeInfo {
-  pub cost_from_start: f64,
-  pub total_cost: f64,
+/// A* algorithm node information.
+/// Equal and ordered by the total cost only.
+pub struct NodeInfo<C = f64> {
+  pub cost_from_start: C,
+  pub total_cost: C,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// An entry in the A* algorithm open node priority queue.
+/// The entry is stale if the node info no longer matches the node info map.
+pub struct OpenNode<N, C = f64> {
+  pub node: N,
+  pub node_info: NodeInfo<C>,
+  pub sequence: u64,
+}
+
+/// Reverses the direction of a cartographer for searching backward from the
+/// goal to the start.
+/// The estimate to the start comes from the estimate_cost_between of the
+/// cartographer.
+pub struct ReverseCartographer<'a, N> {
+  pub cartographer: &'a dyn Cartographer<N>,
+  pub start_node: N,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Limits for a run-to-completion A* search.
+/// The default is unlimited.
+pub struct SearchBudget<C = f64> {
+  /// Maximum number of calls to loop_once
+  pub max_expansions: usize,
+  /// Maximum number of nodes stored in the node info map
+  pub max_nodes: usize,
+  /// Searching stops when the lowest total cost exceeds this
+  pub max_path_cost: C,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// An event reported to the observer of an A* search
+pub enum SearchEvent<N, C = f64> {
+  /// A node was popped from the open list to be expanded
+  Expand {
+    node: N,
+    node_info: NodeInfo<C>,
+  },
+  /// A node was pushed onto the open list
+  Push {
+    node: N,
+    node_info: NodeInfo<C>,
+  },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// The result of a run-to-completion A* search
+pub enum SearchOutcome<N, C = f64> {
+  /// The search stopped early with the path to the best node found so far.
+  /// The cost is infinite and the path is empty if no node was reached.
+  BudgetExhausted {
+    cost: C,
+    path: VecDeque<N>,
+  },
+  GoalFound {
+    cost: C,
+    path: VecDeque<N>,
+  },
+  /// The open list emptied without reaching the goal
+  Unreachable,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Statistics collected by an A* search
+pub struct SearchStatistics {
+  /// The nodes popped from the open list
+  pub nodes_expanded: usize,
+  /// The adjacent nodes returned by the cartographer for the expanded nodes
+  pub nodes_generated: usize,
+  /// The most entries in the open node priority queue including stale entries
+  pub peak_open_list_size: usize,
+  /// The closed nodes which were reached again by a cheaper path
+  pub reopenings: usize,
+  /// The time spent in loop_once
+  pub wall_time: Duration,
+}
+
+/// Multiplies the costs to the adjacent nodes of the wrapped cartographer by
+/// a terrain multiplier.
+/// The estimates are scaled by the lowest multiplier so that they stay
+/// admissible.
+pub struct TerrainCartographer<'a, N, T = TerrainMultiplierFunction<N>> {
+  pub cartographer: &'a dyn Cartographer<N>,
+  /// The lowest multiplier which the terrain multiplier function returns
+  pub min_terrain_multiplier: f64,
+  /// Multiplies the cost from a node to an adjacent node.
+  /// A multiplier per cell can ignore the from node.
+  pub terrain_multiplier_fn: T,
+}
+
+/// Visibility graph cartographer for continuous space with convex obstacles.
+/// The obstacles are inflated by the radius of the agent so that the agent
+/// can be treated as a point.
+/// The nodes are the vertices of the inflated obstacles plus the node being
+/// expanded and the goal.
+/// Two nodes are adjacent if the straight line between them does not pass
+/// through the interior of an inflated obstacle.
+/// A* then finds the shortest path around the inflated obstacles.
+pub struct VisibilityGraphCartographer {
+  pub goal_node: Point2DD,
+  /// The inflated obstacles as convex polygons with counterclockwise vertices
+  pub polygon_list: Vec<Vec<Point2DD>>,
+  /// The visible vertices of each vertex which is outside of the obstacles
+  pub vertex_to_visible_vertices_map: HashMap<Point2DD, Vec<Point2DD>>,
+}
+
+#[derive(Clone, Debug)]
+/// A convex obstacle for the VisibilityGraphCartographer
+pub enum VisibilityObstacle {
+  /// Inflated as a polygon which circumscribes the inflated circle
+  Circle(Circle),
+  /// The vertices in either winding order.
+  /// The corners are rounded by the inflation.
+  ConvexPolygon(Vec<Point2DD>),
+  /// The corners are rounded by the inflation
+  Rectangle(Rectangle),
+}
+
+/// Voxel cartographer for three dimensional space.
+/// The nodes are spaced equally apart on a cubic grid.
+/// The goal node should be on the grid.
+/// The functions can be plain function pointers or capturing closures.
+pub struct VoxelCartographer<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeXYZFunction<N>,
+> {
+  pub goal_node: N,
+  pub is_space_available_fn: S,
+  pub make_node_fn: M,
+  pub step_size: f64,
+  pub voxel_connectivity: VoxelConnectivity,
+  pub voxel_heuristic: VoxelHeuristic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The adjacent voxels of a voxel
+pub enum VoxelConnectivity {
+  /// The voxels which share a face
+  Six,
+  /// The voxels which share a face or an edge
+  Eighteen,
+  /// The voxels which share a face, an edge, or a corner
+  TwentySix,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The estimate of the cost from a voxel to the goal
+pub enum VoxelHeuristic {
+  /// The straight line distance
+  Euclidean,
+  /// The cost of the cheapest path with 26-connectivity and no obstacles.
+  /// More informed than the straight line distance.
+  Octile,
 }
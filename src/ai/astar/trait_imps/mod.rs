@@ -2,12 +2,12 @@
 //! - Trait implementations for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-05-10
 //! - Rust created: 2022-10-24
-//! - Rust updated: 2024-07-29
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the classes in the Java-based [`CroftSoft Core Library`]
@@ -21,22 +21,184 @@
 #[cfg(test)]
 mod test;
 
-use super::structures::AStar;
-use super::structures::{GradientCartographer, GridCartographer, NodeInfo};
-use super::traits::Cartographer;
+use super::constants::DEFAULT_HEURISTIC_WEIGHT_DECREMENT;
+use super::structures::{AStar, OpenNode, SearchBudget};
+use super::structures::{
+  BidirectionalAStar, DStarLiteOpenNode, GoalCartographer, GoalSetCartographer,
+  GradientCartographer, GridCartographer, HexCartographer,
+  IterativeDeepeningAStar, JumpPointCartographer, NodeInfo,
+  ReverseCartographer, TerrainCartographer, VisibilityGraphCartographer,
+  VoxelCartographer, VoxelConnectivity, VoxelHeuristic,
+};
+use super::traits::{Cartographer, PathCost};
+use crate::math::geom::hex_coordinate::HexCoordinate;
+use crate::math::geom::point_2dd::Point2DD;
 use crate::math::geom::point_xy::PointXY;
+use crate::math::geom::point_xyz::PointXYZ;
+use crate::math::math_constants::SQRT_3;
 use core::cmp::Ordering;
-use core::f64::consts::TAU;
+use core::f64::consts::{SQRT_2, TAU};
 use core::hash::Hash;
+use core::ops::Sub;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
-use std::collections::VecDeque;
+use std::collections::HashSet;
+
+impl<N: PartialEq, C: PathCost> Cartographer<N, C>
+  for GoalCartographer<'_, N, C>
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_between(from_node, to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> C {
+    self
+      .cartographer
+      .estimate_cost_between(node, &self.goal_node)
+  }
+
+  /// The adjacent nodes of the cartographer without its jump to its own goal
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_goal_free_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_predecessor_nodes(node)
+  }
+
+  fn has_line_of_sight(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    self.cartographer.has_line_of_sight(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
+
+impl<N, C> Cartographer<N, C> for GoalSetCartographer<'_, N, C>
+where
+  N: PointXY,
+  C: PathCost + Sub<Output = C>,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_between(from_node, to_node)
+  }
+
+  /// Infinite if there are no goal nodes
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> C {
+    self
+      .goal_nodes
+      .iter()
+      .map(|goal_node| {
+        let estimate: C =
+          self.cartographer.estimate_cost_between(node, goal_node);
+        if estimate > self.goal_radius_cost {
+          estimate - self.goal_radius_cost
+        } else {
+          C::ZERO
+        }
+      })
+      .min_by(|estimate_0, estimate_1| estimate_0.total_cmp(estimate_1))
+      .unwrap_or(C::INFINITY)
+  }
+
+  /// The adjacent nodes of the cartographer without its jump to its own goal
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_goal_free_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_predecessor_nodes(node)
+  }
+
+  fn has_line_of_sight(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    self.cartographer.has_line_of_sight(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.get_goal_index(node).is_some()
+  }
+}
+
+impl<N, S, M> Cartographer<N> for GradientCartographer<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xy(to_node)
+  }
 
-impl<N: PointXY> Cartographer<N> for GradientCartographer<N> {
   fn estimate_cost_to_goal(
     &self,
     node: &N,
   ) -> f64 {
-    node.distance_xy(&self.goal_node)
+    self.estimate_cost_between(node, &self.goal_node)
   }
 
   fn get_adjacent_nodes(
@@ -90,12 +252,25 @@ impl<N: PointXY> Cartographer<N> for GradientCartographer<N> {
   }
 }
 
-impl<N: PointXY> Cartographer<N> for GridCartographer<N> {
+impl<N, S, M> Cartographer<N> for GridCartographer<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xy(to_node)
+  }
+
   fn estimate_cost_to_goal(
     &self,
     node: &N,
   ) -> f64 {
-    node.distance_xy(&self.goal_node)
+    self.estimate_cost_between(node, &self.goal_node)
   }
 
   fn get_adjacent_nodes(
@@ -111,6 +286,23 @@ impl<N: PointXY> Cartographer<N> for GridCartographer<N> {
       adjacent_list.push(goal_node_copy);
       return adjacent_list;
     }
+    self.get_goal_free_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xy(to_node)
+  }
+
+  /// The available grid nodes around the node
+  fn get_goal_free_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    let mut adjacent_list = Vec::new();
     let x: f64 = node.get_x();
     let y: f64 = node.get_y();
     for ix in -1..2 {
@@ -130,6 +322,165 @@ impl<N: PointXY> Cartographer<N> for GridCartographer<N> {
     adjacent_list
   }
 
+  /// The available grid nodes around the node which have the node as an
+  /// adjacent node, including those which jump to the goal
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    let mut predecessor_list = Vec::new();
+    let x: f64 = node.get_x();
+    let y: f64 = node.get_y();
+    for ix in -1..2 {
+      for iy in -1..2 {
+        let step: N = (self.make_node_fn)(
+          ((x / self.step_size).trunc() + ix as f64) * self.step_size,
+          ((y / self.step_size).trunc() + iy as f64) * self.step_size,
+        );
+        if step != *node
+          && (self.is_space_available_fn)(&step)
+          && self.get_adjacent_nodes(&step).contains(node)
+        {
+          predecessor_list.push(step);
+        }
+      }
+    }
+    predecessor_list
+  }
+
+  /// Grid raycast which visits every cell the line passes through.
+  /// Where the line passes exactly through a corner, both cells beside the
+  /// corner must be available.
+  fn has_line_of_sight(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    let is_cell_available = |cell_x: f64, cell_y: f64| {
+      let node: N =
+        (self.make_node_fn)(cell_x * self.step_size, cell_y * self.step_size);
+      (self.is_space_available_fn)(&node)
+    };
+    let mut x: f64 = (from_node.get_x() / self.step_size).round();
+    let mut y: f64 = (from_node.get_y() / self.step_size).round();
+    let delta_x: f64 = (to_node.get_x() / self.step_size).round() - x;
+    let delta_y: f64 = (to_node.get_y() / self.step_size).round() - y;
+    let steps_x: f64 = delta_x.abs();
+    let steps_y: f64 = delta_y.abs();
+    let sign_x: f64 = delta_x.signum();
+    let sign_y: f64 = delta_y.signum();
+    let mut index_x: f64 = 0.0;
+    let mut index_y: f64 = 0.0;
+    while index_x < steps_x || index_y < steps_y {
+      let decision: f64 =
+        (1.0 + 2.0 * index_x) * steps_y - (1.0 + 2.0 * index_y) * steps_x;
+      if decision == 0.0 {
+        if !is_cell_available(x + sign_x, y)
+          || !is_cell_available(x, y + sign_y)
+        {
+          return false;
+        }
+        x += sign_x;
+        y += sign_y;
+        index_x += 1.0;
+        index_y += 1.0;
+      } else if decision < 0.0 {
+        x += sign_x;
+        index_x += 1.0;
+      } else {
+        y += sign_y;
+        index_y += 1.0;
+      }
+      if !is_cell_available(x, y) {
+        return false;
+      }
+    }
+    true
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.goal_node.distance_xy(node) == 0.0
+  }
+}
+
+impl<S> Cartographer<HexCoordinate> for HexCartographer<S>
+where
+  S: Fn(&HexCoordinate) -> bool,
+{
+  /// The hex distance, which is exact when no hexes are blocked
+  fn estimate_cost_between(
+    &self,
+    from_node: &HexCoordinate,
+    to_node: &HexCoordinate,
+  ) -> f64 {
+    from_node.distance_to(to_node) as f64
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &HexCoordinate,
+  ) -> f64 {
+    self.estimate_cost_between(node, &self.goal_node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &HexCoordinate,
+  ) -> Vec<HexCoordinate> {
+    node
+      .get_adjacent_hexes()
+      .into_iter()
+      .filter(|adjacent_hex| (self.is_space_available_fn)(adjacent_hex))
+      .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &HexCoordinate,
+    _to_node: &HexCoordinate,
+  ) -> f64 {
+    1.0
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &HexCoordinate,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
+
+impl<N, S, M> Cartographer<N> for JumpPointCartographer<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xy(to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> f64 {
+    self.estimate_cost_between(node, &self.goal_node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.get_successor_nodes(node, None)
+  }
+
   fn get_cost_to_adjacent_node(
     &self,
     from_node: &N,
@@ -138,6 +489,73 @@ impl<N: PointXY> Cartographer<N> for GridCartographer<N> {
     from_node.distance_xy(to_node)
   }
 
+  /// Jumps in the natural and forced directions given the parent direction.
+  /// Jumps in all eight directions if there is no parent.
+  fn get_successor_nodes(
+    &self,
+    node: &N,
+    parent_node_option: Option<&N>,
+  ) -> Vec<N> {
+    if self.is_near_goal(node) {
+      let x: f64 = self.goal_node.get_x();
+      let y: f64 = self.goal_node.get_y();
+      return vec![(self.make_node_fn)(x, y)];
+    }
+    let (x, y) = self.to_cell(node);
+    let mut directions: Vec<(f64, f64)> = Vec::new();
+    let parent_cell_option: Option<(f64, f64)> =
+      parent_node_option.map(|parent_node| self.to_cell(parent_node));
+    match parent_cell_option {
+      Some((parent_x, parent_y)) if (parent_x, parent_y) != (x, y) => {
+        let (dx, dy) = self.to_direction(parent_x, parent_y, x, y);
+        if dx != 0.0 && dy != 0.0 {
+          directions.push((dx, 0.0));
+          directions.push((0.0, dy));
+          directions.push((dx, dy));
+          if !self.is_cell_available(x - dx, y) {
+            directions.push((-dx, dy));
+          }
+          if !self.is_cell_available(x, y - dy) {
+            directions.push((dx, -dy));
+          }
+        } else if dx != 0.0 {
+          directions.push((dx, 0.0));
+          if !self.is_cell_available(x, y + 1.0) {
+            directions.push((dx, 1.0));
+          }
+          if !self.is_cell_available(x, y - 1.0) {
+            directions.push((dx, -1.0));
+          }
+        } else {
+          directions.push((0.0, dy));
+          if !self.is_cell_available(x + 1.0, y) {
+            directions.push((1.0, dy));
+          }
+          if !self.is_cell_available(x - 1.0, y) {
+            directions.push((-1.0, dy));
+          }
+        }
+      },
+      _ => {
+        for dx in [
+          -1.0, 0.0, 1.0,
+        ] {
+          for dy in [
+            -1.0, 0.0, 1.0,
+          ] {
+            if dx != 0.0 || dy != 0.0 {
+              directions.push((dx, dy));
+            }
+          }
+        }
+      },
+    }
+    directions
+      .into_iter()
+      .filter_map(|(dx, dy)| self.jump(x, y, dx, dy))
+      .collect()
+  }
+
   fn is_goal_node(
     &self,
     node: &N,
@@ -146,32 +564,539 @@ impl<N: PointXY> Cartographer<N> for GridCartographer<N> {
   }
 }
 
-impl<N: Eq + Hash> Default for AStar<N> {
+impl<N: PartialEq> Cartographer<N> for ReverseCartographer<'_, N> {
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    self.cartographer.estimate_cost_between(to_node, from_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> f64 {
+    self.estimate_cost_between(node, &self.start_node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_predecessor_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(to_node, from_node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_adjacent_nodes(node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    *node == self.start_node
+  }
+}
+
+impl<N, T: Fn(&N, &N) -> f64> Cartographer<N>
+  for TerrainCartographer<'_, N, T>
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    self.cartographer.estimate_cost_between(from_node, to_node)
+      * self.min_terrain_multiplier
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> f64 {
+    self.cartographer.estimate_cost_to_goal(node) * self.min_terrain_multiplier
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node)
+      * (self.terrain_multiplier_fn)(from_node, to_node)
+  }
+
+  fn get_goal_free_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_goal_free_adjacent_nodes(node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_predecessor_nodes(node)
+  }
+
+  fn get_successor_nodes(
+    &self,
+    node: &N,
+    parent_node_option: Option<&N>,
+  ) -> Vec<N> {
+    self
+      .cartographer
+      .get_successor_nodes(node, parent_node_option)
+  }
+
+  fn has_line_of_sight(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    self.cartographer.has_line_of_sight(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.cartographer.is_goal_node(node)
+  }
+}
+
+impl Cartographer<Point2DD> for VisibilityGraphCartographer {
+  fn estimate_cost_between(
+    &self,
+    from_node: &Point2DD,
+    to_node: &Point2DD,
+  ) -> f64 {
+    from_node.distance_to(to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Point2DD,
+  ) -> f64 {
+    self.estimate_cost_between(node, &self.goal_node)
+  }
+
+  /// The visible vertices plus the goal if it is visible
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point2DD,
+  ) -> Vec<Point2DD> {
+    let mut adjacent_list: Vec<Point2DD> =
+      self.get_goal_free_adjacent_nodes(node);
+    if *node != self.goal_node && self.is_visible(node, &self.goal_node) {
+      adjacent_list.push(self.goal_node);
+    }
+    adjacent_list
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &Point2DD,
+    to_node: &Point2DD,
+  ) -> f64 {
+    from_node.distance_to(to_node)
+  }
+
+  /// The visible vertices are precomputed for the vertices and computed on
+  /// demand for other nodes such as the start
+  fn get_goal_free_adjacent_nodes(
+    &self,
+    node: &Point2DD,
+  ) -> Vec<Point2DD> {
+    match self.vertex_to_visible_vertices_map.get(node) {
+      Some(visible_vertex_list) => visible_vertex_list.clone(),
+      None => self.get_visible_vertices(node),
+    }
+  }
+
+  fn has_line_of_sight(
+    &self,
+    from_node: &Point2DD,
+    to_node: &Point2DD,
+  ) -> bool {
+    self.is_visible(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Point2DD,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
+
+impl<N, S, M> Cartographer<N> for VoxelCartographer<N, S, M>
+where
+  N: PointXYZ,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64, f64) -> N,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    match self.voxel_heuristic {
+      VoxelHeuristic::Euclidean => from_node.distance_xyz(to_node),
+      VoxelHeuristic::Octile => {
+        let mut deltas: [f64; 3] = [
+          (from_node.get_x() - to_node.get_x()).abs(),
+          (from_node.get_y() - to_node.get_y()).abs(),
+          (from_node.get_z() - to_node.get_z()).abs(),
+        ];
+        deltas.sort_by(f64::total_cmp);
+        // The smallest delta is covered by moves across a corner, the
+        // middle by moves across an edge, and the rest by moves across a face
+        deltas[0] * SQRT_3
+          + (deltas[1] - deltas[0]) * SQRT_2
+          + (deltas[2] - deltas[1])
+      },
+    }
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> f64 {
+    self.estimate_cost_between(node, &self.goal_node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    let max_axis_count: i32 = match self.voxel_connectivity {
+      VoxelConnectivity::Six => 1,
+      VoxelConnectivity::Eighteen => 2,
+      VoxelConnectivity::TwentySix => 3,
+    };
+    let x: f64 = (node.get_x() / self.step_size).trunc();
+    let y: f64 = (node.get_y() / self.step_size).trunc();
+    let z: f64 = (node.get_z() / self.step_size).trunc();
+    let mut adjacent_list = Vec::new();
+    for ix in -1..2 {
+      for iy in -1..2 {
+        for iz in -1..2 {
+          let axis_count: i32 = ix * ix + iy * iy + iz * iz;
+          if axis_count == 0 || axis_count > max_axis_count {
+            continue;
+          }
+          let step: N = (self.make_node_fn)(
+            (x + ix as f64) * self.step_size,
+            (y + iy as f64) * self.step_size,
+            (z + iz as f64) * self.step_size,
+          );
+          if (self.is_space_available_fn)(&step) {
+            adjacent_list.push(step);
+          }
+        }
+      }
+    }
+    adjacent_list
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xyz(to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.goal_node.distance_xyz(node) == 0.0
+  }
+}
+
+impl<N: Eq + Hash, C: PathCost> Default for AStar<N, C> {
   fn default() -> Self {
     AStar {
+      any_angle: false,
+      anytime: false,
       best_node_option: None,
-      best_total_cost: f64::INFINITY,
+      best_total_cost: C::INFINITY,
+      closed_node_set: HashSet::new(),
       goal_node_option: None,
+      heuristic_weight: 1.0,
+      heuristic_weight_decrement: DEFAULT_HEURISTIC_WEIGHT_DECREMENT,
+      inconsistent_node_list: Vec::new(),
       list_empty: false,
       node_to_node_info_map: HashMap::new(),
       node_to_parent_node_map: HashMap::new(),
-      open_node_sorted_list: VecDeque::new(),
+      open_node_heap: BinaryHeap::new(),
+      open_node_sequence: 0,
+      search_heuristic_weight: 1.0,
+      statistics_option: None,
+      suboptimality_bound: f64::INFINITY,
+    }
+  }
+}
+
+impl<N: Eq + Hash> Default for BidirectionalAStar<N> {
+  fn default() -> Self {
+    BidirectionalAStar {
+      backward_astar: AStar::default(),
+      best_path_cost: f64::INFINITY,
+      forward_astar: AStar::default(),
+      list_empty: false,
+      meeting_node_option: None,
+      start_node_option: None,
+    }
+  }
+}
+
+impl<N> Default for IterativeDeepeningAStar<N> {
+  fn default() -> Self {
+    IterativeDeepeningAStar {
+      cost_threshold: 0.0,
+      iteration_count: 0,
+      path_cost: f64::INFINITY,
+      path_node_list: Vec::new(),
+      start_node_option: None,
     }
   }
 }
 
-impl Eq for NodeInfo {}
+impl<C: PathCost> Default for SearchBudget<C> {
+  fn default() -> Self {
+    SearchBudget {
+      max_expansions: usize::MAX,
+      max_nodes: usize::MAX,
+      max_path_cost: C::INFINITY,
+    }
+  }
+}
+
+impl<N> Eq for DStarLiteOpenNode<N> {}
+
+/// Reversed so that the max-heap BinaryHeap pops the lowest key first.
+/// Uses the total order of the key components so that NaN is not equal to
+/// every key.
+impl<N> Ord for DStarLiteOpenNode<N> {
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    other
+      .key
+      .primary
+      .total_cmp(&self.key.primary)
+      .then_with(|| other.key.secondary.total_cmp(&self.key.secondary))
+  }
+}
+
+impl<C: PathCost> Eq for NodeInfo<C> {}
 
-impl Ord for NodeInfo {
+/// Uses the total order of the cost so that NaN is not equal to every cost
+impl<C: PathCost> Ord for NodeInfo<C> {
   fn cmp(
     &self,
     other: &Self,
   ) -> std::cmp::Ordering {
-    if self.total_cost < other.total_cost {
-      return Ordering::Less;
-    } else if self.total_cost > other.total_cost {
-      return Ordering::Greater;
+    self.total_cost.total_cmp(&other.total_cost)
+  }
+}
+
+impl<N, C: PathCost> Eq for OpenNode<N, C> {}
+
+/// Reversed so that the max-heap BinaryHeap pops the lowest total cost first.
+/// Ties are broken in favor of the lower heuristic, which is the higher cost
+/// from the start, and then the earliest insertion so that the order is
+/// deterministic.
+impl<N, C: PathCost> Ord for OpenNode<N, C> {
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    other
+      .node_info
+      .cmp(&self.node_info)
+      .then_with(|| {
+        self
+          .node_info
+          .cost_from_start
+          .total_cmp(&other.node_info.cost_from_start)
+      })
+      .then_with(|| other.sequence.cmp(&self.sequence))
+  }
+}
+
+/// Uses the IEEE 754 total order in which NaN is greater than infinity
+impl PathCost for f64 {
+  const INFINITY: Self = f64::INFINITY;
+
+  const ZERO: Self = 0.0;
+
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self {
+    self + other
+  }
+
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    f64::total_cmp(self, other)
+  }
+
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self {
+    self * weight
+  }
+}
+
+impl PathCost for i32 {
+  const INFINITY: Self = i32::MAX;
+
+  const ZERO: Self = 0;
+
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self {
+    i32::saturating_add(self, other)
+  }
+
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    self.cmp(other)
+  }
+
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self {
+    if weight == 1.0 {
+      return self;
+    }
+    (self as f64 * weight) as i32
+  }
+}
+
+impl PathCost for i64 {
+  const INFINITY: Self = i64::MAX;
+
+  const ZERO: Self = 0;
+
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self {
+    i64::saturating_add(self, other)
+  }
+
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    self.cmp(other)
+  }
+
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self {
+    if weight == 1.0 {
+      return self;
+    }
+    (self as f64 * weight) as i64
+  }
+}
+
+impl PathCost for u32 {
+  const INFINITY: Self = u32::MAX;
+
+  const ZERO: Self = 0;
+
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self {
+    u32::saturating_add(self, other)
+  }
+
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    self.cmp(other)
+  }
+
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self {
+    if weight == 1.0 {
+      return self;
+    }
+    (self as f64 * weight) as u32
+  }
+}
+
+impl PathCost for u64 {
+  const INFINITY: Self = u64::MAX;
+
+  const ZERO: Self = 0;
+
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self {
+    u64::saturating_add(self, other)
+  }
+
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    self.cmp(other)
+  }
+
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self {
+    if weight == 1.0 {
+      return self;
     }
-    Ordering::Equal
+    (self as f64 * weight) as u64
   }
 }
@@ -2,10 +2,10 @@
 //! - Unit tests for the A* algorithm trait implementations
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-02
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
@@ -18,14 +18,25 @@ use core::cmp::Ordering;
 use crate::{
   ai::astar::{
     constants::test::{
-      TEST_DISTANCE_TO_GOAL, TEST_GOAL_NODE, TEST_ORIGIN_NODE,
-      TEST_SUBJECT_GRADIENT_CARTOGRAPHER, TEST_SUBJECT_GRID_CARTOGRAPHER,
-      TEST_TOLERANCE,
+      TEST_BEST_NODE, TEST_DISTANCE_TO_GOAL, TEST_GOAL_NODE,
+      TEST_HEX_BLOCKED_NODE, TEST_HEX_GOAL_NODE, TEST_ORIGIN_NODE,
+      TEST_STEP_SIZE, TEST_SUBJECT_GRADIENT_CARTOGRAPHER,
+      TEST_SUBJECT_GRID_CARTOGRAPHER, TEST_SUBJECT_HEX_CARTOGRAPHER,
+      TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER, TEST_SUBJECT_VOXEL_CARTOGRAPHER,
+      TEST_TOLERANCE, TEST_VOXEL_GOAL_NODE,
     },
-    structures::NodeInfo,
-    traits::Cartographer,
+    structures::{
+      DStarLiteKey, DStarLiteOpenNode, GoalCartographer, GoalSetCartographer,
+      GridCartographer, NodeInfo, OpenNode, ReverseCartographer, SearchBudget,
+      TerrainCartographer, VisibilityGraphCartographer, VisibilityObstacle,
+      VoxelCartographer, VoxelConnectivity, VoxelHeuristic,
+    },
+    traits::{Cartographer, PathCost},
   },
+  math::geom::hex_coordinate::HexCoordinate,
   math::geom::point_2dd::Point2DD,
+  math::geom::point_3dd::Point3DD,
+  math::geom::rectangle::Rectangle,
 };
 
 #[test]
@@ -43,6 +54,65 @@ fn test_cmp() {
   assert_eq!(node_info_1.cmp(&node_info_0), Ordering::Greater);
 }
 
+#[test]
+fn test_cmp_nan() {
+  let node_info_0 = NodeInfo {
+    cost_from_start: 0.0,
+    total_cost: f64::INFINITY,
+  };
+  let node_info_1 = NodeInfo {
+    cost_from_start: 0.0,
+    total_cost: f64::NAN,
+  };
+  assert_eq!(node_info_0.cmp(&node_info_1), Ordering::Less);
+  assert_eq!(node_info_1.cmp(&node_info_0), Ordering::Greater);
+  // The comparison operators are consistent with the total order
+  assert!(node_info_1 == node_info_1);
+  assert!(node_info_0 < node_info_1);
+  assert!(node_info_1 > node_info_0);
+  let node_info_negative_zero = NodeInfo {
+    cost_from_start: 0.0,
+    total_cost: -0.0,
+  };
+  let node_info_positive_zero = NodeInfo {
+    cost_from_start: 0.0,
+    total_cost: 0.0,
+  };
+  assert!(node_info_negative_zero != node_info_positive_zero);
+  assert!(node_info_negative_zero < node_info_positive_zero);
+}
+
+#[test]
+fn test_cmp_d_star_lite_open_node() {
+  let open_node_0 = DStarLiteOpenNode {
+    key: DStarLiteKey {
+      primary: 1.0,
+      secondary: 0.0,
+    },
+    node: TEST_ORIGIN_NODE,
+  };
+  let open_node_1 = DStarLiteOpenNode {
+    key: DStarLiteKey {
+      primary: 1.0,
+      secondary: 1.0,
+    },
+    node: TEST_ORIGIN_NODE,
+  };
+  assert_eq!(open_node_0.cmp(&open_node_0), Ordering::Equal);
+  assert_eq!(open_node_0.cmp(&open_node_1), Ordering::Greater);
+  assert_eq!(open_node_1.cmp(&open_node_0), Ordering::Less);
+  let open_node_nan = DStarLiteOpenNode {
+    key: DStarLiteKey {
+      primary: 1.0,
+      secondary: f64::NAN,
+    },
+    node: TEST_ORIGIN_NODE,
+  };
+  assert_eq!(open_node_nan.cmp(&open_node_nan), Ordering::Equal);
+  assert_ne!(open_node_nan.cmp(&open_node_0), Ordering::Equal);
+  assert_ne!(open_node_nan, open_node_1);
+}
+
 #[test]
 fn test_default() {
   assert_eq!(
@@ -54,6 +124,46 @@ fn test_default() {
   );
 }
 
+#[test]
+fn test_default_search_budget() {
+  assert_eq!(
+    SearchBudget::default(),
+    SearchBudget {
+      max_expansions: usize::MAX,
+      max_nodes: usize::MAX,
+      max_path_cost: f64::INFINITY,
+    }
+  );
+}
+
+#[test]
+fn test_estimate_cost_between() {
+  let other_node = Point2DD::new(3.0, 4.0);
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER
+      .estimate_cost_between(&TEST_ORIGIN_NODE, &other_node),
+    5.0
+  );
+  assert_eq!(
+    TEST_SUBJECT_HEX_CARTOGRAPHER
+      .estimate_cost_between(&HexCoordinate::default(), &TEST_HEX_GOAL_NODE),
+    3.0
+  );
+  assert_eq!(
+    TEST_SUBJECT_VOXEL_CARTOGRAPHER
+      .estimate_cost_between(&TEST_VOXEL_GOAL_NODE, &TEST_VOXEL_GOAL_NODE),
+    0.0
+  );
+  let reverse_cartographer = ReverseCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    start_node: TEST_ORIGIN_NODE,
+  };
+  assert_eq!(
+    reverse_cartographer.estimate_cost_between(&other_node, &TEST_ORIGIN_NODE),
+    5.0
+  );
+}
+
 #[test]
 fn test_estimate_cost_to_goal_for_gradient_cartographer() {
   assert_eq!(
@@ -70,6 +180,40 @@ fn test_estimate_cost_to_goal_for_grid_cartographer() {
   );
 }
 
+#[test]
+fn test_estimate_cost_to_goal_for_hex_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_HEX_CARTOGRAPHER
+      .estimate_cost_to_goal(&HexCoordinate::default()),
+    3.0
+  );
+  assert_eq!(
+    TEST_SUBJECT_HEX_CARTOGRAPHER.estimate_cost_to_goal(&TEST_HEX_GOAL_NODE),
+    0.0
+  );
+}
+
+#[test]
+fn test_estimate_cost_to_goal_for_voxel_cartographer() {
+  let origin_node = Point3DD::default();
+  // One move across a corner, one across an edge, and two across a face
+  let expected_octile_cost = 3.0_f64.sqrt() + 2.0_f64.sqrt() + 2.0;
+  assert!(
+    (TEST_SUBJECT_VOXEL_CARTOGRAPHER.estimate_cost_to_goal(&origin_node)
+      - expected_octile_cost)
+      .abs()
+      < TEST_TOLERANCE
+  );
+  let euclidean_cartographer = VoxelCartographer {
+    voxel_heuristic: VoxelHeuristic::Euclidean,
+    ..TEST_SUBJECT_VOXEL_CARTOGRAPHER
+  };
+  assert_eq!(
+    euclidean_cartographer.estimate_cost_to_goal(&origin_node),
+    21.0_f64.sqrt()
+  );
+}
+
 #[test]
 fn test_get_adjacent_nodes_for_gradient_cartographer() {
   let mut actual_adjacent_nodes =
@@ -168,6 +312,139 @@ fn test_get_adjacent_nodes_for_grid_cartographer() {
   );
 }
 
+#[test]
+fn test_get_adjacent_nodes_for_grid_cartographer_with_closures() {
+  let blocked_node = Point2DD {
+    x: 1.0,
+    y: 0.0,
+  };
+  let step_size = TEST_STEP_SIZE;
+  let test_subject_grid_cartographer = GridCartographer {
+    goal_node: TEST_GOAL_NODE,
+    is_space_available_fn: |node: &Point2DD| *node != blocked_node,
+    make_node_fn: move |x, y| Point2DD::new(x * step_size, y * step_size),
+    step_size,
+  };
+  let adjacent_nodes =
+    test_subject_grid_cartographer.get_adjacent_nodes(&Point2DD::default());
+  assert_eq!(adjacent_nodes.len(), 7);
+  assert!(!adjacent_nodes.contains(&blocked_node));
+}
+
+#[test]
+fn test_get_adjacent_nodes_for_grid_cartographer_with_trait_objects() {
+  let rectangle = Rectangle {
+    x_max: 1.0,
+    x_min: 0.0,
+    y_max: 1.0,
+    y_min: 0.0,
+  };
+  let is_space_available_fn: Box<dyn Fn(&Point2DD) -> bool> =
+    Box::new(move |node| rectangle.contains(node));
+  let test_subject_grid_cartographer = GridCartographer {
+    goal_node: TEST_GOAL_NODE,
+    is_space_available_fn,
+    make_node_fn: Point2DD::new,
+    step_size: TEST_STEP_SIZE,
+  };
+  assert_eq!(
+    test_subject_grid_cartographer
+      .get_adjacent_nodes(&Point2DD::default())
+      .len(),
+    3
+  );
+}
+
+#[test]
+fn test_get_adjacent_nodes_for_hex_cartographer() {
+  let adjacent_nodes =
+    TEST_SUBJECT_HEX_CARTOGRAPHER.get_adjacent_nodes(&HexCoordinate::default());
+  assert_eq!(adjacent_nodes.len(), 5);
+  assert!(!adjacent_nodes.contains(&TEST_HEX_BLOCKED_NODE));
+  assert!(adjacent_nodes.contains(&HexCoordinate::new(1, -1)));
+  assert_eq!(
+    TEST_SUBJECT_HEX_CARTOGRAPHER
+      .get_adjacent_nodes(&HexCoordinate::new(10, 0))
+      .len(),
+    3
+  );
+}
+
+#[test]
+fn test_get_adjacent_nodes_for_voxel_cartographer() {
+  let origin_node = Point3DD::default();
+  for (voxel_connectivity, expected_count) in [
+    (VoxelConnectivity::Six, 6),
+    (VoxelConnectivity::Eighteen, 18),
+    (VoxelConnectivity::TwentySix, 26),
+  ] {
+    let voxel_cartographer = VoxelCartographer {
+      voxel_connectivity,
+      ..TEST_SUBJECT_VOXEL_CARTOGRAPHER
+    };
+    let adjacent_nodes = voxel_cartographer.get_adjacent_nodes(&origin_node);
+    assert_eq!(adjacent_nodes.len(), expected_count);
+    assert!(adjacent_nodes.contains(&Point3DD::new(0.0, 0.0, -1.0)));
+  }
+  // The corner of the space has seven adjacent voxels inside the space
+  let corner_node = Point3DD::new(10.0, 10.0, 10.0);
+  assert_eq!(
+    TEST_SUBJECT_VOXEL_CARTOGRAPHER
+      .get_adjacent_nodes(&corner_node)
+      .len(),
+    7
+  );
+}
+
+#[test]
+fn test_get_adjacent_nodes_for_jump_point_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    vec![
+      Point2DD::new(1.0, -1.0),
+      Point2DD::new(3.0, 0.0),
+      Point2DD::new(1.0, 1.0),
+    ],
+  );
+}
+
+#[test]
+fn test_get_successor_nodes_for_jump_point_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER
+      .get_successor_nodes(&Point2DD::new(1.0, 1.0), Some(&TEST_ORIGIN_NODE)),
+    vec![Point2DD::new(4.0, 1.0)],
+  );
+  assert_eq!(
+    TEST_SUBJECT_JUMP_POINT_CARTOGRAPHER
+      .get_successor_nodes(&Point2DD::new(3.0, 0.0), Some(&TEST_ORIGIN_NODE)),
+    vec![TEST_GOAL_NODE],
+  );
+}
+
+#[test]
+fn test_get_predecessor_nodes_for_grid_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER
+      .get_predecessor_nodes(&TEST_GOAL_NODE)
+      .len(),
+    8
+  );
+  let predecessor_nodes = TEST_SUBJECT_GRID_CARTOGRAPHER
+    .get_predecessor_nodes(&Point2DD::new(2.0, 0.0));
+  assert_eq!(predecessor_nodes.len(), 7);
+  assert!(!predecessor_nodes.contains(&Point2DD::new(3.0, 0.0)));
+}
+
+#[test]
+fn test_get_successor_nodes_for_grid_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER
+      .get_successor_nodes(&TEST_ORIGIN_NODE, Some(&TEST_BEST_NODE)),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+  );
+}
+
 #[test]
 fn test_get_cost_to_adjacent_node_for_gradient_cartographer() {
   assert_eq!(
@@ -186,14 +463,391 @@ fn test_get_cost_to_adjacent_node_for_grid_cartographer() {
   );
 }
 
+#[test]
+fn test_get_goal_free_adjacent_nodes_for_grid_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER
+      .get_goal_free_adjacent_nodes(&Point2DD::default()),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&Point2DD::default())
+  );
+  // Next to the goal the grid nodes instead of only the goal
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER
+      .get_goal_free_adjacent_nodes(&Point2DD::new(3.0, 0.0))
+      .len(),
+    8
+  );
+}
+
+#[test]
+fn test_has_line_of_sight_for_gradient_cartographer() {
+  assert!(
+    !TEST_SUBJECT_GRADIENT_CARTOGRAPHER
+      .has_line_of_sight(&TEST_ORIGIN_NODE, &TEST_GOAL_NODE)
+  );
+}
+
+#[test]
+fn test_has_line_of_sight_for_grid_cartographer() {
+  let blocked_node = Point2DD::new(2.0, 1.0);
+  let test_subject_grid_cartographer = GridCartographer {
+    goal_node: TEST_GOAL_NODE,
+    is_space_available_fn: |node: &Point2DD| *node != blocked_node,
+    make_node_fn: Point2DD::new,
+    step_size: TEST_STEP_SIZE,
+  };
+  assert!(
+    test_subject_grid_cartographer
+      .has_line_of_sight(&TEST_ORIGIN_NODE, &TEST_GOAL_NODE)
+  );
+  assert!(
+    !test_subject_grid_cartographer
+      .has_line_of_sight(&TEST_ORIGIN_NODE, &Point2DD::new(4.0, 2.0))
+  );
+  assert!(
+    !test_subject_grid_cartographer
+      .has_line_of_sight(&TEST_ORIGIN_NODE, &Point2DD::new(3.0, 1.0))
+  );
+  assert!(
+    test_subject_grid_cartographer
+      .has_line_of_sight(&TEST_ORIGIN_NODE, &Point2DD::new(1.0, 3.0))
+  );
+}
+
 #[test]
 fn test_is_goal_node_for_gradient_cartographer() {
   assert!(TEST_SUBJECT_GRADIENT_CARTOGRAPHER.is_goal_node(&TEST_GOAL_NODE));
   assert!(!TEST_SUBJECT_GRADIENT_CARTOGRAPHER.is_goal_node(&TEST_ORIGIN_NODE));
 }
 
+#[test]
+fn test_is_goal_node_for_hex_cartographer() {
+  assert!(TEST_SUBJECT_HEX_CARTOGRAPHER.is_goal_node(&TEST_HEX_GOAL_NODE));
+  assert!(
+    !TEST_SUBJECT_HEX_CARTOGRAPHER.is_goal_node(&HexCoordinate::default())
+  );
+}
+
+#[test]
+fn test_get_cost_to_adjacent_node_for_voxel_cartographer() {
+  assert_eq!(
+    TEST_SUBJECT_VOXEL_CARTOGRAPHER.get_cost_to_adjacent_node(
+      &Point3DD::default(),
+      &Point3DD::new(1.0, 0.0, 1.0)
+    ),
+    2.0_f64.sqrt()
+  );
+}
+
+#[test]
+fn test_is_goal_node_for_voxel_cartographer() {
+  assert!(TEST_SUBJECT_VOXEL_CARTOGRAPHER.is_goal_node(&TEST_VOXEL_GOAL_NODE));
+  assert!(!TEST_SUBJECT_VOXEL_CARTOGRAPHER.is_goal_node(&Point3DD::default()));
+}
+
 #[test]
 fn test_is_goal_node_for_grid_cartographer() {
   assert!(TEST_SUBJECT_GRID_CARTOGRAPHER.is_goal_node(&TEST_GOAL_NODE));
   assert!(!TEST_SUBJECT_GRID_CARTOGRAPHER.is_goal_node(&TEST_ORIGIN_NODE));
 }
+
+#[test]
+fn test_cmp_open_node() {
+  let open_node_0 = OpenNode {
+    node: TEST_ORIGIN_NODE,
+    node_info: NodeInfo {
+      cost_from_start: 0.0,
+      total_cost: 0.0,
+    },
+    sequence: 1,
+  };
+  let open_node_1 = OpenNode {
+    node: TEST_ORIGIN_NODE,
+    node_info: NodeInfo {
+      cost_from_start: 0.0,
+      total_cost: 1.0,
+    },
+    sequence: 0,
+  };
+  let open_node_2 = OpenNode {
+    node: TEST_ORIGIN_NODE,
+    node_info: NodeInfo {
+      cost_from_start: 0.0,
+      total_cost: 0.0,
+    },
+    sequence: 2,
+  };
+  assert_eq!(open_node_0.cmp(&open_node_0), Ordering::Equal);
+  assert_eq!(open_node_0.cmp(&open_node_1), Ordering::Greater);
+  assert_eq!(open_node_1.cmp(&open_node_0), Ordering::Less);
+  assert_eq!(open_node_0.cmp(&open_node_2), Ordering::Greater);
+  // The lower heuristic is popped first even if it was inserted later
+  let open_node_3 = OpenNode {
+    node: TEST_ORIGIN_NODE,
+    node_info: NodeInfo {
+      cost_from_start: 1.0,
+      total_cost: 1.0,
+    },
+    sequence: 3,
+  };
+  assert_eq!(open_node_3.cmp(&open_node_1), Ordering::Greater);
+  assert_eq!(open_node_1.cmp(&open_node_3), Ordering::Less);
+}
+
+#[test]
+fn test_reverse_cartographer() {
+  let test_subject_reverse_cartographer = ReverseCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    start_node: TEST_ORIGIN_NODE,
+  };
+  assert_eq!(
+    test_subject_reverse_cartographer.estimate_cost_to_goal(&TEST_GOAL_NODE),
+    TEST_DISTANCE_TO_GOAL
+  );
+  assert_eq!(
+    test_subject_reverse_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    0.0
+  );
+  // The estimate does not depend on the estimate to the goal
+  assert_eq!(
+    test_subject_reverse_cartographer
+      .estimate_cost_to_goal(&Point2DD::new(0.0, 3.0)),
+    3.0
+  );
+  assert_eq!(
+    test_subject_reverse_cartographer.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_predecessor_nodes(&TEST_ORIGIN_NODE)
+  );
+  assert_eq!(
+    test_subject_reverse_cartographer
+      .get_cost_to_adjacent_node(&TEST_BEST_NODE, &TEST_ORIGIN_NODE),
+    TEST_STEP_SIZE
+  );
+  assert!(test_subject_reverse_cartographer.is_goal_node(&TEST_ORIGIN_NODE));
+  assert!(!test_subject_reverse_cartographer.is_goal_node(&TEST_GOAL_NODE));
+}
+
+#[test]
+fn test_goal_cartographer() {
+  let other_goal_node = Point2DD::new(0.0, -3.0);
+  let test_subject_goal_cartographer = GoalCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_node: other_goal_node,
+  };
+  assert_eq!(
+    test_subject_goal_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    3.0
+  );
+  assert_eq!(
+    test_subject_goal_cartographer.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE)
+  );
+  assert_eq!(
+    test_subject_goal_cartographer
+      .get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &TEST_BEST_NODE),
+    TEST_STEP_SIZE
+  );
+  // Next to its own goal the grid cartographer does not jump to it
+  let node = Point2DD::new(3.0, 0.0);
+  assert_eq!(
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&node),
+    vec![TEST_GOAL_NODE]
+  );
+  assert_eq!(
+    test_subject_goal_cartographer.get_adjacent_nodes(&node),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_goal_free_adjacent_nodes(&node)
+  );
+  assert_eq!(
+    test_subject_goal_cartographer
+      .get_successor_nodes(&node, None)
+      .len(),
+    8
+  );
+  assert!(test_subject_goal_cartographer.is_goal_node(&other_goal_node));
+  assert!(!test_subject_goal_cartographer.is_goal_node(&TEST_GOAL_NODE));
+}
+
+#[test]
+fn test_goal_set_cartographer() {
+  let goal_nodes = vec![
+    Point2DD::new(0.0, -3.0),
+    Point2DD::new(-2.0, 0.0),
+  ];
+  let test_subject_goal_set_cartographer = GoalSetCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_nodes: goal_nodes.clone(),
+    goal_radius: 0.0,
+    goal_radius_cost: 0.0,
+  };
+  assert_eq!(
+    test_subject_goal_set_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    2.0
+  );
+  assert_eq!(
+    test_subject_goal_set_cartographer.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE)
+  );
+  assert_eq!(
+    test_subject_goal_set_cartographer
+      .get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &TEST_BEST_NODE),
+    TEST_STEP_SIZE
+  );
+  assert!(test_subject_goal_set_cartographer.is_goal_node(&goal_nodes[0]));
+  assert!(test_subject_goal_set_cartographer.is_goal_node(&goal_nodes[1]));
+  assert!(!test_subject_goal_set_cartographer.is_goal_node(&TEST_GOAL_NODE));
+  let test_subject_goal_set_cartographer = GoalSetCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_nodes: goal_nodes.clone(),
+    goal_radius: 1.5,
+    goal_radius_cost: 1.5,
+  };
+  assert_eq!(
+    test_subject_goal_set_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    0.5
+  );
+  assert!(
+    test_subject_goal_set_cartographer.is_goal_node(&Point2DD::new(-1.0, 0.0))
+  );
+  // The goal radius cost scales with the cost per unit distance
+  let terrain_cartographer = TerrainCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    min_terrain_multiplier: 2.0,
+    terrain_multiplier_fn: |_: &Point2DD, _: &Point2DD| 2.0,
+  };
+  let test_subject_goal_set_cartographer = GoalSetCartographer {
+    cartographer: &terrain_cartographer,
+    goal_nodes,
+    goal_radius: 1.5,
+    goal_radius_cost: 3.0,
+  };
+  assert_eq!(
+    test_subject_goal_set_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    1.0
+  );
+  assert_eq!(
+    test_subject_goal_set_cartographer.estimate_cost_to_goal(&TEST_BEST_NODE),
+    3.0
+  );
+  let test_subject_goal_set_cartographer = GoalSetCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    goal_nodes: Vec::new(),
+    goal_radius: 0.0,
+    goal_radius_cost: 0.0,
+  };
+  assert_eq!(
+    test_subject_goal_set_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    f64::INFINITY
+  );
+  assert!(!test_subject_goal_set_cartographer.is_goal_node(&TEST_ORIGIN_NODE));
+}
+
+#[test]
+fn test_terrain_cartographer() {
+  // Mud is three times as costly to enter as the road along the x axis
+  let test_subject_terrain_cartographer = TerrainCartographer {
+    cartographer: &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    min_terrain_multiplier: 1.0,
+    terrain_multiplier_fn: |_: &Point2DD, to_node: &Point2DD| {
+      if to_node.y == 0.0 {
+        1.0
+      } else {
+        3.0
+      }
+    },
+  };
+  assert_eq!(
+    test_subject_terrain_cartographer
+      .get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &Point2DD::new(1.0, 0.0)),
+    1.0
+  );
+  assert_eq!(
+    test_subject_terrain_cartographer
+      .get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &Point2DD::new(0.0, 1.0)),
+    3.0
+  );
+  assert_eq!(
+    test_subject_terrain_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    TEST_DISTANCE_TO_GOAL
+  );
+  assert_eq!(
+    test_subject_terrain_cartographer.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE)
+  );
+  assert!(test_subject_terrain_cartographer.is_goal_node(&TEST_GOAL_NODE));
+  let test_subject_terrain_cartographer = TerrainCartographer {
+    min_terrain_multiplier: 0.5,
+    ..test_subject_terrain_cartographer
+  };
+  assert_eq!(
+    test_subject_terrain_cartographer.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    TEST_DISTANCE_TO_GOAL * 0.5
+  );
+}
+
+#[test]
+fn test_visibility_graph_cartographer() {
+  let goal_node = Point2DD::new(3.0, 1.0);
+  let start_node = Point2DD::new(-1.0, 1.0);
+  let test_subject = VisibilityGraphCartographer::new(
+    0.0,
+    goal_node,
+    &[
+      VisibilityObstacle::Rectangle(Rectangle {
+        x_max: 2.0,
+        x_min: 0.0,
+        y_max: 2.0,
+        y_min: 0.0,
+      }),
+    ],
+    4,
+  );
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&start_node),
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(0.0, 2.0),
+    ]
+  );
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&Point2DD::new(2.0, 0.0)),
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(2.0, 2.0),
+      goal_node,
+    ]
+  );
+  assert_eq!(test_subject.estimate_cost_to_goal(&start_node), 4.0);
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(
+      &Point2DD::new(0.0, 0.0),
+      &Point2DD::new(2.0, 0.0)
+    ),
+    2.0
+  );
+  assert!(!test_subject.has_line_of_sight(&start_node, &goal_node));
+  assert_eq!(
+    test_subject.get_goal_free_adjacent_nodes(&Point2DD::new(2.0, 0.0)),
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(2.0, 2.0),
+    ]
+  );
+  assert!(test_subject.is_goal_node(&goal_node));
+  assert!(!test_subject.is_goal_node(&start_node));
+}
+
+#[test]
+fn test_path_cost() {
+  assert_eq!(<f64 as PathCost>::INFINITY, f64::INFINITY);
+  assert_eq!(<u32 as PathCost>::INFINITY, u32::MAX);
+  assert_eq!(<i64 as PathCost>::ZERO, 0);
+  assert_eq!(PathCost::total_cmp(&-0.0, &0.0), Ordering::Less);
+  assert_eq!(PathCost::total_cmp(&2_u32, &1_u32), Ordering::Greater);
+  assert_eq!(PathCost::saturating_add(1.5, f64::INFINITY), f64::INFINITY);
+  assert_eq!(PathCost::saturating_add(2_u32, 3), 5);
+  assert_eq!(PathCost::saturating_add(2_u32, u32::MAX), u32::MAX);
+  assert_eq!(PathCost::saturating_add(i64::MAX, 1), i64::MAX);
+  assert_eq!(1.5.weight(2.0), 3.0);
+  assert_eq!(7_u32.weight(1.5), 10);
+  assert_eq!(i64::MAX.weight(1.0), i64::MAX);
+}
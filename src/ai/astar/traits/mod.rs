@@ -2,12 +2,12 @@
 //! - Traits for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-04-29
 //! - Rust created: 2022-10-21
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the interfaces in the Java-based [`CroftSoft Core Library`]
@@ -19,12 +19,28 @@
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
-/// An A* algorithm map maker
-pub trait Cartographer<N> {
+use core::cmp::Ordering;
+use core::ops::Add;
+
+/// An A* algorithm map maker.
+/// The cost type defaults to f64.
+pub trait Cartographer<N, C: PathCost = f64> {
+  /// An estimate of the cost from a node to any other node.
+  /// Used by the searches whose goal is not the goal of the cartographer.
+  /// The estimate must not overestimate the cost.
+  /// The default of zero is always admissible but uninformed.
+  fn estimate_cost_between(
+    &self,
+    _from_node: &N,
+    _to_node: &N,
+  ) -> C {
+    C::ZERO
+  }
+
   fn estimate_cost_to_goal(
     &self,
     node: &N,
-  ) -> f64;
+  ) -> C;
 
   fn get_adjacent_nodes(
     &self,
@@ -35,10 +51,87 @@ pub trait Cartographer<N> {
     &self,
     from_node: &N,
     to_node: &N,
-  ) -> f64;
+  ) -> C;
+
+  /// The adjacent nodes without any jump to the goal of the cartographer.
+  /// A cartographer which jumps to its goal from the nodes near the goal
+  /// offers only the goal there, so an adapter which replaces the goal must
+  /// filter out the jump or its searches would dead-end next to a goal which
+  /// they are not seeking.
+  /// The default assumes that the adjacent nodes do not depend on the goal.
+  fn get_goal_free_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.get_adjacent_nodes(node)
+  }
+
+  /// The nodes which have this node as an adjacent node.
+  /// Used by the searches which expand backward from the goal.
+  /// The default assumes an undirected graph.
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.get_adjacent_nodes(node)
+  }
+
+  /// The nodes to expand from a node given the node it was reached from.
+  /// Override this to prune the adjacent nodes using the parent node.
+  /// The default ignores the parent node.
+  fn get_successor_nodes(
+    &self,
+    node: &N,
+    _parent_node_option: Option<&N>,
+  ) -> Vec<N> {
+    self.get_adjacent_nodes(node)
+  }
+
+  /// True if a straight line between the nodes is unobstructed.
+  /// Used by the any-angle search mode.
+  /// The default is no line of sight.
+  fn has_line_of_sight(
+    &self,
+    _from_node: &N,
+    _to_node: &N,
+  ) -> bool {
+    false
+  }
 
   fn is_goal_node(
     &self,
     node: &N,
   ) -> bool;
 }
+
+/// A cost of a path which is totally ordered and addable.
+/// Implemented for the integers for exact costs which are deterministic
+/// across platforms and for f64 using the IEEE 754 total order.
+pub trait PathCost: Add<Output = Self> + Copy + PartialOrd {
+  /// A cost which is greater than the cost of any path
+  const INFINITY: Self;
+
+  const ZERO: Self;
+
+  /// Sums two costs without overflowing.
+  /// The integers saturate at INFINITY so that an infinite estimate stays
+  /// infinite instead of wrapping around to a small cost.
+  fn saturating_add(
+    self,
+    other: Self,
+  ) -> Self;
+
+  /// A total order which, unlike the partial order, never treats two costs
+  /// as equal when they are not
+  fn total_cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering;
+
+  /// Multiplies the cost by a heuristic weight.
+  /// The integers are truncated.
+  fn weight(
+    self,
+    weight: f64,
+  ) -> Self;
+}
@@ -2,15 +2,27 @@
 //! - Types for the A* algorithm
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Rust created: 2022-11-09
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! [`CroftSoft Inc`]: https://www.CroftSoft.com/
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
+/// The default space tester for the cartographers.
+/// A closure which captures the world state can be used instead.
 pub type IsSpaceAvailableFunction<N> = fn(&N) -> bool;
 
+/// The default node factory for the cartographers.
+/// A closure which captures the world state can be used instead.
 pub type MakeNodeFunction<N> = fn(f64, f64) -> N;
+
+/// The default terrain cost multiplier for the TerrainCartographer.
+/// A closure which captures the terrain map can be used instead.
+pub type TerrainMultiplierFunction<N> = fn(&N, &N) -> f64;
+
+/// The default node factory for the three dimensional cartographers.
+/// A closure which captures the world state can be used instead.
+pub type MakeNodeXYZFunction<N> = fn(f64, f64, f64) -> N;
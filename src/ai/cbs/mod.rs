@@ -0,0 +1,351 @@
+// =============================================================================
+//! - Conflict-Based Search (CBS) for optimal multi-agent pathfinding
+//! - Searches a tree of constraints which resolve the conflicts between agents
+//! - Plans the path of each agent using space-time A*
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - Based on the paper "Conflict-Based Search for Optimal Multi-Agent
+//!   Pathfinding"
+//!   - By Guni Sharon, Roni Stern, Ariel Felner, and Nathan Sturtevant, 2015
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{
+  AStar, GoalCartographer, SearchBudget, SearchOutcome,
+};
+use crate::ai::astar::traits::Cartographer;
+use crate::ai::spacetime::{
+  DEFAULT_WAIT_COST, ReservationTable, SpaceTimeCartographer, SpaceTimeNode,
+};
+use core::cmp::Ordering;
+use core::hash::Hash;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+// Constants -------------------------------------------------------------------
+
+/// The default maximum number of constraint tree nodes to expand
+pub const DEFAULT_MAX_NODE_COUNT: usize = 10_000;
+
+/// The default time step after which a low-level search stops expanding nodes
+pub const DEFAULT_MAX_TIME_STEP: usize = 256;
+
+// Structures ------------------------------------------------------------------
+
+/// A collision between the paths of two agents
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Conflict<N> {
+  /// The agents swap places between the time step and the next time step
+  Edge {
+    agent_indices: (usize, usize),
+    /// The node of the first agent at the time step
+    from_node: N,
+    /// The node of the first agent at the next time step
+    to_node: N,
+    time_step: usize,
+  },
+  /// The agents occupy the same node at the same time step
+  Vertex {
+    agent_indices: (usize, usize),
+    node: N,
+    time_step: usize,
+  },
+}
+
+/// Conflict-Based Search (CBS).
+/// Finds collision-free paths with the minimal sum of costs.
+/// The agents share a cartographer whose estimate_cost_between is used as the
+/// heuristic to the goal of each agent.
+/// The goal of the shared cartographer itself is ignored.
+pub struct ConflictBasedSearch {
+  /// The search gives up after expanding this many constraint tree nodes
+  pub max_node_count: usize,
+  /// Low-level searches stop expanding nodes at this time step
+  pub max_time_step: usize,
+  pub wait_cost: f64,
+}
+
+/// The result of a Conflict-Based Search
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConflictBasedSearchOutcome<N> {
+  /// No solution was found within the maximum node count
+  BudgetExhausted,
+  /// The paths are the nodes occupied at each time step starting with the
+  /// start nodes.
+  /// Each agent stays at its goal after the end of its path.
+  Solved {
+    cost: f64,
+    path_list: Vec<Vec<N>>,
+  },
+  /// No collision-free paths exist within the maximum time step
+  Unsolvable,
+}
+
+/// A node in the constraint tree
+#[derive(Clone, Debug)]
+pub struct ConstraintNode<N> {
+  /// The blocked moves and nodes of each agent
+  pub constraint_table_list: Vec<ReservationTable<N>>,
+  pub cost: f64,
+  pub cost_list: Vec<f64>,
+  pub path_list: Vec<Vec<N>>,
+  /// Insertion counter used to break ties in first-in first-out order
+  pub sequence: u64,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl ConflictBasedSearch {
+  /// The first conflict in time between any two of the paths
+  pub fn get_first_conflict<N: Copy + PartialEq>(
+    path_list: &[Vec<N>]
+  ) -> Option<Conflict<N>> {
+    let time_step_count: usize =
+      path_list.iter().map(|path| path.len()).max().unwrap_or(0);
+    for time_step in 0..time_step_count {
+      for (index_0, path_0) in path_list.iter().enumerate() {
+        for (index_1, path_1) in path_list.iter().enumerate().skip(index_0 + 1)
+        {
+          let node_0: N = get_node_at(path_0, time_step);
+          let node_1: N = get_node_at(path_1, time_step);
+          if node_0 == node_1 {
+            return Some(Conflict::Vertex {
+              agent_indices: (index_0, index_1),
+              node: node_0,
+              time_step,
+            });
+          }
+          let next_node_0: N = get_node_at(path_0, time_step + 1);
+          let next_node_1: N = get_node_at(path_1, time_step + 1);
+          if node_0 == next_node_1 && node_1 == next_node_0 {
+            return Some(Conflict::Edge {
+              agent_indices: (index_0, index_1),
+              from_node: node_0,
+              to_node: next_node_0,
+              time_step,
+            });
+          }
+        }
+      }
+    }
+    None
+  }
+
+  /// Finds collision-free paths for the agents.
+  /// Each agent is a pair of start and goal nodes.
+  pub fn search<N: Copy + Eq + Hash>(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+    agent_list: &[(N, N)],
+  ) -> ConflictBasedSearchOutcome<N> {
+    let start_node_set: HashSet<N> = agent_list
+      .iter()
+      .map(|(start_node, _)| *start_node)
+      .collect();
+    let goal_node_set: HashSet<N> =
+      agent_list.iter().map(|(_, goal_node)| *goal_node).collect();
+    if start_node_set.len() < agent_list.len()
+      || goal_node_set.len() < agent_list.len()
+    {
+      return ConflictBasedSearchOutcome::Unsolvable;
+    }
+    let mut root_node = ConstraintNode {
+      constraint_table_list: Vec::new(),
+      cost: 0.0,
+      cost_list: Vec::new(),
+      path_list: Vec::new(),
+      sequence: 0,
+    };
+    for (start_node, goal_node) in agent_list {
+      let constraint_table = ReservationTable::default();
+      let Some((cost, path)) = self.plan_path(
+        cartographer,
+        *start_node,
+        *goal_node,
+        &constraint_table,
+      ) else {
+        return ConflictBasedSearchOutcome::Unsolvable;
+      };
+      root_node.constraint_table_list.push(constraint_table);
+      root_node.cost += cost;
+      root_node.cost_list.push(cost);
+      root_node.path_list.push(path);
+    }
+    let mut open_node_heap: BinaryHeap<ConstraintNode<N>> =
+      BinaryHeap::from([root_node]);
+    let mut sequence: u64 = 1;
+    for _ in 0..self.max_node_count {
+      let Some(constraint_node) = open_node_heap.pop() else {
+        return ConflictBasedSearchOutcome::Unsolvable;
+      };
+      let Some(conflict) = Self::get_first_conflict(&constraint_node.path_list)
+      else {
+        return ConflictBasedSearchOutcome::Solved {
+          cost: constraint_node.cost,
+          path_list: constraint_node.path_list,
+        };
+      };
+      // Each child forbids one of the agents from its part of the conflict
+      let constraint_list: [(usize, N, N, usize); 2] = match conflict {
+        Conflict::Edge {
+          agent_indices,
+          from_node,
+          to_node,
+          time_step,
+        } => [
+          (agent_indices.0, from_node, to_node, time_step),
+          (agent_indices.1, to_node, from_node, time_step),
+        ],
+        Conflict::Vertex {
+          agent_indices,
+          node,
+          time_step,
+        } => [
+          (agent_indices.0, node, node, time_step),
+          (agent_indices.1, node, node, time_step),
+        ],
+      };
+      let is_vertex_conflict: bool =
+        matches!(conflict, Conflict::Vertex { .. });
+      for (agent_index, from_node, to_node, time_step) in constraint_list {
+        let mut child_node: ConstraintNode<N> = constraint_node.clone();
+        let constraint_table: &mut ReservationTable<N> =
+          &mut child_node.constraint_table_list[agent_index];
+        if is_vertex_conflict {
+          constraint_table.reserve_node(from_node, time_step);
+        } else {
+          // Reserving the opposite move blocks the move
+          constraint_table.reserve_edge(to_node, from_node, time_step);
+        }
+        let (start_node, goal_node) = agent_list[agent_index];
+        let Some((cost, path)) = self.plan_path(
+          cartographer,
+          start_node,
+          goal_node,
+          &child_node.constraint_table_list[agent_index],
+        ) else {
+          continue;
+        };
+        child_node.cost += cost - child_node.cost_list[agent_index];
+        child_node.cost_list[agent_index] = cost;
+        child_node.path_list[agent_index] = path;
+        child_node.sequence = sequence;
+        sequence += 1;
+        open_node_heap.push(child_node);
+      }
+    }
+    ConflictBasedSearchOutcome::BudgetExhausted
+  }
+
+  /// The low-level search for the path of a single agent which satisfies the
+  /// constraints of the agent
+  fn plan_path<N: Copy + Eq + Hash>(
+    &self,
+    cartographer: &dyn Cartographer<N>,
+    start_node: N,
+    goal_node: N,
+    constraint_table: &ReservationTable<N>,
+  ) -> Option<(f64, Vec<N>)> {
+    if constraint_table.is_node_reserved(&start_node, 0) {
+      return None;
+    }
+    let goal_cartographer = GoalCartographer {
+      cartographer,
+      goal_node,
+    };
+    let space_time_cartographer = SpaceTimeCartographer {
+      cartographer: &goal_cartographer,
+      max_time_step: self.max_time_step,
+      reservation_table: constraint_table,
+      wait_cost: self.wait_cost,
+    };
+    let mut astar = AStar::<SpaceTimeNode<N>>::default();
+    let SearchOutcome::GoalFound {
+      cost,
+      path,
+    } = astar.search(
+      SpaceTimeNode {
+        node: start_node,
+        time_step: 0,
+      },
+      &space_time_cartographer,
+      &SearchBudget::default(),
+    )
+    else {
+      return None;
+    };
+    let mut path_node_list: Vec<N> = vec![start_node];
+    path_node_list
+      .extend(path.iter().map(|space_time_node| space_time_node.node));
+    Some((cost, path_node_list))
+  }
+}
+
+// Functions -------------------------------------------------------------------
+
+/// The node occupied at the time step, including after the end of the path
+fn get_node_at<N: Copy>(
+  path: &[N],
+  time_step: usize,
+) -> N {
+  path[time_step.min(path.len() - 1)]
+}
+
+// Trait Default ---------------------------------------------------------------
+
+impl Default for ConflictBasedSearch {
+  fn default() -> Self {
+    ConflictBasedSearch {
+      max_node_count: DEFAULT_MAX_NODE_COUNT,
+      max_time_step: DEFAULT_MAX_TIME_STEP,
+      wait_cost: DEFAULT_WAIT_COST,
+    }
+  }
+}
+
+// Trait Ord -------------------------------------------------------------------
+
+impl<N> Eq for ConstraintNode<N> {}
+
+/// Reversed so that the max-heap BinaryHeap pops the lowest cost first.
+/// Ties are broken in favor of the earliest insertion.
+impl<N> Ord for ConstraintNode<N> {
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> Ordering {
+    other
+      .cost
+      .total_cmp(&self.cost)
+      .then_with(|| other.sequence.cmp(&self.sequence))
+  }
+}
+
+impl<N> PartialEq for ConstraintNode<N> {
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl<N> PartialOrd for ConstraintNode<N> {
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
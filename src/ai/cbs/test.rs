@@ -0,0 +1,220 @@
+// =============================================================================
+//! - Unit tests for Conflict-Based Search
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+type Cell = (i64, i64);
+
+/// A corridor along the x axis from zero to four with a passing bay above the
+/// middle cell.
+/// Moves are to the four adjacent cells.
+#[cfg(test)]
+struct CorridorCartographer {
+  has_bay: bool,
+}
+
+#[cfg(test)]
+impl CorridorCartographer {
+  fn is_cell_available(
+    &self,
+    cell: &Cell,
+  ) -> bool {
+    (cell.1 == 0 && (0..=4).contains(&cell.0))
+      || (self.has_bay && *cell == (2, 1))
+  }
+}
+
+#[cfg(test)]
+impl Cartographer<Cell> for CorridorCartographer {
+  fn estimate_cost_between(
+    &self,
+    from_node: &Cell,
+    to_node: &Cell,
+  ) -> f64 {
+    ((from_node.0 - to_node.0).abs() + (from_node.1 - to_node.1).abs()) as f64
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    _node: &Cell,
+  ) -> f64 {
+    0.0
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Cell,
+  ) -> Vec<Cell> {
+    [
+      (node.0 - 1, node.1),
+      (node.0 + 1, node.1),
+      (node.0, node.1 - 1),
+      (node.0, node.1 + 1),
+    ]
+    .into_iter()
+    .filter(|cell| self.is_cell_available(cell))
+    .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &Cell,
+    _to_node: &Cell,
+  ) -> f64 {
+    1.0
+  }
+
+  fn is_goal_node(
+    &self,
+    _node: &Cell,
+  ) -> bool {
+    false
+  }
+}
+
+#[cfg(test)]
+const TEST_AGENT_LIST: [(Cell, Cell); 2] = [
+  ((0, 0), (4, 0)),
+  ((4, 0), (0, 0)),
+];
+
+#[test]
+fn test_default() {
+  let test_subject = ConflictBasedSearch::default();
+  assert_eq!(test_subject.max_node_count, DEFAULT_MAX_NODE_COUNT);
+  assert_eq!(test_subject.max_time_step, DEFAULT_MAX_TIME_STEP);
+  assert_eq!(test_subject.wait_cost, DEFAULT_WAIT_COST);
+}
+
+#[test]
+fn test_get_first_conflict() {
+  assert_eq!(
+    ConflictBasedSearch::get_first_conflict(&[
+      vec![
+        1, 2, 3
+      ],
+      vec![
+        5, 4
+      ],
+    ]),
+    None
+  );
+  assert_eq!(
+    ConflictBasedSearch::get_first_conflict(&[
+      vec![
+        1, 2, 3
+      ],
+      vec![
+        4, 3, 2
+      ],
+    ]),
+    Some(Conflict::Edge {
+      agent_indices: (0, 1),
+      from_node: 2,
+      to_node: 3,
+      time_step: 1,
+    })
+  );
+  // The second agent arrives where the first agent stays at its goal
+  assert_eq!(
+    ConflictBasedSearch::get_first_conflict(&[
+      vec![
+        0, 1
+      ],
+      vec![
+        5, 4
+      ],
+      vec![
+        4, 3, 2, 1
+      ],
+    ]),
+    Some(Conflict::Vertex {
+      agent_indices: (0, 2),
+      node: 1,
+      time_step: 3,
+    })
+  );
+}
+
+#[test]
+fn test_search() {
+  let test_subject = ConflictBasedSearch::default();
+  let corridor_cartographer = CorridorCartographer {
+    has_bay: true,
+  };
+  let ConflictBasedSearchOutcome::Solved {
+    cost,
+    path_list,
+  } = test_subject.search(&corridor_cartographer, &TEST_AGENT_LIST)
+  else {
+    panic!("not solved");
+  };
+  // One agent enters and leaves the bay and the other waits once for it
+  assert_eq!(cost, 11.0);
+  assert_eq!(ConflictBasedSearch::get_first_conflict(&path_list), None);
+  for ((start_node, goal_node), path) in TEST_AGENT_LIST.iter().zip(path_list) {
+    assert_eq!(path.first(), Some(start_node));
+    assert_eq!(path.last(), Some(goal_node));
+  }
+}
+
+#[test]
+fn test_search_budget_exhausted() {
+  // Without the bay the agents can never pass each other
+  let test_subject = ConflictBasedSearch {
+    max_node_count: 20,
+    ..Default::default()
+  };
+  let corridor_cartographer = CorridorCartographer {
+    has_bay: false,
+  };
+  assert_eq!(
+    test_subject.search(&corridor_cartographer, &TEST_AGENT_LIST),
+    ConflictBasedSearchOutcome::BudgetExhausted
+  );
+}
+
+#[test]
+fn test_search_unsolvable() {
+  let test_subject = ConflictBasedSearch::default();
+  let corridor_cartographer = CorridorCartographer {
+    has_bay: false,
+  };
+  // The same goal for both agents
+  assert_eq!(
+    test_subject.search(
+      &corridor_cartographer,
+      &[
+        ((0, 0), (2, 0)),
+        ((4, 0), (2, 0)),
+      ]
+    ),
+    ConflictBasedSearchOutcome::Unsolvable
+  );
+  // A goal outside of the corridor
+  assert_eq!(
+    test_subject.search(&corridor_cartographer, &[((0, 0), (2, 1))]),
+    ConflictBasedSearchOutcome::Unsolvable
+  );
+  // Every low-level search fails within the maximum time step
+  let test_subject = ConflictBasedSearch {
+    max_time_step: 5,
+    ..Default::default()
+  };
+  assert_eq!(
+    test_subject.search(&corridor_cartographer, &TEST_AGENT_LIST),
+    ConflictBasedSearchOutcome::Unsolvable
+  );
+}
@@ -0,0 +1,226 @@
+// =============================================================================
+//! - Flow fields for many agents sharing the same goals
+//! - A single Dijkstra expansion outward from the goals
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{NodeInfo, OpenNode};
+use crate::ai::astar::traits::Cartographer;
+use core::hash::Hash;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+// Structures ------------------------------------------------------------------
+
+/// The cost to the nearest goal and the best next node for every node which
+/// can reach a goal.
+/// The costs are expanded backward from the goals using the predecessor
+/// nodes of the cartographer so that directed graphs are supported.
+pub struct FlowField<N: Eq + Hash> {
+  pub goal_nodes: Vec<N>,
+  pub node_to_cost_map: HashMap<N, f64>,
+  pub node_to_next_node_map: HashMap<N, N>,
+  /// Priority queue with lazy deletion of stale entries
+  pub open_node_heap: BinaryHeap<OpenNode<N>>,
+  /// Insertion counter used to break ties in first-in first-out order
+  pub open_node_sequence: u64,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<N: Copy + Eq + Hash> FlowField<N> {
+  /// Expands outward from the goals until every node which can reach a goal
+  /// has a cost
+  pub fn compute(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+  ) {
+    while let Some(open_node) = self.open_node_heap.pop() {
+      let node: N = open_node.node;
+      let cost: f64 = open_node.node_info.cost_from_start;
+      if self
+        .node_to_cost_map
+        .get(&node)
+        .is_none_or(|node_cost| *node_cost < cost)
+      {
+        continue;
+      }
+      for predecessor_node in cartographer.get_predecessor_nodes(&node) {
+        let new_cost: f64 = cost
+          + cartographer.get_cost_to_adjacent_node(&predecessor_node, &node);
+        if self
+          .node_to_cost_map
+          .get(&predecessor_node)
+          .is_some_and(|old_cost| *old_cost <= new_cost)
+        {
+          continue;
+        }
+        self.node_to_cost_map.insert(predecessor_node, new_cost);
+        self.node_to_next_node_map.insert(predecessor_node, node);
+        self.push_open_node(predecessor_node, new_cost);
+      }
+    }
+  }
+
+  /// The cost from the node to the nearest goal
+  pub fn get_cost(
+    &self,
+    node: &N,
+  ) -> Option<f64> {
+    self.node_to_cost_map.get(node).copied()
+  }
+
+  /// The best next node toward the nearest goal
+  pub fn get_next_node(
+    &self,
+    node: &N,
+  ) -> Option<N> {
+    self.node_to_next_node_map.get(node).copied()
+  }
+
+  /// Follows the best next nodes from the node to a goal.
+  /// The node is excluded.
+  pub fn get_path(
+    &self,
+    node: &N,
+  ) -> VecDeque<N> {
+    let mut path_list = VecDeque::new();
+    let mut next_node_option: Option<N> = self.get_next_node(node);
+    while let Some(next_node) = next_node_option {
+      path_list.push_back(next_node);
+      next_node_option = self.get_next_node(&next_node);
+    }
+    path_list
+  }
+
+  /// Removes the costs which depended on the changed nodes and then repairs
+  /// the flow field by expanding from the nodes around them.
+  /// Call this after the adjacent nodes or costs of the changed nodes change.
+  pub fn invalidate(
+    &mut self,
+    cartographer: &dyn Cartographer<N>,
+    changed_nodes: &[N],
+  ) {
+    let changed_node_set: HashSet<N> = changed_nodes.iter().copied().collect();
+    let invalid_node_set: HashSet<N> =
+      self.get_dependent_nodes(&changed_node_set);
+    for invalid_node in invalid_node_set.iter() {
+      self.node_to_cost_map.remove(invalid_node);
+      self.node_to_next_node_map.remove(invalid_node);
+    }
+    for goal_node in self.goal_nodes.clone() {
+      if invalid_node_set.contains(&goal_node) {
+        self.node_to_cost_map.insert(goal_node, 0.0);
+        self.push_open_node(goal_node, 0.0);
+      }
+    }
+    // The remaining costs next to the invalid or changed nodes are the seeds
+    let mut seed_node_set: HashSet<N> = HashSet::new();
+    for node in invalid_node_set.iter().chain(changed_nodes) {
+      for adjacent_node in cartographer.get_adjacent_nodes(node) {
+        if let Some(cost) = self.get_cost(&adjacent_node) {
+          if seed_node_set.insert(adjacent_node) {
+            self.push_open_node(adjacent_node, cost);
+          }
+        }
+      }
+    }
+    self.compute(cartographer);
+  }
+
+  /// Clears the flow field and starts a new expansion from the goal nodes
+  pub fn reset(
+    &mut self,
+    goal_nodes: &[N],
+  ) {
+    self.goal_nodes = goal_nodes.to_vec();
+    self.node_to_cost_map = HashMap::new();
+    self.node_to_next_node_map = HashMap::new();
+    self.open_node_heap = BinaryHeap::new();
+    self.open_node_sequence = 0;
+    for goal_node in goal_nodes {
+      self.node_to_cost_map.insert(*goal_node, 0.0);
+      self.push_open_node(*goal_node, 0.0);
+    }
+  }
+
+  /// The changed nodes with a cost and the nodes whose best next nodes lead
+  /// through them
+  fn get_dependent_nodes(
+    &self,
+    changed_node_set: &HashSet<N>,
+  ) -> HashSet<N> {
+    let mut dependent_node_set: HashSet<N> = changed_node_set
+      .iter()
+      .filter(|node| self.node_to_cost_map.contains_key(node))
+      .copied()
+      .collect();
+    let mut independent_node_set: HashSet<N> = HashSet::new();
+    for node in self.node_to_next_node_map.keys() {
+      let mut chain_list: Vec<N> = Vec::new();
+      let mut chain_node: N = *node;
+      let dependent: bool = loop {
+        if dependent_node_set.contains(&chain_node) {
+          break true;
+        }
+        if independent_node_set.contains(&chain_node) {
+          break false;
+        }
+        chain_list.push(chain_node);
+        match self.node_to_next_node_map.get(&chain_node) {
+          Some(next_node) => chain_node = *next_node,
+          None => break false,
+        }
+      };
+      if dependent {
+        dependent_node_set.extend(chain_list);
+      } else {
+        independent_node_set.extend(chain_list);
+      }
+    }
+    dependent_node_set
+  }
+
+  fn push_open_node(
+    &mut self,
+    node: N,
+    cost: f64,
+  ) {
+    self.open_node_heap.push(OpenNode {
+      node,
+      node_info: NodeInfo {
+        cost_from_start: cost,
+        total_cost: cost,
+      },
+      sequence: self.open_node_sequence,
+    });
+    self.open_node_sequence += 1;
+  }
+}
+
+// Trait Default ---------------------------------------------------------------
+
+impl<N: Eq + Hash> Default for FlowField<N> {
+  fn default() -> Self {
+    FlowField {
+      goal_nodes: Vec::new(),
+      node_to_cost_map: HashMap::new(),
+      node_to_next_node_map: HashMap::new(),
+      open_node_heap: BinaryHeap::new(),
+      open_node_sequence: 0,
+    }
+  }
+}
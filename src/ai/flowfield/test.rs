@@ -0,0 +1,177 @@
+// =============================================================================
+//! - Unit tests for the flow fields
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+#[cfg(test)]
+use crate::ai::astar::structures::GridCartographer;
+#[cfg(test)]
+use crate::math::geom::point_2dd::Point2DD;
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+const TEST_GOAL_NODE_0: Point2DD = Point2DD {
+  x: 0.0,
+  y: 0.0,
+};
+#[cfg(test)]
+const TEST_GOAL_NODE_1: Point2DD = Point2DD {
+  x: 9.0,
+  y: 0.0,
+};
+#[cfg(test)]
+const TEST_GRID_SIZE: f64 = 10.0;
+
+#[cfg(test)]
+fn is_in_bounds(node: &Point2DD) -> bool {
+  node.x >= 0.0
+    && node.y >= 0.0
+    && node.x < TEST_GRID_SIZE
+    && node.y < TEST_GRID_SIZE
+}
+
+#[cfg(test)]
+fn make_test_subject(
+  cartographer: &dyn Cartographer<Point2DD>
+) -> FlowField<Point2DD> {
+  let mut test_subject = FlowField::default();
+  test_subject.reset(&[
+    TEST_GOAL_NODE_0,
+    TEST_GOAL_NODE_1,
+  ]);
+  test_subject.compute(cartographer);
+  test_subject
+}
+
+#[cfg(test)]
+fn make_test_grid_cartographer(
+  is_space_available_fn: impl Fn(&Point2DD) -> bool
+) -> GridCartographer<Point2DD, impl Fn(&Point2DD) -> bool> {
+  // The goal is outside so that there are no shortcuts to it
+  GridCartographer {
+    goal_node: Point2DD::new(-TEST_GRID_SIZE, -TEST_GRID_SIZE),
+    is_space_available_fn,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  }
+}
+
+#[test]
+fn test_compute() {
+  let grid_cartographer = make_test_grid_cartographer(is_in_bounds);
+  let test_subject = make_test_subject(&grid_cartographer);
+  assert_eq!(
+    test_subject.node_to_cost_map.len(),
+    (TEST_GRID_SIZE * TEST_GRID_SIZE) as usize
+  );
+  assert_eq!(test_subject.get_cost(&TEST_GOAL_NODE_0), Some(0.0));
+  assert_eq!(test_subject.get_cost(&Point2DD::new(3.0, 0.0)), Some(3.0));
+  assert_eq!(test_subject.get_cost(&Point2DD::new(7.0, 0.0)), Some(2.0));
+  assert_eq!(
+    test_subject.get_cost(&Point2DD::new(2.0, 2.0)),
+    Some(2.0 * 2.0_f64.sqrt())
+  );
+  assert_eq!(test_subject.get_cost(&Point2DD::new(-1.0, 0.0)), None);
+  assert!(test_subject.open_node_heap.is_empty());
+}
+
+#[test]
+fn test_default() {
+  let test_subject = FlowField::<Point2DD>::default();
+  assert!(test_subject.goal_nodes.is_empty());
+  assert!(test_subject.node_to_cost_map.is_empty());
+  assert!(test_subject.node_to_next_node_map.is_empty());
+  assert!(test_subject.open_node_heap.is_empty());
+  assert_eq!(test_subject.open_node_sequence, 0);
+}
+
+#[test]
+fn test_get_next_node() {
+  let grid_cartographer = make_test_grid_cartographer(is_in_bounds);
+  let test_subject = make_test_subject(&grid_cartographer);
+  assert_eq!(
+    test_subject.get_next_node(&Point2DD::new(1.0, 1.0)),
+    Some(TEST_GOAL_NODE_0)
+  );
+  assert_eq!(
+    test_subject.get_next_node(&Point2DD::new(8.0, 0.0)),
+    Some(TEST_GOAL_NODE_1)
+  );
+  assert_eq!(test_subject.get_next_node(&TEST_GOAL_NODE_0), None);
+}
+
+#[test]
+fn test_get_path() {
+  let grid_cartographer = make_test_grid_cartographer(is_in_bounds);
+  let test_subject = make_test_subject(&grid_cartographer);
+  assert_eq!(
+    test_subject.get_path(&Point2DD::new(6.0, 0.0)),
+    VecDeque::from([
+      Point2DD::new(7.0, 0.0),
+      Point2DD::new(8.0, 0.0),
+      TEST_GOAL_NODE_1,
+    ])
+  );
+  assert!(test_subject.get_path(&TEST_GOAL_NODE_1).is_empty());
+}
+
+#[test]
+fn test_invalidate() {
+  let blocked_set: RefCell<HashSet<Point2DD>> = RefCell::new(HashSet::new());
+  let grid_cartographer = make_test_grid_cartographer(|node: &Point2DD| {
+    is_in_bounds(node) && !blocked_set.borrow().contains(node)
+  });
+  let mut test_subject = make_test_subject(&grid_cartographer);
+  // A wall which separates the second goal from the rest of the grid
+  let wall_nodes: Vec<Point2DD> = [
+    (8.0, 0.0),
+    (8.0, 1.0),
+    (9.0, 1.0),
+  ]
+  .iter()
+  .map(|(x, y)| Point2DD::new(*x, *y))
+  .collect();
+  blocked_set.borrow_mut().extend(wall_nodes.iter());
+  test_subject.invalidate(&grid_cartographer, &wall_nodes);
+  let expected_flow_field = make_test_subject(&grid_cartographer);
+  assert_eq!(
+    test_subject.node_to_cost_map,
+    expected_flow_field.node_to_cost_map
+  );
+  assert_eq!(test_subject.get_cost(&Point2DD::new(7.0, 0.0)), Some(7.0));
+  assert_eq!(test_subject.get_cost(&TEST_GOAL_NODE_1), Some(0.0));
+  blocked_set.borrow_mut().clear();
+  test_subject.invalidate(&grid_cartographer, &wall_nodes);
+  assert_eq!(test_subject.get_cost(&Point2DD::new(7.0, 0.0)), Some(2.0));
+}
+
+#[test]
+fn test_reset() {
+  let mut test_subject = FlowField::default();
+  test_subject.reset(&[
+    TEST_GOAL_NODE_0,
+    TEST_GOAL_NODE_1,
+  ]);
+  assert_eq!(
+    test_subject.goal_nodes,
+    vec![
+      TEST_GOAL_NODE_0,
+      TEST_GOAL_NODE_1
+    ]
+  );
+  assert_eq!(test_subject.node_to_cost_map.len(), 2);
+  assert_eq!(test_subject.open_node_heap.len(), 2);
+  assert_eq!(test_subject.open_node_sequence, 2);
+  assert!(test_subject.node_to_next_node_map.is_empty());
+}
@@ -0,0 +1,618 @@
+// =============================================================================
+//! - Hierarchical pathfinding A* (HPA*)
+//! - Splits a grid into square clusters connected through entrances
+//! - Searches the abstract graph of entrances and then refines the segments
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - Based on the paper "Near Optimal Hierarchical Path-Finding"
+//!   - By Adi Botea, Martin Müller, and Jonathan Schaeffer, 2004
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{
+  AStar, GridCartographer, SearchBudget, SearchOutcome,
+};
+use crate::ai::astar::traits::Cartographer;
+use crate::ai::astar::types::{IsSpaceAvailableFunction, MakeNodeFunction};
+use crate::math::geom::point_xy::PointXY;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+// Constants -------------------------------------------------------------------
+
+/// Entrances at least this long have a transition at each end instead of
+/// a single transition in the middle
+pub const ENTRANCE_SPLIT_LENGTH: usize = 6;
+
+// Types -----------------------------------------------------------------------
+
+/// A cell column and row
+pub type Cell = (usize, usize);
+
+/// A cluster column and row
+pub type Cluster = (usize, usize);
+
+// Structures ------------------------------------------------------------------
+
+/// Searches the abstract graph with the start and goal inserted
+pub struct AbstractCartographer<'a, N> {
+  pub abstract_node_to_edges_map: &'a HashMap<N, Vec<AbstractEdge<N>>>,
+  pub goal_node: N,
+  /// Temporary edges which connect the start and goal to the abstract graph
+  pub inserted_node_to_edges_map: HashMap<N, Vec<AbstractEdge<N>>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AbstractEdge<N> {
+  pub cost: f64,
+  pub node: N,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbstractPath<N> {
+  pub cost: f64,
+  /// The abstract nodes from the start to the goal
+  pub node_list: Vec<N>,
+}
+
+/// Precomputed cluster graph over a bounded grid.
+/// Uses the same grid conventions as the GridCartographer with the cell at
+/// column and row at the coordinates column and row times the step size.
+/// Call spaces_changed when the space available results change.
+pub struct HierarchicalPathfinder<
+  N,
+  S = IsSpaceAvailableFunction<N>,
+  M = MakeNodeFunction<N>,
+> {
+  /// The intra-cluster and inter-cluster edges of each entrance node
+  pub abstract_node_to_edges_map: HashMap<N, Vec<AbstractEdge<N>>>,
+  pub cluster_size: usize,
+  pub cluster_to_abstract_nodes_map: HashMap<Cluster, Vec<N>>,
+  pub column_count: usize,
+  pub is_space_available_fn: S,
+  pub make_node_fn: M,
+  pub row_count: usize,
+  pub step_size: f64,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<N, S, M> HierarchicalPathfinder<N, S, M>
+where
+  N: PointXY,
+  S: Fn(&N) -> bool,
+  M: Fn(f64, f64) -> N,
+{
+  /// Searches the abstract graph.
+  /// Returns None if the start or goal is unavailable or if the goal cannot
+  /// be reached.
+  pub fn find_abstract_path(
+    &self,
+    start_node: N,
+    goal_node: N,
+  ) -> Option<AbstractPath<N>> {
+    let start_cluster: Cluster = self.get_cluster(&start_node)?;
+    let goal_cluster: Cluster = self.get_cluster(&goal_node)?;
+    if !(self.is_space_available_fn)(&start_node)
+      || !(self.is_space_available_fn)(&goal_node)
+    {
+      return None;
+    }
+    if start_node == goal_node {
+      return Some(AbstractPath {
+        cost: 0.0,
+        node_list: vec![start_node],
+      });
+    }
+    let mut inserted_node_to_edges_map: HashMap<N, Vec<AbstractEdge<N>>> =
+      HashMap::new();
+    let mut start_edges: Vec<AbstractEdge<N>> = Vec::new();
+    for node in self.get_abstract_nodes(start_cluster) {
+      if *node == start_node {
+        continue;
+      }
+      if let Some((cost, _)) =
+        self.search_cluster(start_cluster, start_node, *node)
+      {
+        start_edges.push(AbstractEdge {
+          cost,
+          node: *node,
+        });
+      }
+    }
+    if start_cluster == goal_cluster {
+      if let Some((cost, _)) =
+        self.search_cluster(start_cluster, start_node, goal_node)
+      {
+        start_edges.push(AbstractEdge {
+          cost,
+          node: goal_node,
+        });
+      }
+    }
+    inserted_node_to_edges_map.insert(start_node, start_edges);
+    for node in self.get_abstract_nodes(goal_cluster) {
+      if *node == goal_node {
+        continue;
+      }
+      if let Some((cost, _)) =
+        self.search_cluster(goal_cluster, *node, goal_node)
+      {
+        inserted_node_to_edges_map.entry(*node).or_default().push(
+          AbstractEdge {
+            cost,
+            node: goal_node,
+          },
+        );
+      }
+    }
+    let abstract_cartographer = AbstractCartographer {
+      abstract_node_to_edges_map: &self.abstract_node_to_edges_map,
+      goal_node,
+      inserted_node_to_edges_map,
+    };
+    let mut astar = AStar::<N>::default();
+    let SearchOutcome::GoalFound {
+      cost,
+      path,
+    } = astar.search(
+      start_node,
+      &abstract_cartographer,
+      &SearchBudget::default(),
+    )
+    else {
+      return None;
+    };
+    let mut node_list: Vec<N> = vec![start_node];
+    node_list.extend(path);
+    Some(AbstractPath {
+      cost,
+      node_list,
+    })
+  }
+
+  /// Searches the abstract graph and refines every segment.
+  /// The start node is excluded.
+  pub fn find_path(
+    &self,
+    start_node: N,
+    goal_node: N,
+  ) -> Option<VecDeque<N>> {
+    let abstract_path: AbstractPath<N> =
+      self.find_abstract_path(start_node, goal_node)?;
+    let mut path_list: VecDeque<N> = VecDeque::new();
+    for segment in abstract_path.node_list.windows(2) {
+      path_list.extend(self.refine_segment(segment[0], segment[1]));
+    }
+    Some(path_list)
+  }
+
+  pub fn get_abstract_nodes(
+    &self,
+    cluster: Cluster,
+  ) -> &[N] {
+    self
+      .cluster_to_abstract_nodes_map
+      .get(&cluster)
+      .map_or(&[], |nodes| nodes.as_slice())
+  }
+
+  /// The cell nearest to the node or None if it is outside of the grid
+  pub fn get_cell(
+    &self,
+    node: &N,
+  ) -> Option<Cell> {
+    let column: f64 = (node.get_x() / self.step_size).round();
+    let row: f64 = (node.get_y() / self.step_size).round();
+    if column < 0.0
+      || row < 0.0
+      || column >= self.column_count as f64
+      || row >= self.row_count as f64
+    {
+      return None;
+    }
+    Some((column as usize, row as usize))
+  }
+
+  pub fn get_cell_node(
+    &self,
+    cell: Cell,
+  ) -> N {
+    (self.make_node_fn)(
+      cell.0 as f64 * self.step_size,
+      cell.1 as f64 * self.step_size,
+    )
+  }
+
+  pub fn get_cluster(
+    &self,
+    node: &N,
+  ) -> Option<Cluster> {
+    let (column, row) = self.get_cell(node)?;
+    Some((column / self.cluster_size, row / self.cluster_size))
+  }
+
+  pub fn get_cluster_count(&self) -> (usize, usize) {
+    (
+      self.column_count.div_ceil(self.cluster_size),
+      self.row_count.div_ceil(self.cluster_size),
+    )
+  }
+
+  /// The column range and the row range of the cells in the cluster
+  pub fn get_cluster_ranges(
+    &self,
+    cluster: Cluster,
+  ) -> (Range<usize>, Range<usize>) {
+    let column_start: usize = cluster.0 * self.cluster_size;
+    let row_start: usize = cluster.1 * self.cluster_size;
+    (
+      column_start..(column_start + self.cluster_size).min(self.column_count),
+      row_start..(row_start + self.cluster_size).min(self.row_count),
+    )
+  }
+
+  pub fn is_cell_available(
+    &self,
+    cell: Cell,
+  ) -> bool {
+    (self.is_space_available_fn)(&self.get_cell_node(cell))
+  }
+
+  /// Builds the abstract graph for all of the clusters
+  pub fn new(
+    cluster_size: usize,
+    column_count: usize,
+    row_count: usize,
+    step_size: f64,
+    is_space_available_fn: S,
+    make_node_fn: M,
+  ) -> Self {
+    let mut hierarchical_pathfinder = HierarchicalPathfinder {
+      abstract_node_to_edges_map: HashMap::new(),
+      cluster_size,
+      cluster_to_abstract_nodes_map: HashMap::new(),
+      column_count,
+      is_space_available_fn,
+      make_node_fn,
+      row_count,
+      step_size,
+    };
+    let (cluster_columns, cluster_rows) =
+      hierarchical_pathfinder.get_cluster_count();
+    for cluster_column in 0..cluster_columns {
+      for cluster_row in 0..cluster_rows {
+        hierarchical_pathfinder.rebuild_cluster((cluster_column, cluster_row));
+      }
+    }
+    hierarchical_pathfinder
+  }
+
+  /// The path within a segment of the abstract path excluding the from node.
+  /// Empty if the segment cannot be refined.
+  pub fn refine_segment(
+    &self,
+    from_node: N,
+    to_node: N,
+  ) -> VecDeque<N> {
+    let (Some(from_cluster), Some(to_cluster)) =
+      (self.get_cluster(&from_node), self.get_cluster(&to_node))
+    else {
+      return VecDeque::new();
+    };
+    if from_cluster != to_cluster {
+      return VecDeque::from([to_node]);
+    }
+    self
+      .search_cluster(from_cluster, from_node, to_node)
+      .map(|(_, path)| path)
+      .unwrap_or_default()
+  }
+
+  /// Searches within the cluster using a GridCartographer which treats the
+  /// spaces outside of the cluster as unavailable
+  pub fn search_cluster(
+    &self,
+    cluster: Cluster,
+    from_node: N,
+    to_node: N,
+  ) -> Option<(f64, VecDeque<N>)> {
+    let (column_range, row_range) = self.get_cluster_ranges(cluster);
+    let grid_cartographer = GridCartographer {
+      goal_node: to_node,
+      is_space_available_fn: |node: &N| {
+        self.get_cell(node).is_some_and(|(column, row)| {
+          column_range.contains(&column) && row_range.contains(&row)
+        }) && (self.is_space_available_fn)(node)
+      },
+      make_node_fn: &self.make_node_fn,
+      step_size: self.step_size,
+    };
+    let mut astar = AStar::<N>::default();
+    match astar.search(from_node, &grid_cartographer, &SearchBudget::default())
+    {
+      SearchOutcome::GoalFound {
+        cost,
+        path,
+      } => Some((cost, path)),
+      _ => None,
+    }
+  }
+
+  /// Updates the abstract graph locally for the clusters of the changed
+  /// nodes and their neighboring clusters
+  pub fn spaces_changed(
+    &mut self,
+    changed_nodes: &[N],
+  ) {
+    let (cluster_columns, cluster_rows) = self.get_cluster_count();
+    let mut cluster_set: HashSet<Cluster> = HashSet::new();
+    let mut cluster_list: Vec<Cluster> = Vec::new();
+    for changed_node in changed_nodes {
+      let Some((cluster_column, cluster_row)) = self.get_cluster(changed_node)
+      else {
+        continue;
+      };
+      for column in cluster_column.saturating_sub(1)
+        ..(cluster_column + 2).min(cluster_columns)
+      {
+        for row in
+          cluster_row.saturating_sub(1)..(cluster_row + 2).min(cluster_rows)
+        {
+          if cluster_set.insert((column, row)) {
+            cluster_list.push((column, row));
+          }
+        }
+      }
+    }
+    for cluster in cluster_list {
+      self.rebuild_cluster(cluster);
+    }
+  }
+
+  /// The transitions across a border as pairs of cells with the first on
+  /// the near side.
+  /// An entrance is a run of border positions where the cells on both
+  /// sides are available.
+  /// A diagonal crossing is added where there is no entrance beside it.
+  fn get_border_transitions(
+    &self,
+    border_range: Range<usize>,
+    get_cells: impl Fn(usize) -> (Cell, Cell),
+  ) -> Vec<(Cell, Cell)> {
+    let mut transitions: Vec<(Cell, Cell)> = Vec::new();
+    let is_open = |index: usize| {
+      let (near_cell, far_cell) = get_cells(index);
+      self.is_cell_available(near_cell) && self.is_cell_available(far_cell)
+    };
+    let mut run_start_option: Option<usize> = None;
+    for index in border_range.start..=border_range.end {
+      let open: bool = index < border_range.end && is_open(index);
+      match (open, run_start_option) {
+        (true, None) => run_start_option = Some(index),
+        (false, Some(run_start)) => {
+          let run_end: usize = index - 1;
+          if run_end + 1 - run_start >= ENTRANCE_SPLIT_LENGTH {
+            transitions.push(get_cells(run_start));
+            transitions.push(get_cells(run_end));
+          } else {
+            transitions.push(get_cells((run_start + run_end) / 2));
+          }
+          run_start_option = None;
+        },
+        _ => {},
+      }
+    }
+    for index in border_range.start..border_range.end.saturating_sub(1) {
+      if is_open(index) || is_open(index + 1) {
+        continue;
+      }
+      let (near_cell, _) = get_cells(index);
+      let (_, far_cell) = get_cells(index + 1);
+      if self.is_cell_available(near_cell) && self.is_cell_available(far_cell) {
+        transitions.push((near_cell, far_cell));
+      }
+      let (near_cell, _) = get_cells(index + 1);
+      let (_, far_cell) = get_cells(index);
+      if self.is_cell_available(near_cell) && self.is_cell_available(far_cell) {
+        transitions.push((near_cell, far_cell));
+      }
+    }
+    transitions
+  }
+
+  /// The transitions from the cluster to its eight neighboring clusters as
+  /// pairs of cells with the first in the cluster
+  fn get_cluster_transitions(
+    &self,
+    cluster: Cluster,
+  ) -> Vec<(Cell, Cell)> {
+    let (column_range, row_range) = self.get_cluster_ranges(cluster);
+    let first_column: usize = column_range.start;
+    let first_row: usize = row_range.start;
+    let last_column: usize = column_range.end - 1;
+    let last_row: usize = row_range.end - 1;
+    let has_east: bool = column_range.end < self.column_count;
+    let has_north: bool = first_row > 0;
+    let has_south: bool = row_range.end < self.row_count;
+    let has_west: bool = first_column > 0;
+    let mut transitions: Vec<(Cell, Cell)> = Vec::new();
+    if has_east {
+      transitions.extend(
+        self.get_border_transitions(row_range.clone(), |row| {
+          ((last_column, row), (last_column + 1, row))
+        }),
+      );
+    }
+    if has_west {
+      transitions.extend(
+        self.get_border_transitions(row_range.clone(), |row| {
+          ((first_column, row), (first_column - 1, row))
+        }),
+      );
+    }
+    if has_south {
+      transitions.extend(
+        self.get_border_transitions(column_range.clone(), |column| {
+          ((column, last_row), (column, last_row + 1))
+        }),
+      );
+    }
+    if has_north {
+      transitions.extend(
+        self.get_border_transitions(column_range.clone(), |column| {
+          ((column, first_row), (column, first_row - 1))
+        }),
+      );
+    }
+    let mut corner_transitions: Vec<(Cell, Cell)> = Vec::new();
+    if has_east && has_south {
+      corner_transitions
+        .push(((last_column, last_row), (last_column + 1, last_row + 1)));
+    }
+    if has_east && has_north {
+      corner_transitions
+        .push(((last_column, first_row), (last_column + 1, first_row - 1)));
+    }
+    if has_west && has_south {
+      corner_transitions
+        .push(((first_column, last_row), (first_column - 1, last_row + 1)));
+    }
+    if has_west && has_north {
+      corner_transitions
+        .push(((first_column, first_row), (first_column - 1, first_row - 1)));
+    }
+    for (near_cell, far_cell) in corner_transitions {
+      if self.is_cell_available(near_cell) && self.is_cell_available(far_cell) {
+        transitions.push((near_cell, far_cell));
+      }
+    }
+    transitions
+  }
+
+  /// Replaces the abstract nodes of the cluster and their edges
+  fn rebuild_cluster(
+    &mut self,
+    cluster: Cluster,
+  ) {
+    if let Some(old_nodes) = self.cluster_to_abstract_nodes_map.remove(&cluster)
+    {
+      for old_node in old_nodes {
+        self.abstract_node_to_edges_map.remove(&old_node);
+      }
+    }
+    let mut nodes: Vec<N> = Vec::new();
+    let mut node_to_edges_map: HashMap<N, Vec<AbstractEdge<N>>> =
+      HashMap::new();
+    for (near_cell, far_cell) in self.get_cluster_transitions(cluster) {
+      let near_node: N = self.get_cell_node(near_cell);
+      let far_node: N = self.get_cell_node(far_cell);
+      if !nodes.contains(&near_node) {
+        nodes.push(near_node);
+      }
+      node_to_edges_map
+        .entry(near_node)
+        .or_default()
+        .push(AbstractEdge {
+          cost: near_node.distance_xy(&far_node),
+          node: far_node,
+        });
+    }
+    for (index, from_node) in nodes.iter().enumerate() {
+      for to_node in nodes.iter().skip(index + 1) {
+        let Some((cost, _)) =
+          self.search_cluster(cluster, *from_node, *to_node)
+        else {
+          continue;
+        };
+        // The grid costs are symmetric
+        node_to_edges_map
+          .entry(*from_node)
+          .or_default()
+          .push(AbstractEdge {
+            cost,
+            node: *to_node,
+          });
+        node_to_edges_map
+          .entry(*to_node)
+          .or_default()
+          .push(AbstractEdge {
+            cost,
+            node: *from_node,
+          });
+      }
+    }
+    self.abstract_node_to_edges_map.extend(node_to_edges_map);
+    self.cluster_to_abstract_nodes_map.insert(cluster, nodes);
+  }
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl<N: PointXY> Cartographer<N> for AbstractCartographer<'_, N> {
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    from_node.distance_xy(to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> f64 {
+    node.distance_xy(&self.goal_node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self
+      .abstract_node_to_edges_map
+      .get(node)
+      .into_iter()
+      .chain(self.inserted_node_to_edges_map.get(node))
+      .flatten()
+      .map(|abstract_edge| abstract_edge.node)
+      .collect()
+  }
+
+  /// The lowest cost of the edges between the nodes
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> f64 {
+    self
+      .abstract_node_to_edges_map
+      .get(from_node)
+      .into_iter()
+      .chain(self.inserted_node_to_edges_map.get(from_node))
+      .flatten()
+      .filter(|abstract_edge| abstract_edge.node == *to_node)
+      .map(|abstract_edge| abstract_edge.cost)
+      .fold(f64::INFINITY, f64::min)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
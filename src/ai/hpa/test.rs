@@ -0,0 +1,212 @@
+// =============================================================================
+//! - Unit tests for hierarchical pathfinding
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+#[cfg(test)]
+use crate::math::geom::point_2dd::Point2DD;
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+const TEST_CLUSTER_SIZE: usize = 4;
+#[cfg(test)]
+const TEST_GRID_SIZE: usize = 8;
+#[cfg(test)]
+const TEST_TOLERANCE: f64 = 0.001;
+
+#[cfg(test)]
+fn is_space_available(node: &Point2DD) -> bool {
+  node.x >= 0.0
+    && node.y >= 0.0
+    && node.x < TEST_GRID_SIZE as f64
+    && node.y < TEST_GRID_SIZE as f64
+}
+
+#[cfg(test)]
+fn make_test_subject() -> HierarchicalPathfinder<Point2DD> {
+  HierarchicalPathfinder::new(
+    TEST_CLUSTER_SIZE,
+    TEST_GRID_SIZE,
+    TEST_GRID_SIZE,
+    1.0,
+    is_space_available,
+    Point2DD::new,
+  )
+}
+
+#[test]
+fn test_find_abstract_path() {
+  let test_subject = make_test_subject();
+  let abstract_path = test_subject
+    .find_abstract_path(Point2DD::new(0.0, 1.0), Point2DD::new(7.0, 1.0))
+    .unwrap();
+  assert_eq!(abstract_path.cost, 7.0);
+  assert_eq!(
+    abstract_path.node_list,
+    vec![
+      Point2DD::new(0.0, 1.0),
+      Point2DD::new(3.0, 1.0),
+      Point2DD::new(4.0, 1.0),
+      Point2DD::new(7.0, 1.0),
+    ]
+  );
+  let abstract_path = test_subject
+    .find_abstract_path(Point2DD::new(1.0, 1.0), Point2DD::new(1.0, 1.0))
+    .unwrap();
+  assert_eq!(abstract_path.cost, 0.0);
+  assert_eq!(
+    test_subject
+      .find_abstract_path(Point2DD::new(-1.0, 0.0), Point2DD::new(1.0, 1.0)),
+    None
+  );
+}
+
+#[test]
+fn test_find_path() {
+  let test_subject = make_test_subject();
+  let start_node = Point2DD::new(0.0, 0.0);
+  let goal_node = Point2DD::new(7.0, 6.0);
+  let path = test_subject.find_path(start_node, goal_node).unwrap();
+  assert_eq!(path.back(), Some(&goal_node));
+  let mut previous_node = start_node;
+  let mut cost = 0.0;
+  for node in path.iter() {
+    assert!(previous_node.distance_to(node) < 1.5);
+    cost += previous_node.distance_to(node);
+    previous_node = *node;
+  }
+  let abstract_path = test_subject
+    .find_abstract_path(start_node, goal_node)
+    .unwrap();
+  assert!((cost - abstract_path.cost).abs() < TEST_TOLERANCE);
+}
+
+#[test]
+fn test_get_cell() {
+  let test_subject = make_test_subject();
+  assert_eq!(
+    test_subject.get_cell(&Point2DD::new(5.0, 2.0)),
+    Some((5, 2))
+  );
+  assert_eq!(test_subject.get_cell(&Point2DD::new(8.0, 2.0)), None);
+  assert_eq!(
+    test_subject.get_cluster(&Point2DD::new(5.0, 2.0)),
+    Some((1, 0))
+  );
+  assert_eq!(test_subject.get_cluster_count(), (2, 2));
+  assert_eq!(test_subject.get_cluster_ranges((1, 0)), (4..8, 0..4));
+}
+
+#[test]
+fn test_new() {
+  let test_subject = make_test_subject();
+  // One transition in the middle of each border and one at each corner
+  assert_eq!(test_subject.get_abstract_nodes((0, 0)).len(), 3);
+  let edges =
+    &test_subject.abstract_node_to_edges_map[&Point2DD::new(3.0, 1.0)];
+  assert!(edges.contains(&AbstractEdge {
+    cost: 1.0,
+    node: Point2DD::new(4.0, 1.0),
+  }));
+  assert!(edges.contains(&AbstractEdge {
+    cost: 2.0_f64.sqrt() * 2.0,
+    node: Point2DD::new(1.0, 3.0),
+  }));
+}
+
+#[test]
+fn test_refine_segment() {
+  let test_subject = make_test_subject();
+  assert_eq!(
+    test_subject
+      .refine_segment(Point2DD::new(3.0, 1.0), Point2DD::new(4.0, 1.0)),
+    VecDeque::from([Point2DD::new(4.0, 1.0)])
+  );
+  assert_eq!(
+    test_subject
+      .refine_segment(Point2DD::new(0.0, 1.0), Point2DD::new(3.0, 1.0))
+      .len(),
+    3
+  );
+}
+
+#[test]
+fn test_spaces_changed() {
+  let blocked_set: RefCell<HashSet<Point2DD>> = RefCell::new(HashSet::new());
+  let mut test_subject = HierarchicalPathfinder::new(
+    TEST_CLUSTER_SIZE,
+    TEST_GRID_SIZE,
+    TEST_GRID_SIZE,
+    1.0,
+    |node: &Point2DD| {
+      is_space_available(node) && !blocked_set.borrow().contains(node)
+    },
+    Point2DD::new,
+  );
+  let start_node = Point2DD::new(0.0, 0.0);
+  let goal_node = Point2DD::new(7.0, 0.0);
+  assert!(test_subject.find_path(start_node, goal_node).is_some());
+  let wall_nodes: Vec<Point2DD> = (0..TEST_GRID_SIZE)
+    .map(|row| Point2DD::new(4.0, row as f64))
+    .collect();
+  blocked_set.borrow_mut().extend(wall_nodes.iter());
+  test_subject.spaces_changed(&wall_nodes);
+  assert_eq!(test_subject.find_path(start_node, goal_node), None);
+  let gap_node = Point2DD::new(4.0, 6.0);
+  blocked_set.borrow_mut().remove(&gap_node);
+  test_subject.spaces_changed(&[gap_node]);
+  let path = test_subject.find_path(start_node, goal_node).unwrap();
+  assert!(path.contains(&gap_node));
+  assert_eq!(path.back(), Some(&goal_node));
+}
+
+#[test]
+fn test_get_cost_to_adjacent_node_for_abstract_cartographer() {
+  let node = Point2DD::new(0.0, 0.0);
+  let other_node = Point2DD::new(1.0, 0.0);
+  let abstract_node_to_edges_map = HashMap::from([(
+    node,
+    vec![
+      AbstractEdge {
+        cost: 3.0,
+        node: other_node,
+      },
+    ],
+  )]);
+  let test_subject = AbstractCartographer {
+    abstract_node_to_edges_map: &abstract_node_to_edges_map,
+    goal_node: other_node,
+    inserted_node_to_edges_map: HashMap::from([(
+      node,
+      vec![
+        AbstractEdge {
+          cost: 2.0,
+          node: other_node,
+        },
+      ],
+    )]),
+  };
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&node),
+    vec![
+      other_node, other_node
+    ]
+  );
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&node, &other_node),
+    2.0
+  );
+  assert_eq!(test_subject.estimate_cost_to_goal(&node), 1.0);
+  assert!(test_subject.is_goal_node(&other_node));
+}
@@ -0,0 +1,299 @@
+// =============================================================================
+//! - K shortest loopless paths using Yen's algorithm
+//! - Searches for each deviation from the shorter paths using A*
+//! - Optionally skips the paths which overlap a shorter path too much
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - Based on the paper "Finding the K Shortest Loopless Paths in a Network"
+//!   - By Jin Y. Yen, 1971
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{AStar, SearchBudget, SearchOutcome};
+use crate::ai::astar::traits::{Cartographer, PathCost};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// Structures ------------------------------------------------------------------
+
+/// Finds the shortest loopless paths from a start node to the goal of a
+/// cartographer in ascending order of cost
+pub struct KShortestPathfinder<C = f64> {
+  /// The most candidate paths from which the deviations are searched.
+  /// The paths found so far are returned when this is reached.
+  /// Use usize::MAX for no limit.
+  pub max_candidate_count: usize,
+  /// A path is skipped if more than this fraction of its steps are also
+  /// steps of any of the shorter paths returned.
+  /// The paths are not required to be diverse if None.
+  pub max_overlap_ratio_option: Option<f64>,
+  /// The maximum number of paths returned
+  pub path_count: usize,
+  /// The budget of each of the searches
+  pub search_budget: SearchBudget<C>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankedPath<N, C = f64> {
+  pub cost: C,
+  /// The nodes from the start to the goal
+  pub node_list: Vec<N>,
+}
+
+/// Removes nodes and steps from the graph of a cartographer.
+/// Used to search for the deviations from the shorter paths.
+/// There is no line of sight so that every step is an adjacent step.
+pub struct RestrictedCartographer<'a, N, C: PathCost = f64> {
+  pub blocked_node_set: HashSet<N>,
+  /// The steps from a node to an adjacent node which cannot be taken
+  pub blocked_step_set: HashSet<(N, N)>,
+  pub cartographer: &'a dyn Cartographer<N, C>,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<C: PathCost> KShortestPathfinder<C> {
+  /// Returns up to path_count paths in ascending order of cost.
+  /// The ties are returned in the order in which they were found.
+  /// A strict max_overlap_ratio_option can require searching for the
+  /// deviations from many skipped paths before enough diverse paths are found
+  /// so the max_candidate_count bounds the number of searches.
+  pub fn find_paths<N: Copy + Eq + Hash>(
+    &self,
+    start_node: N,
+    cartographer: &dyn Cartographer<N, C>,
+  ) -> Vec<RankedPath<N, C>> {
+    let mut ranked_paths: Vec<RankedPath<N, C>> = Vec::new();
+    if self.path_count == 0 {
+      return ranked_paths;
+    }
+    let mut astar = AStar::<N, C>::default();
+    let unrestricted_cartographer = RestrictedCartographer {
+      blocked_node_set: HashSet::new(),
+      blocked_step_set: HashSet::new(),
+      cartographer,
+    };
+    let Some(first_ranked_path) = self.search_spur(
+      &mut astar,
+      &[start_node],
+      C::ZERO,
+      &unrestricted_cartographer,
+    ) else {
+      return ranked_paths;
+    };
+    let mut candidate_paths: Vec<RankedPath<N, C>> = vec![first_ranked_path];
+    let mut found_node_list_set: HashSet<Vec<N>> =
+      HashSet::from([candidate_paths[0].node_list.clone()]);
+    // The paths from which the deviations have been searched
+    let mut root_node_lists: Vec<Vec<N>> = Vec::new();
+    while ranked_paths.len() < self.path_count
+      && root_node_lists.len() < self.max_candidate_count
+    {
+      let Some(candidate_index) = candidate_paths
+        .iter()
+        .enumerate()
+        .min_by(|(_, path_0), (_, path_1)| path_0.cost.total_cmp(&path_1.cost))
+        .map(|(candidate_index, _)| candidate_index)
+      else {
+        break;
+      };
+      let candidate_path: RankedPath<N, C> =
+        candidate_paths.remove(candidate_index);
+      let node_list: Vec<N> = candidate_path.node_list.clone();
+      if self.is_diverse(&candidate_path.node_list, &ranked_paths) {
+        ranked_paths.push(candidate_path);
+      }
+      root_node_lists.push(node_list.clone());
+      let mut root_cost: C = C::ZERO;
+      for spur_index in 0..node_list.len().saturating_sub(1) {
+        let root: &[N] = &node_list[..=spur_index];
+        if spur_index > 0 {
+          root_cost =
+            root_cost.saturating_add(cartographer.get_cost_to_adjacent_node(
+              &node_list[spur_index - 1],
+              &node_list[spur_index],
+            ));
+        }
+        let restricted_cartographer = RestrictedCartographer {
+          blocked_node_set: root[..spur_index].iter().copied().collect(),
+          blocked_step_set: root_node_lists
+            .iter()
+            .filter(|root_node_list| {
+              root_node_list.len() > spur_index + 1
+                && root_node_list[..=spur_index] == *root
+            })
+            .map(|root_node_list| {
+              (root_node_list[spur_index], root_node_list[spur_index + 1])
+            })
+            .collect(),
+          cartographer,
+        };
+        let Some(spur_path) = self.search_spur(
+          &mut astar,
+          root,
+          root_cost,
+          &restricted_cartographer,
+        ) else {
+          continue;
+        };
+        if found_node_list_set.insert(spur_path.node_list.clone()) {
+          candidate_paths.push(spur_path);
+        }
+      }
+    }
+    ranked_paths
+  }
+
+  /// True if the fraction of the steps of the node list which are also steps
+  /// of any one of the ranked paths is not greater than the maximum
+  pub fn is_diverse<N: Copy + Eq + Hash>(
+    &self,
+    node_list: &[N],
+    ranked_paths: &[RankedPath<N, C>],
+  ) -> bool {
+    let Some(max_overlap_ratio) = self.max_overlap_ratio_option else {
+      return true;
+    };
+    let step_count: usize = node_list.len().saturating_sub(1);
+    if step_count == 0 {
+      return true;
+    }
+    ranked_paths.iter().all(|ranked_path| {
+      let step_set: HashSet<(N, N)> = ranked_path
+        .node_list
+        .windows(2)
+        .map(|step| (step[0], step[1]))
+        .collect();
+      let shared_count: usize = node_list
+        .windows(2)
+        .filter(|step| step_set.contains(&(step[0], step[1])))
+        .count();
+      shared_count as f64 / step_count as f64 <= max_overlap_ratio
+    })
+  }
+
+  /// Searches from the last node of the root and prepends the root
+  fn search_spur<N: Copy + Eq + Hash>(
+    &self,
+    astar: &mut AStar<N, C>,
+    root: &[N],
+    root_cost: C,
+    restricted_cartographer: &RestrictedCartographer<N, C>,
+  ) -> Option<RankedPath<N, C>> {
+    let spur_node: N = *root.last()?;
+    let SearchOutcome::GoalFound {
+      cost,
+      path,
+    } = astar.search(spur_node, restricted_cartographer, &self.search_budget)
+    else {
+      return None;
+    };
+    let mut node_list: Vec<N> = root.to_vec();
+    node_list.extend(path);
+    Some(RankedPath {
+      cost: root_cost.saturating_add(cost),
+      node_list,
+    })
+  }
+}
+
+impl<N: Copy + Eq + Hash, C: PathCost> RestrictedCartographer<'_, N, C> {
+  /// True if the step to the adjacent node has not been removed
+  pub fn is_step_allowed(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    !self.blocked_node_set.contains(to_node)
+      && !self.blocked_step_set.contains(&(*from_node, *to_node))
+  }
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl<N: Copy + Eq + Hash, C: PathCost> Cartographer<N, C>
+  for RestrictedCartographer<'_, N, C>
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_between(from_node, to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_to_goal(node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self
+      .cartographer
+      .get_adjacent_nodes(node)
+      .into_iter()
+      .filter(|adjacent_node| self.is_step_allowed(node, adjacent_node))
+      .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self
+      .cartographer
+      .get_predecessor_nodes(node)
+      .into_iter()
+      .filter(|predecessor_node| {
+        self.is_step_allowed(predecessor_node, node)
+          && !self.blocked_node_set.contains(predecessor_node)
+      })
+      .collect()
+  }
+
+  fn get_successor_nodes(
+    &self,
+    node: &N,
+    parent_node_option: Option<&N>,
+  ) -> Vec<N> {
+    self
+      .cartographer
+      .get_successor_nodes(node, parent_node_option)
+      .into_iter()
+      .filter(|successor_node| self.is_step_allowed(node, successor_node))
+      .collect()
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.cartographer.is_goal_node(node)
+  }
+}
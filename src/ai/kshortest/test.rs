@@ -0,0 +1,298 @@
+// =============================================================================
+//! - Unit tests for the k shortest paths
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+/// The directed graph of the example often used to illustrate Yen's algorithm
+#[cfg(test)]
+const TEST_EDGES: [(char, char, u32); 9] = [
+  ('C', 'D', 3),
+  ('C', 'E', 2),
+  ('D', 'F', 4),
+  ('E', 'D', 1),
+  ('E', 'F', 2),
+  ('E', 'G', 3),
+  ('F', 'G', 2),
+  ('F', 'H', 1),
+  ('G', 'H', 2),
+];
+
+/// Multiplies the edge costs so that the path costs can exceed the maximum
+#[cfg(test)]
+struct ScaledCartographer {
+  cartographer: TestCartographer,
+  cost_scale: u32,
+}
+
+#[cfg(test)]
+struct TestCartographer {
+  goal_node: char,
+}
+
+#[cfg(test)]
+impl Cartographer<char, u32> for ScaledCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &char,
+  ) -> u32 {
+    self.cartographer.estimate_cost_to_goal(node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &char,
+  ) -> Vec<char> {
+    self.cartographer.get_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &char,
+    to_node: &char,
+  ) -> u32 {
+    self.cost_scale
+      * self
+        .cartographer
+        .get_cost_to_adjacent_node(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &char,
+  ) -> bool {
+    self.cartographer.is_goal_node(node)
+  }
+}
+
+#[cfg(test)]
+impl Cartographer<char, u32> for TestCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    _node: &char,
+  ) -> u32 {
+    0
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &char,
+  ) -> Vec<char> {
+    TEST_EDGES
+      .iter()
+      .filter(|edge| edge.0 == *node)
+      .map(|edge| edge.1)
+      .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &char,
+    to_node: &char,
+  ) -> u32 {
+    TEST_EDGES
+      .iter()
+      .find(|edge| edge.0 == *from_node && edge.1 == *to_node)
+      .unwrap()
+      .2
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &char,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
+
+#[cfg(test)]
+fn make_ranked_path(
+  cost: u32,
+  nodes: &str,
+) -> RankedPath<char, u32> {
+  RankedPath {
+    cost,
+    node_list: nodes.chars().collect(),
+  }
+}
+
+#[cfg(test)]
+fn make_test_subject(
+  max_overlap_ratio_option: Option<f64>,
+  path_count: usize,
+) -> KShortestPathfinder<u32> {
+  KShortestPathfinder {
+    max_candidate_count: usize::MAX,
+    max_overlap_ratio_option,
+    path_count,
+    search_budget: SearchBudget::default(),
+  }
+}
+
+#[test]
+fn test_find_paths() {
+  let cartographer = TestCartographer {
+    goal_node: 'H',
+  };
+  assert_eq!(
+    make_test_subject(None, 3).find_paths('C', &cartographer),
+    vec![
+      make_ranked_path(5, "CEFH"),
+      make_ranked_path(7, "CEGH"),
+      make_ranked_path(8, "CDFH"),
+    ]
+  );
+  // Every loopless path in ascending order of cost
+  let ranked_paths: Vec<RankedPath<char, u32>> =
+    make_test_subject(None, 10).find_paths('C', &cartographer);
+  assert_eq!(
+    ranked_paths
+      .iter()
+      .map(|ranked_path| ranked_path.cost)
+      .collect::<Vec<u32>>(),
+    vec![
+      5, 7, 8, 8, 8, 11, 11
+    ]
+  );
+  assert_eq!(
+    make_test_subject(None, 0).find_paths('C', &cartographer),
+    []
+  );
+  assert_eq!(
+    make_test_subject(None, 3).find_paths('H', &cartographer),
+    vec![make_ranked_path(0, "H")]
+  );
+  let cartographer = TestCartographer {
+    goal_node: 'C',
+  };
+  assert_eq!(
+    make_test_subject(None, 3).find_paths('H', &cartographer),
+    []
+  );
+}
+
+#[test]
+fn test_find_paths_diverse() {
+  let cartographer = TestCartographer {
+    goal_node: 'H',
+  };
+  // Sharing one of three steps with each of the shorter paths is allowed
+  assert_eq!(
+    make_test_subject(Some(0.5), 3).find_paths('C', &cartographer),
+    make_test_subject(None, 3).find_paths('C', &cartographer)
+  );
+  // Only the paths which share no steps
+  assert_eq!(
+    make_test_subject(Some(0.0), 3).find_paths('C', &cartographer),
+    vec![
+      make_ranked_path(5, "CEFH"),
+      make_ranked_path(11, "CDFGH"),
+    ]
+  );
+}
+
+#[test]
+fn test_find_paths_max_candidate_count() {
+  let cartographer = TestCartographer {
+    goal_node: 'H',
+  };
+  let test_subject = KShortestPathfinder {
+    max_candidate_count: 2,
+    ..make_test_subject(None, 10)
+  };
+  assert_eq!(
+    test_subject.find_paths('C', &cartographer),
+    vec![
+      make_ranked_path(5, "CEFH"),
+      make_ranked_path(7, "CEGH"),
+    ]
+  );
+  // The diverse path is not found before the limit is reached
+  let test_subject = KShortestPathfinder {
+    max_candidate_count: 2,
+    ..make_test_subject(Some(0.0), 3)
+  };
+  assert_eq!(
+    test_subject.find_paths('C', &cartographer),
+    vec![make_ranked_path(5, "CEFH")]
+  );
+  let test_subject = KShortestPathfinder {
+    max_candidate_count: 0,
+    ..make_test_subject(None, 3)
+  };
+  assert_eq!(test_subject.find_paths('C', &cartographer), []);
+}
+
+#[test]
+fn test_find_paths_saturating() {
+  let cost_scale: u32 = u32::MAX / 7;
+  let cartographer = ScaledCartographer {
+    cartographer: TestCartographer {
+      goal_node: 'H',
+    },
+    cost_scale,
+  };
+  let ranked_paths: Vec<RankedPath<char, u32>> =
+    make_test_subject(None, 4).find_paths('C', &cartographer);
+  assert_eq!(
+    ranked_paths[..2],
+    [
+      make_ranked_path(5 * cost_scale, "CEFH"),
+      make_ranked_path(7 * cost_scale, "CEGH"),
+    ]
+  );
+  // The costs of the longer paths saturate instead of overflowing
+  assert_eq!(
+    ranked_paths[2..]
+      .iter()
+      .map(|ranked_path| ranked_path.cost)
+      .collect::<Vec<u32>>(),
+    vec![
+      u32::MAX,
+      u32::MAX
+    ]
+  );
+}
+
+#[test]
+fn test_is_diverse() {
+  let ranked_paths = vec![make_ranked_path(5, "CEFH")];
+  let node_list: Vec<char> = "CEGH".chars().collect();
+  assert!(make_test_subject(None, 3).is_diverse(&node_list, &ranked_paths));
+  assert!(
+    make_test_subject(Some(0.5), 3).is_diverse(&node_list, &ranked_paths)
+  );
+  assert!(
+    !make_test_subject(Some(0.25), 3).is_diverse(&node_list, &ranked_paths)
+  );
+  assert!(make_test_subject(Some(0.0), 3).is_diverse(&['H'], &ranked_paths));
+}
+
+#[test]
+fn test_restricted_cartographer() {
+  let cartographer = TestCartographer {
+    goal_node: 'H',
+  };
+  let test_subject = RestrictedCartographer {
+    blocked_node_set: HashSet::from(['D']),
+    blocked_step_set: HashSet::from([('E', 'F')]),
+    cartographer: &cartographer,
+  };
+  assert_eq!(test_subject.get_adjacent_nodes(&'E'), vec!['G']);
+  assert_eq!(test_subject.get_successor_nodes(&'C', None), vec!['E']);
+  assert_eq!(test_subject.get_predecessor_nodes(&'D'), []);
+  assert!(test_subject.is_step_allowed(&'F', &'H'));
+  assert!(!test_subject.is_step_allowed(&'C', &'D'));
+  assert_eq!(test_subject.get_cost_to_adjacent_node(&'E', &'G'), 3);
+  assert!(test_subject.is_goal_node(&'H'));
+}
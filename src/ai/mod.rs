@@ -2,10 +2,10 @@
 //! - Artificial Intelligence (AI)
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-10-21
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the Java package com.croftsoft.core.ai
@@ -17,3 +17,12 @@
 // =============================================================================
 
 pub mod astar;
+pub mod cbs;
+pub mod flowfield;
+pub mod hpa;
+pub mod kshortest;
+pub mod navmesh;
+pub mod pathcache;
+pub mod smoothing;
+pub mod spacetime;
+pub mod tilemap;
@@ -0,0 +1,468 @@
+// =============================================================================
+//! - Navigation mesh of convex polygons which share edges
+//! - Searches the polygon corridor with A* through the portal midpoints
+//! - Straightens the corridor into waypoints with the funnel algorithm
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - The funnel algorithm is based on "Simple Stupid Funnel Algorithm"
+//!   - By Mikko Mononen, 2010
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{AStar, SearchBudget, SearchOutcome};
+use crate::ai::astar::traits::Cartographer;
+use crate::math::geom::point_2dd::Point2DD;
+use std::collections::HashMap;
+
+// Constants -------------------------------------------------------------------
+
+/// Distance within which a point on the boundary of a polygon is considered
+/// inside of the polygon
+pub const NAV_MESH_TOLERANCE: f64 = 1e-9;
+
+// Structures ------------------------------------------------------------------
+
+/// Convex polygons which are adjacent where they share an edge
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavMesh {
+  /// The vertex indices of each polygon in counterclockwise order
+  pub polygon_list: Vec<Vec<usize>>,
+  /// The portals out of each polygon
+  pub portal_list_list: Vec<Vec<NavMeshPortal>>,
+  pub vertex_list: Vec<Point2DD>,
+}
+
+/// Searches the polygons from portal midpoint to portal midpoint
+pub struct NavMeshCartographer<'a> {
+  pub goal_point: Point2DD,
+  pub goal_polygon_index: usize,
+  pub nav_mesh: &'a NavMesh,
+}
+
+/// A polygon entered at a point.
+/// The point is a portal midpoint or the start point.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NavMeshNode {
+  pub point: Point2DD,
+  pub polygon_index: usize,
+}
+
+/// A problem with a line of the navigation mesh text format
+#[derive(Debug, PartialEq)]
+pub enum NavMeshParseError {
+  /// A coordinate or index which could not be parsed
+  InvalidNumber {
+    line_number: usize,
+  },
+  /// A polygon with fewer than three vertices
+  TooFewVertices {
+    line_number: usize,
+  },
+  /// A line which does not start with a known record type
+  UnknownRecord {
+    line_number: usize,
+  },
+  /// A polygon vertex index without a matching vertex
+  VertexIndexOutOfRange {
+    line_number: usize,
+    vertex_index: usize,
+  },
+  /// A vertex with other than two coordinates
+  WrongCoordinateCount {
+    line_number: usize,
+  },
+}
+
+/// A shared edge seen from the polygon which it leads out of
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NavMeshPortal {
+  /// The end on the left when facing into the next polygon
+  pub left_point: Point2DD,
+  pub polygon_index: usize,
+  /// The end on the right when facing into the next polygon
+  pub right_point: Point2DD,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl NavMesh {
+  /// The polygons from the polygon containing the start to the polygon
+  /// containing the goal
+  pub fn find_corridor(
+    &self,
+    start_point: &Point2DD,
+    goal_point: &Point2DD,
+  ) -> Option<Vec<usize>> {
+    let start_polygon_index: usize = self.find_polygon_index(start_point)?;
+    let goal_polygon_index: usize = self.find_polygon_index(goal_point)?;
+    let nav_mesh_cartographer = NavMeshCartographer {
+      goal_point: *goal_point,
+      goal_polygon_index,
+      nav_mesh: self,
+    };
+    let mut astar = AStar::<NavMeshNode>::default();
+    let SearchOutcome::GoalFound {
+      path,
+      ..
+    } = astar.search(
+      NavMeshNode {
+        point: *start_point,
+        polygon_index: start_polygon_index,
+      },
+      &nav_mesh_cartographer,
+      &SearchBudget::default(),
+    )
+    else {
+      return None;
+    };
+    let mut corridor: Vec<usize> = vec![start_polygon_index];
+    corridor.extend(path.iter().map(|node| node.polygon_index));
+    Some(corridor)
+  }
+
+  /// The waypoints from the start to the goal, both included
+  pub fn find_path(
+    &self,
+    start_point: &Point2DD,
+    goal_point: &Point2DD,
+  ) -> Option<Vec<Point2DD>> {
+    let corridor: Vec<usize> = self.find_corridor(start_point, goal_point)?;
+    let portal_list: Vec<NavMeshPortal> = corridor
+      .iter()
+      .zip(corridor.iter().skip(1))
+      .filter_map(|(from_polygon_index, to_polygon_index)| {
+        self.get_portal(*from_polygon_index, *to_polygon_index)
+      })
+      .collect();
+    Some(Self::pull_funnel(start_point, goal_point, &portal_list))
+  }
+
+  /// The first polygon which contains the point, including its boundary
+  pub fn find_polygon_index(
+    &self,
+    point: &Point2DD,
+  ) -> Option<usize> {
+    self.polygon_list.iter().position(|polygon| {
+      polygon.iter().enumerate().all(|(index, vertex_index)| {
+        let vertex: &Point2DD = &self.vertex_list[*vertex_index];
+        let next_vertex: &Point2DD =
+          &self.vertex_list[polygon[(index + 1) % polygon.len()]];
+        get_cross_product(vertex, next_vertex, point)
+          >= -NAV_MESH_TOLERANCE * vertex.distance_to(next_vertex)
+      })
+    })
+  }
+
+  /// The portal out of the polygon into the adjacent polygon
+  pub fn get_portal(
+    &self,
+    from_polygon_index: usize,
+    to_polygon_index: usize,
+  ) -> Option<NavMeshPortal> {
+    self.portal_list_list[from_polygon_index]
+      .iter()
+      .find(|portal| portal.polygon_index == to_polygon_index)
+      .copied()
+  }
+
+  /// Connects the polygons which share an edge.
+  /// Polygons with clockwise vertices are reversed.
+  pub fn new(
+    vertex_list: Vec<Point2DD>,
+    polygon_list: Vec<Vec<usize>>,
+  ) -> Self {
+    let polygon_list: Vec<Vec<usize>> = polygon_list
+      .into_iter()
+      .map(|mut polygon| {
+        let doubled_area: f64 = polygon
+          .iter()
+          .zip(polygon.iter().cycle().skip(1))
+          .map(|(vertex_index, next_vertex_index)| {
+            let vertex: &Point2DD = &vertex_list[*vertex_index];
+            let next_vertex: &Point2DD = &vertex_list[*next_vertex_index];
+            vertex.x * next_vertex.y - next_vertex.x * vertex.y
+          })
+          .sum();
+        if doubled_area < 0.0 {
+          polygon.reverse();
+        }
+        polygon
+      })
+      .collect();
+    let mut portal_list_list: Vec<Vec<NavMeshPortal>> =
+      vec![Vec::new(); polygon_list.len()];
+    // The polygon on the left of each directed edge
+    let mut edge_to_polygon_index_map: HashMap<(usize, usize), usize> =
+      HashMap::new();
+    for (polygon_index, polygon) in polygon_list.iter().enumerate() {
+      for (index, vertex_index) in polygon.iter().enumerate() {
+        let next_vertex_index: usize = polygon[(index + 1) % polygon.len()];
+        edge_to_polygon_index_map
+          .insert((*vertex_index, next_vertex_index), polygon_index);
+      }
+    }
+    for ((vertex_index, next_vertex_index), polygon_index) in
+      &edge_to_polygon_index_map
+    {
+      // The adjacent polygon has the same edge in the opposite direction
+      let Some(adjacent_polygon_index) =
+        edge_to_polygon_index_map.get(&(*next_vertex_index, *vertex_index))
+      else {
+        continue;
+      };
+      portal_list_list[*polygon_index].push(NavMeshPortal {
+        left_point: vertex_list[*next_vertex_index],
+        polygon_index: *adjacent_polygon_index,
+        right_point: vertex_list[*vertex_index],
+      });
+    }
+    // Sorted so that the searches do not depend on the hash order
+    for portal_list in &mut portal_list_list {
+      portal_list.sort_by_key(|portal| portal.polygon_index);
+    }
+    NavMesh {
+      polygon_list,
+      portal_list_list,
+      vertex_list,
+    }
+  }
+
+  /// Parses the navigation mesh text format.
+  /// Each line is a vertex "v x y", a polygon "p i j k ..." of zero-based
+  /// vertex indices in either winding order, a comment starting with "#", or
+  /// blank.
+  pub fn parse(text: &str) -> Result<Self, NavMeshParseError> {
+    let mut polygon_list: Vec<Vec<usize>> = Vec::new();
+    let mut vertex_list: Vec<Point2DD> = Vec::new();
+    // The line number of each polygon for reporting the vertex index errors
+    let mut polygon_line_number_list: Vec<usize> = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+      let line_number: usize = line_index + 1;
+      let mut token_iterator = line.split_whitespace();
+      match token_iterator.next() {
+        None => {},
+        Some(token) if token.starts_with('#') => {},
+        Some("p") => {
+          let polygon: Vec<usize> = token_iterator
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| NavMeshParseError::InvalidNumber {
+              line_number,
+            })?;
+          if polygon.len() < 3 {
+            return Err(NavMeshParseError::TooFewVertices {
+              line_number,
+            });
+          }
+          polygon_list.push(polygon);
+          polygon_line_number_list.push(line_number);
+        },
+        Some("v") => {
+          let coordinate_list: Vec<f64> = token_iterator
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| NavMeshParseError::InvalidNumber {
+              line_number,
+            })?;
+          let [
+            x,
+            y,
+          ] = coordinate_list[..]
+          else {
+            return Err(NavMeshParseError::WrongCoordinateCount {
+              line_number,
+            });
+          };
+          vertex_list.push(Point2DD::new(x, y));
+        },
+        Some(_) => {
+          return Err(NavMeshParseError::UnknownRecord {
+            line_number,
+          });
+        },
+      }
+    }
+    for (polygon, line_number) in
+      polygon_list.iter().zip(polygon_line_number_list)
+    {
+      if let Some(vertex_index) = polygon
+        .iter()
+        .find(|vertex_index| **vertex_index >= vertex_list.len())
+      {
+        return Err(NavMeshParseError::VertexIndexOutOfRange {
+          line_number,
+          vertex_index: *vertex_index,
+        });
+      }
+    }
+    Ok(Self::new(vertex_list, polygon_list))
+  }
+
+  /// The simple stupid funnel algorithm.
+  /// Pulls a string from the start to the goal through the portals so that
+  /// the waypoints are the portal ends where the path turns.
+  /// A corner shared by consecutive portals becomes a single waypoint.
+  pub fn pull_funnel(
+    start_point: &Point2DD,
+    goal_point: &Point2DD,
+    portal_list: &[NavMeshPortal],
+  ) -> Vec<Point2DD> {
+    // The start and goal are portals of zero width
+    let mut point_pair_list: Vec<(Point2DD, Point2DD)> =
+      vec![(*start_point, *start_point)];
+    point_pair_list.extend(
+      portal_list
+        .iter()
+        .map(|portal| (portal.left_point, portal.right_point)),
+    );
+    point_pair_list.push((*goal_point, *goal_point));
+    let mut waypoint_list: Vec<Point2DD> = vec![*start_point];
+    let mut apex_point: Point2DD = *start_point;
+    let mut left_point: Point2DD = *start_point;
+    let mut right_point: Point2DD = *start_point;
+    let mut left_index: usize = 0;
+    let mut right_index: usize = 0;
+    let mut index: usize = 1;
+    while index < point_pair_list.len() {
+      let (next_left_point, next_right_point) = point_pair_list[index];
+      // Narrows the funnel from the right unless it crosses over the left
+      if get_cross_product(&apex_point, &right_point, &next_right_point) >= 0.0
+      {
+        if apex_point == right_point
+          || get_cross_product(&apex_point, &left_point, &next_right_point)
+            < 0.0
+        {
+          right_point = next_right_point;
+          right_index = index;
+        } else {
+          // The left side becomes the new apex
+          if waypoint_list.last() != Some(&left_point) {
+            waypoint_list.push(left_point);
+          }
+          apex_point = left_point;
+          let apex_index: usize = left_index;
+          left_point = apex_point;
+          left_index = apex_index;
+          right_point = apex_point;
+          right_index = apex_index;
+          index = apex_index + 1;
+          continue;
+        }
+      }
+      // Narrows the funnel from the left unless it crosses over the right
+      if get_cross_product(&apex_point, &left_point, &next_left_point) <= 0.0 {
+        if apex_point == left_point
+          || get_cross_product(&apex_point, &right_point, &next_left_point)
+            > 0.0
+        {
+          left_point = next_left_point;
+          left_index = index;
+        } else {
+          // The right side becomes the new apex
+          if waypoint_list.last() != Some(&right_point) {
+            waypoint_list.push(right_point);
+          }
+          apex_point = right_point;
+          let apex_index: usize = right_index;
+          left_point = apex_point;
+          left_index = apex_index;
+          right_point = apex_point;
+          right_index = apex_index;
+          index = apex_index + 1;
+          continue;
+        }
+      }
+      index += 1;
+    }
+    if waypoint_list.last() != Some(goal_point) {
+      waypoint_list.push(*goal_point);
+    }
+    waypoint_list
+  }
+}
+
+// Functions -------------------------------------------------------------------
+
+/// Twice the signed area of the triangle.
+/// Positive if the third point is to the left of the line from the first
+/// point through the second point.
+fn get_cross_product(
+  point_a: &Point2DD,
+  point_b: &Point2DD,
+  point_c: &Point2DD,
+) -> f64 {
+  (point_b.x - point_a.x) * (point_c.y - point_a.y)
+    - (point_b.y - point_a.y) * (point_c.x - point_a.x)
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl Cartographer<NavMeshNode> for NavMeshCartographer<'_> {
+  fn estimate_cost_between(
+    &self,
+    from_node: &NavMeshNode,
+    to_node: &NavMeshNode,
+  ) -> f64 {
+    from_node.point.distance_to(&to_node.point)
+  }
+
+  /// Zero in the goal polygon since the last leg is already in the cost
+  fn estimate_cost_to_goal(
+    &self,
+    node: &NavMeshNode,
+  ) -> f64 {
+    if node.polygon_index == self.goal_polygon_index {
+      return 0.0;
+    }
+    node.point.distance_to(&self.goal_point)
+  }
+
+  /// The adjacent polygons entered at the portal midpoints
+  fn get_adjacent_nodes(
+    &self,
+    node: &NavMeshNode,
+  ) -> Vec<NavMeshNode> {
+    self.nav_mesh.portal_list_list[node.polygon_index]
+      .iter()
+      .map(|portal| NavMeshNode {
+        point: Point2DD::new(
+          (portal.left_point.x + portal.right_point.x) / 2.0,
+          (portal.left_point.y + portal.right_point.y) / 2.0,
+        ),
+        polygon_index: portal.polygon_index,
+      })
+      .collect()
+  }
+
+  /// The distance between the points plus the distance to the goal point
+  /// when entering the goal polygon
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &NavMeshNode,
+    to_node: &NavMeshNode,
+  ) -> f64 {
+    let cost: f64 = from_node.point.distance_to(&to_node.point);
+    if to_node.polygon_index == self.goal_polygon_index {
+      return cost + to_node.point.distance_to(&self.goal_point);
+    }
+    cost
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &NavMeshNode,
+  ) -> bool {
+    node.polygon_index == self.goal_polygon_index
+  }
+}
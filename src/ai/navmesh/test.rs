@@ -0,0 +1,253 @@
+// =============================================================================
+//! - Unit tests for the navigation mesh
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+/// Three squares in an L shape.
+/// The last polygon is clockwise.
+#[cfg(test)]
+const TEST_NAV_MESH_TEXT: &str = "
+# L-shaped level
+v 0 0
+v 2 0
+v 4 0
+v 0 2
+v 2 2
+v 4 2
+v 2 4
+v 4 4
+
+p 0 1 4 3
+p 1 2 5 4
+p 6 7 5 4
+";
+
+#[cfg(test)]
+fn make_test_nav_mesh() -> NavMesh {
+  NavMesh::parse(TEST_NAV_MESH_TEXT).unwrap()
+}
+
+#[test]
+fn test_find_corridor() {
+  let test_subject = make_test_nav_mesh();
+  assert_eq!(
+    test_subject
+      .find_corridor(&Point2DD::new(1.0, 1.0), &Point2DD::new(3.0, 3.5)),
+    Some(vec![
+      0, 1, 2
+    ])
+  );
+  assert_eq!(
+    test_subject
+      .find_corridor(&Point2DD::new(1.0, 1.0), &Point2DD::new(1.0, 3.0)),
+    None
+  );
+}
+
+#[test]
+fn test_find_path() {
+  let test_subject = make_test_nav_mesh();
+  // Turns at the inside corner of the L
+  assert_eq!(
+    test_subject.find_path(&Point2DD::new(1.0, 1.0), &Point2DD::new(3.0, 3.5)),
+    Some(vec![
+      Point2DD::new(1.0, 1.0),
+      Point2DD::new(2.0, 2.0),
+      Point2DD::new(3.0, 3.5),
+    ])
+  );
+  // Turns at the same corner in the other direction
+  assert_eq!(
+    test_subject.find_path(&Point2DD::new(3.0, 3.5), &Point2DD::new(1.0, 1.0)),
+    Some(vec![
+      Point2DD::new(3.0, 3.5),
+      Point2DD::new(2.0, 2.0),
+      Point2DD::new(1.0, 1.0),
+    ])
+  );
+  // Straight across a portal
+  assert_eq!(
+    test_subject.find_path(&Point2DD::new(1.0, 0.5), &Point2DD::new(3.0, 1.5)),
+    Some(vec![
+      Point2DD::new(1.0, 0.5),
+      Point2DD::new(3.0, 1.5),
+    ])
+  );
+  // Within a single polygon
+  assert_eq!(
+    test_subject.find_path(&Point2DD::new(3.0, 3.0), &Point2DD::new(2.5, 2.5)),
+    Some(vec![
+      Point2DD::new(3.0, 3.0),
+      Point2DD::new(2.5, 2.5),
+    ])
+  );
+}
+
+#[test]
+fn test_find_polygon_index() {
+  let test_subject = make_test_nav_mesh();
+  assert_eq!(
+    test_subject.find_polygon_index(&Point2DD::new(1.0, 1.0)),
+    Some(0)
+  );
+  assert_eq!(
+    test_subject.find_polygon_index(&Point2DD::new(3.0, 3.0)),
+    Some(2)
+  );
+  assert_eq!(
+    test_subject.find_polygon_index(&Point2DD::new(1.0, 3.0)),
+    None
+  );
+  // On a shared edge
+  assert_eq!(
+    test_subject.find_polygon_index(&Point2DD::new(2.0, 1.0)),
+    Some(0)
+  );
+}
+
+#[test]
+fn test_get_adjacent_nodes() {
+  let nav_mesh = make_test_nav_mesh();
+  let test_subject = NavMeshCartographer {
+    goal_point: Point2DD::new(3.0, 3.5),
+    goal_polygon_index: 2,
+    nav_mesh: &nav_mesh,
+  };
+  let node = NavMeshNode {
+    point: Point2DD::new(3.0, 1.0),
+    polygon_index: 1,
+  };
+  let adjacent_list = test_subject.get_adjacent_nodes(&node);
+  assert_eq!(
+    adjacent_list,
+    vec![
+      NavMeshNode {
+        point: Point2DD::new(2.0, 1.0),
+        polygon_index: 0,
+      },
+      NavMeshNode {
+        point: Point2DD::new(3.0, 2.0),
+        polygon_index: 2,
+      },
+    ]
+  );
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&node, &adjacent_list[0]),
+    1.0
+  );
+  // Entering the goal polygon includes the last leg to the goal point
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&node, &adjacent_list[1]),
+    2.5
+  );
+  assert_eq!(test_subject.estimate_cost_to_goal(&adjacent_list[1]), 0.0);
+  assert!(test_subject.is_goal_node(&adjacent_list[1]));
+}
+
+#[test]
+fn test_parse() {
+  let test_subject = make_test_nav_mesh();
+  assert_eq!(test_subject.vertex_list.len(), 8);
+  // The clockwise polygon is reversed
+  assert_eq!(
+    test_subject.polygon_list[2],
+    vec![
+      4, 5, 7, 6
+    ]
+  );
+  assert_eq!(
+    test_subject
+      .portal_list_list
+      .iter()
+      .map(Vec::len)
+      .collect::<Vec<usize>>(),
+    vec![
+      1, 2, 1
+    ]
+  );
+  assert_eq!(
+    test_subject.get_portal(0, 1),
+    Some(NavMeshPortal {
+      left_point: Point2DD::new(2.0, 2.0),
+      polygon_index: 1,
+      right_point: Point2DD::new(2.0, 0.0),
+    })
+  );
+  assert_eq!(test_subject.get_portal(0, 2), None);
+}
+
+#[test]
+fn test_parse_error() {
+  assert_eq!(
+    NavMesh::parse("v 0 0\nv 1 x"),
+    Err(NavMeshParseError::InvalidNumber {
+      line_number: 2,
+    })
+  );
+  assert_eq!(
+    NavMesh::parse("v 0 0\nv 1 0\np 0 1"),
+    Err(NavMeshParseError::TooFewVertices {
+      line_number: 3,
+    })
+  );
+  assert_eq!(
+    NavMesh::parse("f 0 1 2"),
+    Err(NavMeshParseError::UnknownRecord {
+      line_number: 1,
+    })
+  );
+  assert_eq!(
+    NavMesh::parse("p 0 1 2\nv 0 0\nv 1 0"),
+    Err(NavMeshParseError::VertexIndexOutOfRange {
+      line_number: 1,
+      vertex_index: 2,
+    })
+  );
+  assert_eq!(
+    NavMesh::parse("v 0 0 0"),
+    Err(NavMeshParseError::WrongCoordinateCount {
+      line_number: 1,
+    })
+  );
+}
+
+#[test]
+fn test_pull_funnel() {
+  let nav_mesh = make_test_nav_mesh();
+  let portal_list = [
+    nav_mesh.get_portal(0, 1).unwrap(),
+    nav_mesh.get_portal(1, 2).unwrap(),
+  ];
+  // Close to the walls of the corridor
+  assert_eq!(
+    NavMesh::pull_funnel(
+      &Point2DD::new(0.5, 1.9),
+      &Point2DD::new(2.1, 3.9),
+      &portal_list
+    ),
+    vec![
+      Point2DD::new(0.5, 1.9),
+      Point2DD::new(2.0, 2.0),
+      Point2DD::new(2.1, 3.9),
+    ]
+  );
+  assert_eq!(
+    NavMesh::pull_funnel(
+      &Point2DD::new(1.0, 1.0),
+      &Point2DD::new(1.0, 1.0),
+      &[]
+    ),
+    vec![Point2DD::new(1.0, 1.0)]
+  );
+}
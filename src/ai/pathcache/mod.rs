@@ -0,0 +1,321 @@
+// =============================================================================
+//! - Caching for repeated path queries
+//! - A cartographer adapter which memoizes the adjacent nodes and the costs
+//! - A bounded least recently used (LRU) cache of the search outcomes by the
+//!   start and goal nodes
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{AStar, SearchBudget, SearchOutcome};
+use crate::ai::astar::traits::{Cartographer, PathCost};
+use core::cell::{Cell, RefCell};
+use core::hash::Hash;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+// Structures ------------------------------------------------------------------
+
+/// Memoizes the adjacent nodes and the costs to the adjacent nodes of the
+/// wrapped cartographer.
+/// The goal, the estimates, the goal free adjacent nodes, the predecessor
+/// nodes, and the line of sight are forwarded without caching.
+/// The adjacent nodes can include a jump to the goal so the wrapped
+/// cartographer is only mutable through change_goal, which forgets the cached
+/// adjacent nodes, and change_world, which forgets both caches.
+/// The successor nodes are the cached adjacent nodes so do not wrap a
+/// cartographer which prunes its successor nodes using the parent node.
+pub struct CachingCartographer<T, N, C = f64> {
+  pub adjacent_nodes_cache: RefCell<HashMap<N, Vec<N>>>,
+  cartographer: T,
+  pub cost_cache: RefCell<HashMap<(N, N), C>>,
+  /// The calls answered from the caches
+  pub hit_count: Cell<usize>,
+  /// The calls forwarded to the wrapped cartographer
+  pub miss_count: Cell<usize>,
+}
+
+/// A bounded least recently used (LRU) cache of the search outcomes by the
+/// start and goal nodes.
+/// Call clear when the world changes.
+pub struct PathCache<N, C = f64> {
+  /// The least recently used outcome is evicted when this is exceeded
+  pub capacity: usize,
+  pub hit_count: usize,
+  pub key_to_entry_map: HashMap<(N, N), PathCacheEntry<N, C>>,
+  pub miss_count: usize,
+  /// The keys by the sequence number of their most recent use
+  pub sequence_to_key_map: BTreeMap<u64, (N, N)>,
+  /// Incremented each time an outcome is inserted or retrieved
+  pub use_sequence: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathCacheEntry<N, C = f64> {
+  pub search_outcome: SearchOutcome<N, C>,
+  /// The sequence number of the most recent use
+  pub use_sequence: u64,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<T, N, C> CachingCartographer<T, N, C> {
+  /// Changes the goal of the wrapped cartographer and forgets the cached
+  /// adjacent nodes but not the costs
+  pub fn change_goal<F: FnOnce(&mut T)>(
+    &mut self,
+    change_goal_fn: F,
+  ) {
+    change_goal_fn(&mut self.cartographer);
+    self.adjacent_nodes_cache.get_mut().clear();
+  }
+
+  /// Changes the world of the wrapped cartographer and forgets the cached
+  /// adjacent nodes and costs
+  pub fn change_world<F: FnOnce(&mut T)>(
+    &mut self,
+    change_world_fn: F,
+  ) {
+    change_world_fn(&mut self.cartographer);
+    self.clear();
+  }
+
+  /// Forgets the cached adjacent nodes and costs but not the counts.
+  /// Call this when a world captured by the wrapped cartographer changes.
+  pub fn clear(&mut self) {
+    self.adjacent_nodes_cache.get_mut().clear();
+    self.cost_cache.get_mut().clear();
+  }
+
+  pub fn get_cartographer(&self) -> &T {
+    &self.cartographer
+  }
+
+  pub fn new(cartographer: T) -> Self {
+    Self {
+      adjacent_nodes_cache: RefCell::new(HashMap::new()),
+      cartographer,
+      cost_cache: RefCell::new(HashMap::new()),
+      hit_count: Cell::new(0),
+      miss_count: Cell::new(0),
+    }
+  }
+}
+
+impl<N: Copy + Eq + Hash, C: PathCost> PathCache<N, C> {
+  /// Forgets the cached outcomes but not the counts
+  pub fn clear(&mut self) {
+    self.key_to_entry_map.clear();
+    self.sequence_to_key_map.clear();
+  }
+
+  /// Returns the cached outcome and marks it as the most recently used
+  pub fn get(
+    &mut self,
+    start_node: N,
+    goal_node: N,
+  ) -> Option<&SearchOutcome<N, C>> {
+    let Some(path_cache_entry) =
+      self.key_to_entry_map.get_mut(&(start_node, goal_node))
+    else {
+      self.miss_count += 1;
+      return None;
+    };
+    self.hit_count += 1;
+    self.use_sequence += 1;
+    self
+      .sequence_to_key_map
+      .remove(&path_cache_entry.use_sequence);
+    self
+      .sequence_to_key_map
+      .insert(self.use_sequence, (start_node, goal_node));
+    path_cache_entry.use_sequence = self.use_sequence;
+    Some(&path_cache_entry.search_outcome)
+  }
+
+  /// Replaces any cached outcome and evicts the least recently used outcomes
+  /// which exceed the capacity
+  pub fn insert(
+    &mut self,
+    start_node: N,
+    goal_node: N,
+    search_outcome: SearchOutcome<N, C>,
+  ) {
+    self.remove(start_node, goal_node);
+    if self.capacity == 0 {
+      return;
+    }
+    while self.key_to_entry_map.len() >= self.capacity {
+      let Some((_, key)) = self.sequence_to_key_map.pop_first() else {
+        break;
+      };
+      self.key_to_entry_map.remove(&key);
+    }
+    self.use_sequence += 1;
+    self
+      .sequence_to_key_map
+      .insert(self.use_sequence, (start_node, goal_node));
+    self.key_to_entry_map.insert(
+      (start_node, goal_node),
+      PathCacheEntry {
+        search_outcome,
+        use_sequence: self.use_sequence,
+      },
+    );
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.key_to_entry_map.is_empty()
+  }
+
+  pub fn len(&self) -> usize {
+    self.key_to_entry_map.len()
+  }
+
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      hit_count: 0,
+      key_to_entry_map: HashMap::new(),
+      miss_count: 0,
+      sequence_to_key_map: BTreeMap::new(),
+      use_sequence: 0,
+    }
+  }
+
+  /// Forgets the cached outcome for the start and goal nodes if any
+  pub fn remove(
+    &mut self,
+    start_node: N,
+    goal_node: N,
+  ) -> Option<SearchOutcome<N, C>> {
+    let path_cache_entry: PathCacheEntry<N, C> =
+      self.key_to_entry_map.remove(&(start_node, goal_node))?;
+    self
+      .sequence_to_key_map
+      .remove(&path_cache_entry.use_sequence);
+    Some(path_cache_entry.search_outcome)
+  }
+
+  /// Returns the cached outcome or searches and caches the outcome.
+  /// The goal of the cartographer must be the goal node.
+  /// An outcome of an exhausted budget is returned but not cached.
+  pub fn search(
+    &mut self,
+    astar: &mut AStar<N, C>,
+    start_node: N,
+    goal_node: N,
+    cartographer: &dyn Cartographer<N, C>,
+    search_budget: &SearchBudget<C>,
+  ) -> SearchOutcome<N, C> {
+    if let Some(search_outcome) = self.get(start_node, goal_node) {
+      return search_outcome.clone();
+    }
+    let search_outcome: SearchOutcome<N, C> =
+      astar.search(start_node, cartographer, search_budget);
+    if !matches!(search_outcome, SearchOutcome::BudgetExhausted { .. }) {
+      self.insert(start_node, goal_node, search_outcome.clone());
+    }
+    search_outcome
+  }
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl<T, N, C> Cartographer<N, C> for CachingCartographer<T, N, C>
+where
+  T: Cartographer<N, C>,
+  N: Copy + Eq + Hash,
+  C: PathCost,
+{
+  fn estimate_cost_between(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_between(from_node, to_node)
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &N,
+  ) -> C {
+    self.cartographer.estimate_cost_to_goal(node)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    if let Some(adjacent_nodes) = self.adjacent_nodes_cache.borrow().get(node) {
+      self.hit_count.set(self.hit_count.get() + 1);
+      return adjacent_nodes.clone();
+    }
+    self.miss_count.set(self.miss_count.get() + 1);
+    let adjacent_nodes: Vec<N> = self.cartographer.get_adjacent_nodes(node);
+    self
+      .adjacent_nodes_cache
+      .borrow_mut()
+      .insert(*node, adjacent_nodes.clone());
+    adjacent_nodes
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> C {
+    if let Some(cost) = self.cost_cache.borrow().get(&(*from_node, *to_node)) {
+      self.hit_count.set(self.hit_count.get() + 1);
+      return *cost;
+    }
+    self.miss_count.set(self.miss_count.get() + 1);
+    let cost: C = self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node);
+    self
+      .cost_cache
+      .borrow_mut()
+      .insert((*from_node, *to_node), cost);
+    cost
+  }
+
+  fn get_goal_free_adjacent_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_goal_free_adjacent_nodes(node)
+  }
+
+  fn get_predecessor_nodes(
+    &self,
+    node: &N,
+  ) -> Vec<N> {
+    self.cartographer.get_predecessor_nodes(node)
+  }
+
+  fn has_line_of_sight(
+    &self,
+    from_node: &N,
+    to_node: &N,
+  ) -> bool {
+    self.cartographer.has_line_of_sight(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &N,
+  ) -> bool {
+    self.cartographer.is_goal_node(node)
+  }
+}
@@ -0,0 +1,236 @@
+// =============================================================================
+//! - Unit tests for the path caching
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+#[cfg(test)]
+use crate::ai::astar::constants::test::{
+  TEST_BEST_NODE, TEST_DISTANCE_TO_GOAL, TEST_GOAL_NODE, TEST_ORIGIN_NODE,
+  TEST_STEP_SIZE, TEST_SUBJECT_GRID_CARTOGRAPHER,
+};
+#[cfg(test)]
+use crate::ai::astar::structures::GridCartographer;
+#[cfg(test)]
+use crate::math::geom::point_2dd::Point2DD;
+#[cfg(test)]
+use std::collections::VecDeque;
+
+#[cfg(test)]
+fn make_search_outcome(cost: f64) -> SearchOutcome<Point2DD> {
+  SearchOutcome::GoalFound {
+    cost,
+    path: VecDeque::new(),
+  }
+}
+
+#[test]
+fn test_caching_cartographer() {
+  let mut test_subject =
+    CachingCartographer::new(TEST_SUBJECT_GRID_CARTOGRAPHER);
+  let adjacent_nodes: Vec<Point2DD> =
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_adjacent_nodes(&TEST_ORIGIN_NODE);
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    adjacent_nodes
+  );
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&TEST_ORIGIN_NODE),
+    adjacent_nodes
+  );
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &TEST_BEST_NODE),
+    TEST_STEP_SIZE
+  );
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&TEST_ORIGIN_NODE, &TEST_BEST_NODE),
+    TEST_STEP_SIZE
+  );
+  assert_eq!(test_subject.hit_count.get(), 2);
+  assert_eq!(test_subject.miss_count.get(), 2);
+  // The goal is forwarded without caching
+  assert!(test_subject.is_goal_node(&TEST_GOAL_NODE));
+  assert_eq!(
+    test_subject.estimate_cost_to_goal(&TEST_ORIGIN_NODE),
+    TEST_DISTANCE_TO_GOAL
+  );
+  test_subject.clear();
+  assert!(test_subject.adjacent_nodes_cache.borrow().is_empty());
+  assert!(test_subject.cost_cache.borrow().is_empty());
+  test_subject.get_adjacent_nodes(&TEST_ORIGIN_NODE);
+  assert_eq!(test_subject.miss_count.get(), 3);
+}
+
+#[test]
+fn test_caching_cartographer_change_goal() {
+  let mut test_subject =
+    CachingCartographer::new(TEST_SUBJECT_GRID_CARTOGRAPHER);
+  // Next to the goal the grid cartographer jumps to the goal
+  let node = Point2DD::new(3.5, 0.0);
+  assert_eq!(test_subject.get_adjacent_nodes(&node), vec![TEST_GOAL_NODE]);
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(&node, &TEST_GOAL_NODE),
+    0.5
+  );
+  // The grid steps are forwarded instead of the cached jump to the goal
+  assert_eq!(
+    test_subject.get_goal_free_adjacent_nodes(&node),
+    TEST_SUBJECT_GRID_CARTOGRAPHER.get_goal_free_adjacent_nodes(&node)
+  );
+  let goal_node = Point2DD::new(-4.0, 0.0);
+  test_subject.change_goal(|grid_cartographer| {
+    grid_cartographer.goal_node = goal_node;
+  });
+  assert!(test_subject.adjacent_nodes_cache.borrow().is_empty());
+  assert!(!test_subject.cost_cache.borrow().is_empty());
+  let grid_cartographer = GridCartographer {
+    goal_node,
+    ..TEST_SUBJECT_GRID_CARTOGRAPHER
+  };
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&node),
+    grid_cartographer.get_adjacent_nodes(&node)
+  );
+  let mut astar = AStar::<Point2DD>::default();
+  let search_outcome: SearchOutcome<Point2DD> =
+    astar.search(node, &test_subject, &SearchBudget::default());
+  assert_eq!(
+    search_outcome,
+    astar.search(node, &grid_cartographer, &SearchBudget::default())
+  );
+  assert!(matches!(
+    search_outcome,
+    SearchOutcome::GoalFound { path, .. } if path.back() == Some(&goal_node)
+  ));
+  test_subject.change_world(|grid_cartographer| {
+    grid_cartographer.step_size = 2.0 * TEST_STEP_SIZE;
+  });
+  assert!(test_subject.adjacent_nodes_cache.borrow().is_empty());
+  assert!(test_subject.cost_cache.borrow().is_empty());
+  assert_eq!(
+    test_subject.get_cartographer().step_size,
+    2.0 * TEST_STEP_SIZE
+  );
+}
+
+#[test]
+fn test_caching_cartographer_search() {
+  let test_subject = CachingCartographer::new(TEST_SUBJECT_GRID_CARTOGRAPHER);
+  let mut astar = AStar::<Point2DD>::default();
+  let expected_search_outcome: SearchOutcome<Point2DD> = astar.search(
+    TEST_ORIGIN_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  assert_eq!(
+    astar.search(TEST_ORIGIN_NODE, &test_subject, &SearchBudget::default()),
+    expected_search_outcome
+  );
+  let miss_count: usize = test_subject.miss_count.get();
+  // The repeated search is answered entirely from the caches
+  assert_eq!(
+    astar.search(TEST_ORIGIN_NODE, &test_subject, &SearchBudget::default()),
+    expected_search_outcome
+  );
+  assert_eq!(test_subject.miss_count.get(), miss_count);
+}
+
+#[test]
+fn test_path_cache() {
+  let mut test_subject = PathCache::<Point2DD>::new(2);
+  assert!(test_subject.is_empty());
+  let node_0 = Point2DD::new(0.0, 0.0);
+  let node_1 = Point2DD::new(1.0, 0.0);
+  let node_2 = Point2DD::new(2.0, 0.0);
+  test_subject.insert(node_0, node_1, make_search_outcome(1.0));
+  test_subject.insert(node_0, node_2, make_search_outcome(2.0));
+  assert_eq!(test_subject.len(), 2);
+  assert_eq!(test_subject.get(node_1, node_0), None);
+  // Using the older outcome makes the newer outcome the least recently used
+  assert_eq!(
+    test_subject.get(node_0, node_1),
+    Some(&make_search_outcome(1.0))
+  );
+  test_subject.insert(node_1, node_2, make_search_outcome(1.0));
+  assert_eq!(test_subject.len(), 2);
+  assert_eq!(test_subject.get(node_0, node_2), None);
+  assert!(test_subject.get(node_0, node_1).is_some());
+  assert_eq!(test_subject.hit_count, 2);
+  assert_eq!(test_subject.miss_count, 2);
+  // Replacing an outcome does not evict another
+  test_subject.insert(node_1, node_2, SearchOutcome::Unreachable);
+  assert_eq!(test_subject.len(), 2);
+  assert_eq!(
+    test_subject.get(node_1, node_2),
+    Some(&SearchOutcome::Unreachable)
+  );
+  assert_eq!(
+    test_subject.remove(node_1, node_2),
+    Some(SearchOutcome::Unreachable)
+  );
+  assert_eq!(test_subject.len(), 1);
+  assert_eq!(test_subject.sequence_to_key_map.len(), 1);
+  test_subject.clear();
+  assert!(test_subject.is_empty());
+  assert!(test_subject.sequence_to_key_map.is_empty());
+  let mut test_subject = PathCache::<Point2DD>::new(0);
+  test_subject.insert(node_0, node_1, make_search_outcome(1.0));
+  assert!(test_subject.is_empty());
+}
+
+#[test]
+fn test_path_cache_search() {
+  let mut test_subject = PathCache::<Point2DD>::new(2);
+  let mut astar = AStar::<Point2DD>::default();
+  let search_outcome: SearchOutcome<Point2DD> = test_subject.search(
+    &mut astar,
+    TEST_ORIGIN_NODE,
+    TEST_GOAL_NODE,
+    &TEST_SUBJECT_GRID_CARTOGRAPHER,
+    &SearchBudget::default(),
+  );
+  assert!(matches!(
+    search_outcome,
+    SearchOutcome::GoalFound {
+      cost: TEST_DISTANCE_TO_GOAL,
+      ..
+    }
+  ));
+  assert_eq!(
+    test_subject.search(
+      &mut astar,
+      TEST_ORIGIN_NODE,
+      TEST_GOAL_NODE,
+      &TEST_SUBJECT_GRID_CARTOGRAPHER,
+      &SearchBudget::default(),
+    ),
+    search_outcome
+  );
+  assert_eq!(test_subject.hit_count, 1);
+  // An exhausted budget is not cached
+  test_subject.clear();
+  let search_budget = SearchBudget {
+    max_expansions: 1,
+    ..Default::default()
+  };
+  assert!(matches!(
+    test_subject.search(
+      &mut astar,
+      TEST_ORIGIN_NODE,
+      TEST_GOAL_NODE,
+      &TEST_SUBJECT_GRID_CARTOGRAPHER,
+      &search_budget,
+    ),
+    SearchOutcome::BudgetExhausted { .. }
+  ));
+  assert!(test_subject.is_empty());
+}
@@ -0,0 +1,299 @@
+// =============================================================================
+//! - Path smoothing for the output of the pathfinding algorithms
+//! - String pulling removes the waypoints which can be skipped
+//! - Optional Bezier or Catmull-Rom curves through the remaining waypoints
+//! - Samples the result at a requested spacing
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::math::geom::point_2dd::Point2DD;
+use crate::math::geom::point_xy::PointXY;
+use std::collections::VecDeque;
+
+// Constants -------------------------------------------------------------------
+
+/// The default distance from the line through its neighbors within which a
+/// waypoint is considered collinear
+pub const DEFAULT_COLLINEAR_TOLERANCE: f64 = 0.000_001;
+
+/// The default number of line segments which approximate each curve segment
+pub const DEFAULT_CURVE_SEGMENT_COUNT: usize = 16;
+
+// Types -----------------------------------------------------------------------
+
+/// The default line of sight tester for the PathSmoother.
+/// A closure which captures the world state can be used instead.
+pub type HasLineOfSightFunction = fn(&Point2DD, &Point2DD) -> bool;
+
+// Structures ------------------------------------------------------------------
+
+/// The curve fitted through the waypoints after string pulling
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PathCurve {
+  /// Rounds each corner with a quadratic Bezier curve from the middle of the
+  /// incoming segment to the middle of the outgoing segment.
+  /// The curve stays within the triangle of the corner.
+  /// A corner stays sharp if the line of sight across the triangle is blocked.
+  Bezier,
+  /// A uniform Catmull-Rom spline which passes through every waypoint.
+  /// The curve can overshoot the straight segments near sharp corners.
+  CatmullRom,
+}
+
+/// Post-processes a path into a smooth sequence of evenly spaced points
+pub struct PathSmoother<L = HasLineOfSightFunction> {
+  pub collinear_tolerance: f64,
+  /// No curve is fitted if None
+  pub curve_option: Option<PathCurve>,
+  /// The number of line segments which approximate each curve segment
+  pub curve_segment_count: usize,
+  /// True if an agent can move in a straight line between the points
+  pub has_line_of_sight_fn: L,
+  /// The distance between the sampled points.
+  /// The waypoints are returned without sampling if not positive.
+  pub sample_spacing: f64,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<L: Fn(&Point2DD, &Point2DD) -> bool> PathSmoother<L> {
+  /// Replaces each corner with a quadratic Bezier curve unless an obstacle
+  /// blocks the line of sight between the ends of the curve
+  pub fn fit_bezier(
+    &self,
+    waypoints: &[Point2DD],
+  ) -> Vec<Point2DD> {
+    if waypoints.len() < 3 {
+      return waypoints.to_vec();
+    }
+    let segment_count: usize = self.curve_segment_count.max(1);
+    let mut point_list: Vec<Point2DD> = vec![waypoints[0]];
+    // Neighboring curves share the midpoint of the segment between them
+    let mut previous_rounded = false;
+    for index in 1..waypoints.len() - 1 {
+      let corner: Point2DD = waypoints[index];
+      let curve_start: Point2DD =
+        interpolate(&waypoints[index - 1], &corner, 0.5);
+      let curve_stop: Point2DD =
+        interpolate(&corner, &waypoints[index + 1], 0.5);
+      if !(self.has_line_of_sight_fn)(&curve_start, &curve_stop) {
+        point_list.push(corner);
+        previous_rounded = false;
+        continue;
+      }
+      let first_segment_index: usize = if previous_rounded {
+        1
+      } else {
+        0
+      };
+      previous_rounded = true;
+      for segment_index in first_segment_index..=segment_count {
+        let t: f64 = segment_index as f64 / segment_count as f64;
+        point_list.push(interpolate(
+          &interpolate(&curve_start, &corner, t),
+          &interpolate(&corner, &curve_stop, t),
+          t,
+        ));
+      }
+    }
+    point_list.push(waypoints[waypoints.len() - 1]);
+    point_list
+  }
+
+  /// A uniform Catmull-Rom spline through the waypoints.
+  /// The end waypoints are repeated to supply the missing control points.
+  pub fn fit_catmull_rom(
+    &self,
+    waypoints: &[Point2DD],
+  ) -> Vec<Point2DD> {
+    if waypoints.len() < 3 {
+      return waypoints.to_vec();
+    }
+    let segment_count: usize = self.curve_segment_count.max(1);
+    let last_index: usize = waypoints.len() - 1;
+    let mut point_list: Vec<Point2DD> = Vec::new();
+    for index in 0..last_index {
+      let p0: Point2DD = waypoints[index.saturating_sub(1)];
+      let p1: Point2DD = waypoints[index];
+      let p2: Point2DD = waypoints[index + 1];
+      let p3: Point2DD = waypoints[(index + 2).min(last_index)];
+      for segment_index in 0..segment_count {
+        let t: f64 = segment_index as f64 / segment_count as f64;
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+        let blend = |v0: f64, v1: f64, v2: f64, v3: f64| {
+          0.5
+            * (2.0 * v1
+              + (v2 - v0) * t
+              + (2.0 * v0 - 5.0 * v1 + 4.0 * v2 - v3) * t2
+              + (3.0 * v1 - v0 - 3.0 * v2 + v3) * t3)
+        };
+        point_list.push(Point2DD::new(
+          blend(p0.x, p1.x, p2.x, p3.x),
+          blend(p0.y, p1.y, p2.y, p3.y),
+        ));
+      }
+    }
+    point_list.push(waypoints[last_index]);
+    point_list
+  }
+
+  /// Skips every waypoint which can be bypassed with a straight line of sight
+  /// from the previous kept waypoint
+  pub fn pull_string(
+    &self,
+    waypoints: &[Point2DD],
+  ) -> Vec<Point2DD> {
+    let Some(first_waypoint) = waypoints.first() else {
+      return Vec::new();
+    };
+    let mut anchor_index: usize = 0;
+    let mut kept_list: Vec<Point2DD> = vec![*first_waypoint];
+    while anchor_index < waypoints.len() - 1 {
+      let mut next_index: usize = anchor_index + 1;
+      while next_index < waypoints.len() - 1
+        && (self.has_line_of_sight_fn)(
+          &waypoints[anchor_index],
+          &waypoints[next_index + 1],
+        )
+      {
+        next_index += 1;
+      }
+      kept_list.push(waypoints[next_index]);
+      anchor_index = next_index;
+    }
+    kept_list
+  }
+
+  /// Removes the waypoints which lie on the line segment between their
+  /// neighbors
+  pub fn remove_collinear_points(
+    &self,
+    waypoints: &[Point2DD],
+  ) -> Vec<Point2DD> {
+    let mut kept_list: Vec<Point2DD> = Vec::new();
+    for (index, waypoint) in waypoints.iter().enumerate() {
+      let is_collinear: bool =
+        match (kept_list.last(), waypoints.get(index + 1)) {
+          (Some(previous_waypoint), Some(next_waypoint)) => is_between(
+            previous_waypoint,
+            waypoint,
+            next_waypoint,
+            self.collinear_tolerance,
+          ),
+          _ => false,
+        };
+      if !is_collinear {
+        kept_list.push(*waypoint);
+      }
+    }
+    kept_list
+  }
+
+  /// Points along the polyline separated by the sample spacing.
+  /// The first and last points of the polyline are always included so the
+  /// last gap can be shorter.
+  pub fn sample(
+    &self,
+    polyline: &[Point2DD],
+  ) -> Vec<Point2DD> {
+    let (Some(first_point), Some(last_point)) =
+      (polyline.first(), polyline.last())
+    else {
+      return Vec::new();
+    };
+    if self.sample_spacing <= 0.0 {
+      return polyline.to_vec();
+    }
+    let mut sample_list: Vec<Point2DD> = vec![*first_point];
+    // The distance travelled along the polyline since the last sample
+    let mut travelled_distance: f64 = 0.0;
+    for (from_point, to_point) in polyline.iter().zip(polyline.iter().skip(1)) {
+      let segment_length: f64 = from_point.distance_to(to_point);
+      let mut offset: f64 = self.sample_spacing - travelled_distance;
+      while offset < segment_length {
+        sample_list.push(interpolate(
+          from_point,
+          to_point,
+          offset / segment_length,
+        ));
+        offset += self.sample_spacing;
+      }
+      travelled_distance = segment_length - (offset - self.sample_spacing);
+    }
+    if polyline.len() > 1 {
+      sample_list.push(*last_point);
+    }
+    sample_list
+  }
+
+  /// Removes the collinear and the redundant waypoints, fits the optional
+  /// curve, and then samples the result.
+  /// The path is the output of AStar::get_path which excludes the start node.
+  pub fn smooth<N: PointXY>(
+    &self,
+    start_node: &N,
+    path: &VecDeque<N>,
+  ) -> Vec<Point2DD> {
+    let waypoints: Vec<Point2DD> = core::iter::once(start_node)
+      .chain(path.iter())
+      .map(|node| Point2DD::new(node.get_x(), node.get_y()))
+      .collect();
+    let waypoints: Vec<Point2DD> = self.remove_collinear_points(&waypoints);
+    let waypoints: Vec<Point2DD> = self.pull_string(&waypoints);
+    let polyline: Vec<Point2DD> = match self.curve_option {
+      Some(PathCurve::Bezier) => self.fit_bezier(&waypoints),
+      Some(PathCurve::CatmullRom) => self.fit_catmull_rom(&waypoints),
+      None => waypoints,
+    };
+    self.sample(&polyline)
+  }
+}
+
+// Functions -------------------------------------------------------------------
+
+/// The point the fraction of the way from the first point to the second
+fn interpolate(
+  from_point: &Point2DD,
+  to_point: &Point2DD,
+  fraction: f64,
+) -> Point2DD {
+  Point2DD::new(
+    from_point.x + (to_point.x - from_point.x) * fraction,
+    from_point.y + (to_point.y - from_point.y) * fraction,
+  )
+}
+
+/// True if the middle point lies on the line segment between the other points
+/// within the tolerance
+fn is_between(
+  from_point: &Point2DD,
+  middle_point: &Point2DD,
+  to_point: &Point2DD,
+  tolerance: f64,
+) -> bool {
+  let dx: f64 = to_point.x - from_point.x;
+  let dy: f64 = to_point.y - from_point.y;
+  let length: f64 = dx.hypot(dy);
+  if length == 0.0 {
+    return from_point.is_near(middle_point, tolerance);
+  }
+  let mx: f64 = middle_point.x - from_point.x;
+  let my: f64 = middle_point.y - from_point.y;
+  let offset: f64 = (dx * my - dy * mx).abs() / length;
+  let projection: f64 = (dx * mx + dy * my) / length;
+  offset <= tolerance
+    && projection >= -tolerance
+    && projection <= length + tolerance
+}
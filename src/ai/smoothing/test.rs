@@ -0,0 +1,220 @@
+// =============================================================================
+//! - Unit tests for path smoothing
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+const TOLERANCE: f64 = 0.000_001;
+
+/// An L-shaped path around a wall at x = 1 which blocks y < 2
+#[cfg(test)]
+const TEST_WAYPOINTS: [Point2DD; 5] = [
+  Point2DD {
+    x: 0.0,
+    y: 0.0,
+  },
+  Point2DD {
+    x: 0.0,
+    y: 1.0,
+  },
+  Point2DD {
+    x: 0.0,
+    y: 2.0,
+  },
+  Point2DD {
+    x: 1.0,
+    y: 2.0,
+  },
+  Point2DD {
+    x: 2.0,
+    y: 2.0,
+  },
+];
+
+/// Blocked if the segment crosses the wall at x = 1 below y = 2
+#[cfg(test)]
+fn has_line_of_sight(
+  from_point: &Point2DD,
+  to_point: &Point2DD,
+) -> bool {
+  let dx: f64 = to_point.x - from_point.x;
+  if (from_point.x - 1.0) * (to_point.x - 1.0) >= 0.0 || dx == 0.0 {
+    return true;
+  }
+  let t: f64 = (1.0 - from_point.x) / dx;
+  from_point.y + (to_point.y - from_point.y) * t >= 2.0
+}
+
+#[cfg(test)]
+fn make_test_subject() -> PathSmoother {
+  PathSmoother {
+    collinear_tolerance: DEFAULT_COLLINEAR_TOLERANCE,
+    curve_option: None,
+    curve_segment_count: DEFAULT_CURVE_SEGMENT_COUNT,
+    has_line_of_sight_fn: has_line_of_sight,
+    sample_spacing: 0.5,
+  }
+}
+
+#[test]
+fn test_fit_bezier() {
+  let test_subject = make_test_subject();
+  let point_list = test_subject.fit_bezier(&[
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(0.0, 2.0),
+    Point2DD::new(2.0, 2.0),
+  ]);
+  assert_eq!(point_list.first(), Some(&Point2DD::new(0.0, 0.0)));
+  assert_eq!(point_list[1], Point2DD::new(0.0, 1.0));
+  assert_eq!(point_list.last(), Some(&Point2DD::new(2.0, 2.0)));
+  // The curve cuts the corner without leaving the triangle of the corner
+  let middle_point: Point2DD = point_list[1 + DEFAULT_CURVE_SEGMENT_COUNT / 2];
+  assert!(middle_point.is_near(&Point2DD::new(0.25, 1.75), TOLERANCE));
+  // The corner at the top of the wall stays sharp
+  let point_list = test_subject.fit_bezier(&[
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(1.0, 2.0),
+    Point2DD::new(2.0, 2.0),
+  ]);
+  assert_eq!(
+    point_list,
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(1.0, 2.0),
+      Point2DD::new(2.0, 2.0),
+    ]
+  );
+  // Two rounded corners share the midpoint of the segment between them once
+  let point_list = test_subject.fit_bezier(&[
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(0.0, 2.0),
+    Point2DD::new(2.0, 2.0),
+    Point2DD::new(2.0, 0.0),
+  ]);
+  assert_eq!(point_list.len(), 2 * DEFAULT_CURVE_SEGMENT_COUNT + 3);
+  assert_eq!(
+    point_list
+      .iter()
+      .filter(|point| **point == Point2DD::new(1.0, 2.0))
+      .count(),
+    1
+  );
+  assert!(point_list.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn test_fit_catmull_rom() {
+  let test_subject = make_test_subject();
+  let point_list = test_subject.fit_catmull_rom(&[
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(0.0, 2.0),
+    Point2DD::new(2.0, 2.0),
+  ]);
+  assert_eq!(point_list.len(), 2 * DEFAULT_CURVE_SEGMENT_COUNT + 1);
+  // The spline passes through every waypoint
+  assert_eq!(point_list[0], Point2DD::new(0.0, 0.0));
+  assert_eq!(
+    point_list[DEFAULT_CURVE_SEGMENT_COUNT],
+    Point2DD::new(0.0, 2.0)
+  );
+  assert_eq!(point_list.last(), Some(&Point2DD::new(2.0, 2.0)));
+}
+
+#[test]
+fn test_pull_string() {
+  let test_subject = make_test_subject();
+  // The line of sight to the top of the wall is not blocked
+  assert_eq!(
+    test_subject.pull_string(&TEST_WAYPOINTS),
+    vec![
+      TEST_WAYPOINTS[0],
+      TEST_WAYPOINTS[3],
+      TEST_WAYPOINTS[4],
+    ]
+  );
+  let test_subject = PathSmoother {
+    collinear_tolerance: DEFAULT_COLLINEAR_TOLERANCE,
+    curve_option: None,
+    curve_segment_count: DEFAULT_CURVE_SEGMENT_COUNT,
+    has_line_of_sight_fn: |_: &Point2DD, _: &Point2DD| true,
+    sample_spacing: 0.5,
+  };
+  assert_eq!(
+    test_subject.pull_string(&TEST_WAYPOINTS),
+    vec![
+      TEST_WAYPOINTS[0],
+      TEST_WAYPOINTS[4],
+    ]
+  );
+  assert!(test_subject.pull_string(&[]).is_empty());
+}
+
+#[test]
+fn test_remove_collinear_points() {
+  let test_subject = make_test_subject();
+  assert_eq!(
+    test_subject.remove_collinear_points(&TEST_WAYPOINTS),
+    vec![
+      TEST_WAYPOINTS[0],
+      TEST_WAYPOINTS[2],
+      TEST_WAYPOINTS[4],
+    ]
+  );
+  // A point which doubles back is not between its neighbors
+  let doubling_back_waypoints = [
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(2.0, 0.0),
+    Point2DD::new(1.0, 0.0),
+  ];
+  assert_eq!(
+    test_subject.remove_collinear_points(&doubling_back_waypoints),
+    doubling_back_waypoints.to_vec()
+  );
+}
+
+#[test]
+fn test_sample() {
+  let test_subject = make_test_subject();
+  let sample_list = test_subject.sample(&[
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(0.0, 0.75),
+    Point2DD::new(0.6, 0.75),
+  ]);
+  assert_eq!(sample_list.len(), 4);
+  assert!(sample_list[1].is_near(&Point2DD::new(0.0, 0.5), TOLERANCE));
+  // The spacing is measured along the polyline around the corner
+  assert!(sample_list[2].is_near(&Point2DD::new(0.25, 0.75), TOLERANCE));
+  assert_eq!(sample_list[3], Point2DD::new(0.6, 0.75));
+  assert!(test_subject.sample(&[]).is_empty());
+}
+
+#[test]
+fn test_smooth() {
+  let test_subject = PathSmoother {
+    curve_option: Some(PathCurve::Bezier),
+    ..make_test_subject()
+  };
+  let path: VecDeque<Point2DD> = TEST_WAYPOINTS[1..].iter().copied().collect();
+  let sample_list = test_subject.smooth(&TEST_WAYPOINTS[0], &path);
+  assert_eq!(sample_list.first(), Some(&TEST_WAYPOINTS[0]));
+  assert_eq!(sample_list.last(), Some(&TEST_WAYPOINTS[4]));
+  for (point, next_point) in sample_list.iter().zip(sample_list.iter().skip(1))
+  {
+    assert!(point.distance_to(next_point) <= 0.5 + TOLERANCE);
+  }
+  // The points pass over the top of the wall
+  for point in &sample_list {
+    assert!(point.x <= 1.0 || point.y >= 2.0);
+  }
+}
@@ -0,0 +1,378 @@
+// =============================================================================
+//! - Space-time A* for agents which avoid moving obstacles and each other
+//! - Searches nodes which carry a time step with a wait-in-place action
+//! - Cooperative A* plans the agents in priority order using reservations
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - Based on the paper "Cooperative Pathfinding" by David Silver, 2005
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{AStar, SearchBudget, SearchOutcome};
+use crate::ai::astar::traits::Cartographer;
+use core::hash::Hash;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// Constants -------------------------------------------------------------------
+
+/// The default time step after which a search stops expanding nodes
+pub const DEFAULT_MAX_TIME_STEP: usize = 1_000;
+
+/// The default cost of waiting in place for one time step
+pub const DEFAULT_WAIT_COST: f64 = 1.0;
+
+// Structures ------------------------------------------------------------------
+
+/// Cooperative A*.
+/// Plans the agents one at a time in priority order.
+/// The path of each agent is reserved so that the agents planned later
+/// avoid it.
+pub struct CooperativeAStar<N> {
+  /// Searches stop expanding nodes at this time step
+  pub max_time_step: usize,
+  pub reservation_table: ReservationTable<N>,
+  pub wait_cost: f64,
+}
+
+/// The (node, time step) pairs and the moves which are blocked.
+/// A path is the list of the nodes occupied at each time step.
+#[derive(Clone, Debug)]
+pub struct ReservationTable<N> {
+  /// The last time step at which each node is reserved.
+  /// A parked node is reserved until the end of time.
+  pub node_to_last_time_step_map: HashMap<N, usize>,
+  /// The nodes which are reserved from a time step onward
+  pub node_to_parked_time_step_map: HashMap<N, usize>,
+  /// The moves from the first node at the time step to the second node at
+  /// the next time step
+  pub reserved_edge_set: HashSet<(N, N, usize)>,
+  pub reserved_node_set: HashSet<(N, usize)>,
+}
+
+/// Time-expanded cartographer which adds a time step to the nodes of the
+/// wrapped cartographer.
+/// Each move takes one time step and waiting in place is allowed.
+pub struct SpaceTimeCartographer<'a, N> {
+  pub cartographer: &'a dyn Cartographer<N>,
+  /// Nodes at this time step have no adjacent nodes
+  pub max_time_step: usize,
+  pub reservation_table: &'a ReservationTable<N>,
+  pub wait_cost: f64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SpaceTimeNode<N> {
+  pub node: N,
+  pub time_step: usize,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl<N: Copy + Eq + Hash> CooperativeAStar<N> {
+  /// Plans a path which avoids the reservations without reserving it.
+  /// The path starts with the start node at time step zero and ends at a goal
+  /// node which the agent can then occupy indefinitely.
+  pub fn find_path(
+    &self,
+    start_node: N,
+    cartographer: &dyn Cartographer<N>,
+  ) -> Option<Vec<N>> {
+    let space_time_cartographer = SpaceTimeCartographer {
+      cartographer,
+      max_time_step: self.max_time_step,
+      reservation_table: &self.reservation_table,
+      wait_cost: self.wait_cost,
+    };
+    let mut astar = AStar::<SpaceTimeNode<N>>::default();
+    let search_outcome = astar.search(
+      SpaceTimeNode {
+        node: start_node,
+        time_step: 0,
+      },
+      &space_time_cartographer,
+      &SearchBudget::default(),
+    );
+    let SearchOutcome::GoalFound {
+      path,
+      ..
+    } = search_outcome
+    else {
+      return None;
+    };
+    let mut path_node_list: Vec<N> = vec![start_node];
+    path_node_list
+      .extend(path.iter().map(|space_time_node| space_time_node.node));
+    Some(path_node_list)
+  }
+
+  /// Plans and reserves the paths of the agents in priority order.
+  /// Each agent is a start node and a cartographer with the goal of the agent.
+  /// An agent without a path is parked at its start node.
+  pub fn find_paths(
+    &mut self,
+    agents: &[(N, &dyn Cartographer<N>)],
+  ) -> Vec<Option<Vec<N>>> {
+    let mut path_option_list = Vec::new();
+    for (start_node, cartographer) in agents {
+      let path_option: Option<Vec<N>> =
+        self.find_path(*start_node, *cartographer);
+      match &path_option {
+        Some(path) => self.reservation_table.reserve_path(path),
+        None => self.reservation_table.park_node(*start_node, 0),
+      }
+      path_option_list.push(path_option);
+    }
+    path_option_list
+  }
+}
+
+impl<N: Copy + Eq + Hash> ReservationTable<N> {
+  pub fn clear(&mut self) {
+    self.node_to_last_time_step_map.clear();
+    self.node_to_parked_time_step_map.clear();
+    self.reserved_edge_set.clear();
+    self.reserved_node_set.clear();
+  }
+
+  /// The last time step at which the node is reserved.
+  /// The maximum time step if the node is parked.
+  pub fn get_last_time_step(
+    &self,
+    node: &N,
+  ) -> Option<usize> {
+    if self.node_to_parked_time_step_map.contains_key(node) {
+      return Some(usize::MAX);
+    }
+    self.node_to_last_time_step_map.get(node).copied()
+  }
+
+  /// True if moving from the node at the time step to the other node at the
+  /// next time step would collide with a reservation.
+  /// The move collides if the other node is reserved at the next time step or
+  /// if the move would swap places with a reserved move.
+  pub fn is_move_blocked(
+    &self,
+    from_node: &N,
+    to_node: &N,
+    time_step: usize,
+  ) -> bool {
+    self.is_node_reserved(to_node, time_step + 1)
+      || self
+        .reserved_edge_set
+        .contains(&(*to_node, *from_node, time_step))
+  }
+
+  pub fn is_node_reserved(
+    &self,
+    node: &N,
+    time_step: usize,
+  ) -> bool {
+    self.reserved_node_set.contains(&(*node, time_step))
+      || self
+        .node_to_parked_time_step_map
+        .get(node)
+        .is_some_and(|parked_time_step| *parked_time_step <= time_step)
+  }
+
+  /// Reserves the node from the time step onward
+  pub fn park_node(
+    &mut self,
+    node: N,
+    time_step: usize,
+  ) {
+    let parked_time_step: &mut usize = self
+      .node_to_parked_time_step_map
+      .entry(node)
+      .or_insert(time_step);
+    *parked_time_step = (*parked_time_step).min(time_step);
+  }
+
+  /// Reserves the move from the node at the time step to the other node at
+  /// the next time step
+  pub fn reserve_edge(
+    &mut self,
+    from_node: N,
+    to_node: N,
+    time_step: usize,
+  ) {
+    self
+      .reserved_edge_set
+      .insert((from_node, to_node, time_step));
+  }
+
+  pub fn reserve_node(
+    &mut self,
+    node: N,
+    time_step: usize,
+  ) {
+    self.reserved_node_set.insert((node, time_step));
+    let last_time_step: &mut usize = self
+      .node_to_last_time_step_map
+      .entry(node)
+      .or_insert(time_step);
+    *last_time_step = (*last_time_step).max(time_step);
+  }
+
+  /// Reserves the nodes and the moves of the path starting at time step zero
+  /// and parks the last node.
+  /// Moving obstacles can be reserved the same way.
+  pub fn reserve_path(
+    &mut self,
+    path: &[N],
+  ) {
+    for (time_step, node) in path.iter().enumerate() {
+      self.reserve_node(*node, time_step);
+      if let Some(next_node) = path.get(time_step + 1) {
+        self.reserve_edge(*node, *next_node, time_step);
+      }
+    }
+    if let Some(last_node) = path.last() {
+      self.park_node(*last_node, path.len() - 1);
+    }
+  }
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl<N: Copy + Eq + Hash> Cartographer<SpaceTimeNode<N>>
+  for SpaceTimeCartographer<'_, N>
+{
+  /// The estimate of the wrapped cartographer, which ignores waiting
+  fn estimate_cost_between(
+    &self,
+    from_node: &SpaceTimeNode<N>,
+    to_node: &SpaceTimeNode<N>,
+  ) -> f64 {
+    self
+      .cartographer
+      .estimate_cost_between(&from_node.node, &to_node.node)
+  }
+
+  /// The estimate of the wrapped cartographer, which ignores waiting
+  fn estimate_cost_to_goal(
+    &self,
+    node: &SpaceTimeNode<N>,
+  ) -> f64 {
+    self.cartographer.estimate_cost_to_goal(&node.node)
+  }
+
+  /// The unblocked adjacent nodes and the same node at the next time step
+  fn get_adjacent_nodes(
+    &self,
+    node: &SpaceTimeNode<N>,
+  ) -> Vec<SpaceTimeNode<N>> {
+    if node.time_step >= self.max_time_step {
+      return Vec::new();
+    }
+    let mut adjacent_list: Vec<N> =
+      self.cartographer.get_adjacent_nodes(&node.node);
+    adjacent_list.push(node.node);
+    adjacent_list
+      .into_iter()
+      .filter(|adjacent_node| {
+        !self.reservation_table.is_move_blocked(
+          &node.node,
+          adjacent_node,
+          node.time_step,
+        )
+      })
+      .map(|adjacent_node| SpaceTimeNode {
+        node: adjacent_node,
+        time_step: node.time_step + 1,
+      })
+      .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &SpaceTimeNode<N>,
+    to_node: &SpaceTimeNode<N>,
+  ) -> f64 {
+    if from_node.node == to_node.node {
+      return self.wait_cost;
+    }
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(&from_node.node, &to_node.node)
+  }
+
+  /// The unblocked predecessor nodes and the same node at the previous time
+  /// step
+  fn get_predecessor_nodes(
+    &self,
+    node: &SpaceTimeNode<N>,
+  ) -> Vec<SpaceTimeNode<N>> {
+    if node.time_step == 0 {
+      return Vec::new();
+    }
+    let time_step: usize = node.time_step - 1;
+    let mut predecessor_list: Vec<N> =
+      self.cartographer.get_predecessor_nodes(&node.node);
+    predecessor_list.push(node.node);
+    predecessor_list
+      .into_iter()
+      .filter(|predecessor_node| {
+        !self
+          .reservation_table
+          .is_node_reserved(predecessor_node, time_step)
+          && !self.reservation_table.is_move_blocked(
+            predecessor_node,
+            &node.node,
+            time_step,
+          )
+      })
+      .map(|predecessor_node| SpaceTimeNode {
+        node: predecessor_node,
+        time_step,
+      })
+      .collect()
+  }
+
+  /// A goal node of the wrapped cartographer which is not reserved at this
+  /// time step or later so that the agent can stay there
+  fn is_goal_node(
+    &self,
+    node: &SpaceTimeNode<N>,
+  ) -> bool {
+    self.cartographer.is_goal_node(&node.node)
+      && self
+        .reservation_table
+        .get_last_time_step(&node.node)
+        .is_none_or(|last_time_step| last_time_step < node.time_step)
+  }
+}
+
+// Trait Default ---------------------------------------------------------------
+
+impl<N> Default for CooperativeAStar<N> {
+  fn default() -> Self {
+    CooperativeAStar {
+      max_time_step: DEFAULT_MAX_TIME_STEP,
+      reservation_table: ReservationTable::default(),
+      wait_cost: DEFAULT_WAIT_COST,
+    }
+  }
+}
+
+impl<N> Default for ReservationTable<N> {
+  fn default() -> Self {
+    ReservationTable {
+      node_to_last_time_step_map: HashMap::new(),
+      node_to_parked_time_step_map: HashMap::new(),
+      reserved_edge_set: HashSet::new(),
+      reserved_node_set: HashSet::new(),
+    }
+  }
+}
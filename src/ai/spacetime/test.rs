@@ -0,0 +1,284 @@
+// =============================================================================
+//! - Unit tests for space-time A*
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+/// A corridor of cells along a line with a goal cell
+#[cfg(test)]
+struct CorridorCartographer {
+  goal_node: i64,
+  length: i64,
+}
+
+#[cfg(test)]
+impl Cartographer<i64> for CorridorCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &i64,
+  ) -> f64 {
+    (self.goal_node - node).abs() as f64
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &i64,
+  ) -> Vec<i64> {
+    [
+      node - 1,
+      node + 1,
+    ]
+    .into_iter()
+    .filter(|adjacent_node| (0..self.length).contains(adjacent_node))
+    .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &i64,
+    _to_node: &i64,
+  ) -> f64 {
+    1.0
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &i64,
+  ) -> bool {
+    *node == self.goal_node
+  }
+}
+
+#[cfg(test)]
+const TEST_CORRIDOR_CARTOGRAPHER: CorridorCartographer = CorridorCartographer {
+  goal_node: 4,
+  length: 6,
+};
+
+#[test]
+fn test_default() {
+  let test_subject = CooperativeAStar::<i64>::default();
+  assert_eq!(test_subject.max_time_step, DEFAULT_MAX_TIME_STEP);
+  assert_eq!(test_subject.wait_cost, DEFAULT_WAIT_COST);
+  assert!(test_subject.reservation_table.reserved_node_set.is_empty());
+}
+
+#[test]
+fn test_find_path() {
+  let mut test_subject = CooperativeAStar::<i64>::default();
+  assert_eq!(
+    test_subject.find_path(1, &TEST_CORRIDOR_CARTOGRAPHER),
+    Some(vec![
+      1, 2, 3, 4
+    ])
+  );
+  // A moving obstacle passes through the goal at time step five
+  test_subject.reservation_table.reserve_node(4, 5);
+  let path = test_subject
+    .find_path(1, &TEST_CORRIDOR_CARTOGRAPHER)
+    .unwrap();
+  assert_eq!(path.len(), 7);
+  assert_eq!(path.last(), Some(&4));
+  assert!(!test_subject.reservation_table.is_node_reserved(&path[5], 5));
+}
+
+#[test]
+fn test_find_path_wait() {
+  let mut test_subject = CooperativeAStar::<i64>::default();
+  // A moving obstacle blocks the next cell for two time steps
+  test_subject.reservation_table.reserve_node(2, 1);
+  test_subject.reservation_table.reserve_node(2, 2);
+  assert_eq!(
+    test_subject.find_path(1, &TEST_CORRIDOR_CARTOGRAPHER),
+    Some(vec![
+      1, 1, 1, 2, 3, 4
+    ])
+  );
+}
+
+#[test]
+fn test_find_paths() {
+  let mut test_subject = CooperativeAStar::<i64>::default();
+  let other_cartographer = CorridorCartographer {
+    goal_node: 0,
+    length: 6,
+  };
+  // The second agent is trapped behind the goal of the first agent
+  let path_option_list = test_subject.find_paths(&[
+    (3, &TEST_CORRIDOR_CARTOGRAPHER),
+    (5, &other_cartographer),
+  ]);
+  assert_eq!(
+    path_option_list[0],
+    Some(vec![
+      3, 4
+    ])
+  );
+  assert_eq!(path_option_list[1], None);
+  assert!(test_subject.reservation_table.is_node_reserved(&5, 100));
+}
+
+#[test]
+fn test_find_paths_max_time_step() {
+  let mut test_subject = CooperativeAStar::<i64> {
+    max_time_step: 2,
+    ..Default::default()
+  };
+  let path_option_list =
+    test_subject.find_paths(&[(0, &TEST_CORRIDOR_CARTOGRAPHER)]);
+  assert_eq!(path_option_list[0], None);
+  assert!(test_subject.reservation_table.is_node_reserved(&0, 0));
+}
+
+#[test]
+fn test_get_adjacent_nodes() {
+  let mut reservation_table = ReservationTable::default();
+  reservation_table.reserve_node(0, 1);
+  let test_subject = SpaceTimeCartographer {
+    cartographer: &TEST_CORRIDOR_CARTOGRAPHER,
+    max_time_step: 3,
+    reservation_table: &reservation_table,
+    wait_cost: 0.5,
+  };
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&SpaceTimeNode {
+      node: 1,
+      time_step: 0,
+    }),
+    vec![
+      SpaceTimeNode {
+        node: 2,
+        time_step: 1,
+      },
+      SpaceTimeNode {
+        node: 1,
+        time_step: 1,
+      },
+    ]
+  );
+  assert!(
+    test_subject
+      .get_adjacent_nodes(&SpaceTimeNode {
+        node: 1,
+        time_step: 3,
+      })
+      .is_empty()
+  );
+  assert_eq!(
+    test_subject.get_cost_to_adjacent_node(
+      &SpaceTimeNode {
+        node: 1,
+        time_step: 0,
+      },
+      &SpaceTimeNode {
+        node: 1,
+        time_step: 1,
+      },
+    ),
+    0.5
+  );
+}
+
+#[test]
+fn test_get_predecessor_nodes() {
+  let mut reservation_table = ReservationTable::default();
+  reservation_table.reserve_node(2, 0);
+  let test_subject = SpaceTimeCartographer {
+    cartographer: &TEST_CORRIDOR_CARTOGRAPHER,
+    max_time_step: 3,
+    reservation_table: &reservation_table,
+    wait_cost: 1.0,
+  };
+  assert_eq!(
+    test_subject.get_predecessor_nodes(&SpaceTimeNode {
+      node: 1,
+      time_step: 1,
+    }),
+    vec![
+      SpaceTimeNode {
+        node: 0,
+        time_step: 0,
+      },
+      SpaceTimeNode {
+        node: 1,
+        time_step: 0,
+      },
+    ]
+  );
+  assert!(
+    test_subject
+      .get_predecessor_nodes(&SpaceTimeNode {
+        node: 1,
+        time_step: 0,
+      })
+      .is_empty()
+  );
+}
+
+#[test]
+fn test_is_goal_node() {
+  let mut reservation_table = ReservationTable::default();
+  reservation_table.reserve_node(4, 3);
+  let test_subject = SpaceTimeCartographer {
+    cartographer: &TEST_CORRIDOR_CARTOGRAPHER,
+    max_time_step: 10,
+    reservation_table: &reservation_table,
+    wait_cost: 1.0,
+  };
+  assert!(!test_subject.is_goal_node(&SpaceTimeNode {
+    node: 4,
+    time_step: 2,
+  }));
+  assert!(test_subject.is_goal_node(&SpaceTimeNode {
+    node: 4,
+    time_step: 4,
+  }));
+  assert!(!test_subject.is_goal_node(&SpaceTimeNode {
+    node: 3,
+    time_step: 4,
+  }));
+}
+
+#[test]
+fn test_is_move_blocked() {
+  let mut test_subject = ReservationTable::default();
+  test_subject.reserve_path(&[
+    1, 2, 3,
+  ]);
+  assert!(!test_subject.is_move_blocked(&0, &4, 0));
+  assert!(test_subject.is_move_blocked(&1, &2, 0));
+  // Swapping places with the reserved move from 1 to 2
+  assert!(test_subject.is_move_blocked(&2, &1, 0));
+  assert!(!test_subject.is_move_blocked(&2, &1, 1));
+  // The last node is parked
+  assert!(test_subject.is_move_blocked(&4, &3, 50));
+}
+
+#[test]
+fn test_reserve_path() {
+  let mut test_subject = ReservationTable::default();
+  test_subject.reserve_path(&[
+    1, 2, 2, 3,
+  ]);
+  assert!(test_subject.is_node_reserved(&1, 0));
+  assert!(!test_subject.is_node_reserved(&1, 1));
+  assert!(test_subject.is_node_reserved(&2, 2));
+  assert!(test_subject.is_node_reserved(&3, 3));
+  assert!(test_subject.is_node_reserved(&3, 100));
+  assert!(!test_subject.is_node_reserved(&3, 2));
+  assert_eq!(test_subject.get_last_time_step(&2), Some(2));
+  assert_eq!(test_subject.get_last_time_step(&3), Some(usize::MAX));
+  assert_eq!(test_subject.get_last_time_step(&4), None);
+  test_subject.clear();
+  assert!(!test_subject.is_node_reserved(&3, 100));
+}
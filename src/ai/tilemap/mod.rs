@@ -0,0 +1,495 @@
+// =============================================================================
+//! - Tile maps of passable and blocked cells for grid path finding
+//! - Loads the MovingAI benchmark map and scenario formats and ASCII grids
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - The map and scenario formats are from the Moving AI Lab benchmarks
+//!   - "Benchmarks for Grid-Based Pathfinding"
+//!   - By Nathan R. Sturtevant, 2012
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use crate::ai::astar::structures::{
+  AStar, GoalCartographer, SearchBudget, SearchOutcome,
+};
+use crate::ai::astar::traits::Cartographer;
+use crate::math::geom::point_2dd::Point2DD;
+use core::f64::consts::SQRT_2;
+
+// Structures ------------------------------------------------------------------
+
+/// A grid of passable and blocked cells.
+/// A node is the column as x and the row as y of a cell.
+/// Diagonal moves cannot cut the corner of a blocked cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileMap {
+  pub connectivity: TileMapConnectivity,
+  /// The goal of the searches which use the tile map as the cartographer.
+  /// Nothing is the goal when this is None.
+  pub goal_node_option: Option<Point2DD>,
+  pub height: usize,
+  /// Whether each cell is passable by row and then by column
+  pub passable_list: Vec<bool>,
+  pub start_node_option: Option<Point2DD>,
+  pub width: usize,
+}
+
+/// The adjacent cells of a cell
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TileMapConnectivity {
+  /// The cells which share an edge at a cost of one
+  Four,
+  /// The cells which share an edge or a corner with the diagonal moves at a
+  /// cost of the square root of two
+  Eight,
+}
+
+/// A problem with a line of a map, ASCII grid, or scenario
+#[derive(Debug, PartialEq)]
+pub enum TileMapParseError {
+  /// A second start or goal marker in an ASCII grid
+  DuplicateMarker {
+    line_number: usize,
+  },
+  /// A map header line which is missing or not recognized
+  InvalidHeader {
+    line_number: usize,
+  },
+  /// A size, coordinate, or length which could not be parsed
+  InvalidNumber {
+    line_number: usize,
+  },
+  /// A character which is not a known tile
+  UnknownTile {
+    line_number: usize,
+    tile: char,
+  },
+  /// A scenario line with other than nine fields
+  WrongFieldCount {
+    line_number: usize,
+  },
+  /// A map with fewer rows than its height.
+  /// The line number is the line after the last row.
+  WrongRowCount {
+    line_number: usize,
+  },
+  /// A row with a different length than the width of the map
+  WrongRowLength {
+    line_number: usize,
+  },
+}
+
+/// A search problem from a MovingAI scenario file
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileMapScenario {
+  pub bucket: usize,
+  pub goal_node: Point2DD,
+  pub map_height: usize,
+  pub map_name: String,
+  pub map_width: usize,
+  /// The length of the shortest path with eight-connectivity
+  pub optimal_length: f64,
+  pub start_node: Point2DD,
+}
+
+/// A scenario for which the path length found was not the optimal length
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileMapScenarioMismatch {
+  /// None if no path was found
+  pub found_length_option: Option<f64>,
+  pub optimal_length: f64,
+  /// The position of the scenario in the scenario list
+  pub scenario_index: usize,
+}
+
+// Methods ---------------------------------------------------------------------
+
+impl TileMap {
+  /// Searches each scenario with A* and returns the scenarios for which the
+  /// path length differs from the optimal length by more than the tolerance
+  pub fn check_scenarios(
+    &self,
+    scenario_list: &[TileMapScenario],
+    tolerance: f64,
+  ) -> Vec<TileMapScenarioMismatch> {
+    scenario_list
+      .iter()
+      .enumerate()
+      .filter_map(|(scenario_index, scenario)| {
+        let found_length_option: Option<f64> =
+          self.find_path_length(&scenario.start_node, &scenario.goal_node);
+        let matched: bool = found_length_option.is_some_and(|found_length| {
+          (found_length - scenario.optimal_length).abs() <= tolerance
+        });
+        if matched {
+          return None;
+        }
+        Some(TileMapScenarioMismatch {
+          found_length_option,
+          optimal_length: scenario.optimal_length,
+          scenario_index,
+        })
+      })
+      .collect()
+  }
+
+  /// The length of the shortest path between the nodes.
+  /// The goal node of the tile map is ignored.
+  pub fn find_path_length(
+    &self,
+    start_node: &Point2DD,
+    goal_node: &Point2DD,
+  ) -> Option<f64> {
+    if !self.is_passable(start_node) || !self.is_passable(goal_node) {
+      return None;
+    }
+    let goal_cartographer = GoalCartographer {
+      cartographer: self,
+      goal_node: *goal_node,
+    };
+    let mut astar = AStar::<Point2DD>::default();
+    match astar.search(
+      *start_node,
+      &goal_cartographer,
+      &SearchBudget::default(),
+    ) {
+      SearchOutcome::GoalFound {
+        cost,
+        ..
+      } => Some(cost),
+      _ => None,
+    }
+  }
+
+  /// The index of the cell in the passable list if the node is on the map
+  fn get_cell_index(
+    &self,
+    node: &Point2DD,
+  ) -> Option<usize> {
+    if node.x < 0.0
+      || node.y < 0.0
+      || node.x >= self.width as f64
+      || node.y >= self.height as f64
+    {
+      return None;
+    }
+    Some(node.y as usize * self.width + node.x as usize)
+  }
+
+  /// True if the node is on the map and the cell is passable
+  pub fn is_passable(
+    &self,
+    node: &Point2DD,
+  ) -> bool {
+    self
+      .get_cell_index(node)
+      .is_some_and(|cell_index| self.passable_list[cell_index])
+  }
+
+  /// Parses an ASCII grid with one line per row.
+  /// A "#" is a wall and a "." is a floor.
+  /// An "S" or a "G" is a floor which is the start or the goal.
+  /// The blank lines before and after the grid are ignored.
+  pub fn parse_ascii(
+    text: &str,
+    connectivity: TileMapConnectivity,
+  ) -> Result<Self, TileMapParseError> {
+    let line_list: Vec<(usize, &str)> = text
+      .lines()
+      .enumerate()
+      .map(|(line_index, line)| (line_index + 1, line.trim_end()))
+      .skip_while(|(_, line)| line.is_empty())
+      .collect();
+    let row_count: usize = line_list
+      .iter()
+      .rposition(|(_, line)| !line.is_empty())
+      .map_or(0, |row_index| row_index + 1);
+    let width: usize = line_list.first().map_or(0, |(_, line)| line.len());
+    let mut tile_map = TileMap {
+      connectivity,
+      goal_node_option: None,
+      height: row_count,
+      passable_list: Vec::with_capacity(row_count * width),
+      start_node_option: None,
+      width,
+    };
+    for (row_index, (line_number, line)) in
+      line_list[..row_count].iter().enumerate()
+    {
+      let line_number: usize = *line_number;
+      if line.chars().count() != width {
+        return Err(TileMapParseError::WrongRowLength {
+          line_number,
+        });
+      }
+      for (column_index, tile) in line.chars().enumerate() {
+        let node = Point2DD::new(column_index as f64, row_index as f64);
+        let passable: bool = match tile {
+          '#' => false,
+          '.' => true,
+          'G' => {
+            if tile_map.goal_node_option.replace(node).is_some() {
+              return Err(TileMapParseError::DuplicateMarker {
+                line_number,
+              });
+            }
+            true
+          },
+          'S' => {
+            if tile_map.start_node_option.replace(node).is_some() {
+              return Err(TileMapParseError::DuplicateMarker {
+                line_number,
+              });
+            }
+            true
+          },
+          _ => {
+            return Err(TileMapParseError::UnknownTile {
+              line_number,
+              tile,
+            });
+          },
+        };
+        tile_map.passable_list.push(passable);
+      }
+    }
+    Ok(tile_map)
+  }
+
+  /// Parses a MovingAI benchmark map.
+  /// The header is "type octile", "height", "width", and "map" lines
+  /// followed by one line per row.
+  /// The "." and "G" ground and the "S" swamp tiles are passable.
+  /// The "@" and "O" out of bounds, "T" tree, and "W" water tiles are not.
+  pub fn parse_map(
+    text: &str,
+    connectivity: TileMapConnectivity,
+  ) -> Result<Self, TileMapParseError> {
+    let mut line_iterator = text
+      .lines()
+      .enumerate()
+      .map(|(line_index, line)| (line_index + 1, line.trim_end()));
+    let mut height_option: Option<usize> = None;
+    let mut width_option: Option<usize> = None;
+    let mut last_line_number: usize = 0;
+    loop {
+      let Some((line_number, line)) = line_iterator.next() else {
+        return Err(TileMapParseError::InvalidHeader {
+          line_number: last_line_number + 1,
+        });
+      };
+      last_line_number = line_number;
+      let mut token_iterator = line.split_whitespace();
+      let size_option: &mut Option<usize> = match token_iterator.next() {
+        Some("height") => &mut height_option,
+        Some("map") => break,
+        Some("type") => continue,
+        Some("width") => &mut width_option,
+        _ => {
+          return Err(TileMapParseError::InvalidHeader {
+            line_number,
+          });
+        },
+      };
+      let size: usize = token_iterator
+        .next()
+        .and_then(|token| token.parse().ok())
+        .ok_or(TileMapParseError::InvalidNumber {
+          line_number,
+        })?;
+      *size_option = Some(size);
+    }
+    let (Some(height), Some(width)) = (height_option, width_option) else {
+      return Err(TileMapParseError::InvalidHeader {
+        line_number: last_line_number,
+      });
+    };
+    let mut passable_list: Vec<bool> = Vec::with_capacity(height * width);
+    for _ in 0..height {
+      let Some((line_number, line)) = line_iterator.next() else {
+        return Err(TileMapParseError::WrongRowCount {
+          line_number: last_line_number + 1,
+        });
+      };
+      last_line_number = line_number;
+      if line.chars().count() != width {
+        return Err(TileMapParseError::WrongRowLength {
+          line_number,
+        });
+      }
+      for tile in line.chars() {
+        let passable: bool = match tile {
+          '.' | 'G' | 'S' => true,
+          '@' | 'O' | 'T' | 'W' => false,
+          _ => {
+            return Err(TileMapParseError::UnknownTile {
+              line_number,
+              tile,
+            });
+          },
+        };
+        passable_list.push(passable);
+      }
+    }
+    Ok(TileMap {
+      connectivity,
+      goal_node_option: None,
+      height,
+      passable_list,
+      start_node_option: None,
+      width,
+    })
+  }
+}
+
+impl TileMapScenario {
+  /// Parses a MovingAI benchmark scenario file.
+  /// Each line is the bucket, map name, map width, map height, start x,
+  /// start y, goal x, goal y, and optimal length separated by whitespace.
+  /// The version line and blank lines are skipped.
+  pub fn parse(text: &str) -> Result<Vec<Self>, TileMapParseError> {
+    let mut scenario_list: Vec<Self> = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+      let line_number: usize = line_index + 1;
+      let field_list: Vec<&str> = line.split_whitespace().collect();
+      let [
+        bucket,
+        map_name,
+        map_width,
+        map_height,
+        start_x,
+        start_y,
+        goal_x,
+        goal_y,
+        optimal_length,
+      ] = field_list[..]
+      else {
+        if field_list.is_empty() || field_list[0] == "version" {
+          continue;
+        }
+        return Err(TileMapParseError::WrongFieldCount {
+          line_number,
+        });
+      };
+      let parse_usize = |field: &str| {
+        field
+          .parse::<usize>()
+          .map_err(|_| TileMapParseError::InvalidNumber {
+            line_number,
+          })
+      };
+      scenario_list.push(TileMapScenario {
+        bucket: parse_usize(bucket)?,
+        goal_node: Point2DD::new(
+          parse_usize(goal_x)? as f64,
+          parse_usize(goal_y)? as f64,
+        ),
+        map_height: parse_usize(map_height)?,
+        map_name: map_name.to_string(),
+        map_width: parse_usize(map_width)?,
+        optimal_length: optimal_length.parse().map_err(|_| {
+          TileMapParseError::InvalidNumber {
+            line_number,
+          }
+        })?,
+        start_node: Point2DD::new(
+          parse_usize(start_x)? as f64,
+          parse_usize(start_y)? as f64,
+        ),
+      });
+    }
+    Ok(scenario_list)
+  }
+}
+
+// Trait Cartographer ----------------------------------------------------------
+
+impl Cartographer<Point2DD> for TileMap {
+  /// The octile distance with eight-connectivity or the Manhattan distance
+  /// with four-connectivity
+  fn estimate_cost_between(
+    &self,
+    from_node: &Point2DD,
+    to_node: &Point2DD,
+  ) -> f64 {
+    let delta_x: f64 = (to_node.x - from_node.x).abs();
+    let delta_y: f64 = (to_node.y - from_node.y).abs();
+    match self.connectivity {
+      TileMapConnectivity::Four => delta_x + delta_y,
+      TileMapConnectivity::Eight => {
+        delta_x.max(delta_y) + (SQRT_2 - 1.0) * delta_x.min(delta_y)
+      },
+    }
+  }
+
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Point2DD,
+  ) -> f64 {
+    self.goal_node_option.map_or(0.0, |goal_node| {
+      self.estimate_cost_between(node, &goal_node)
+    })
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point2DD,
+  ) -> Vec<Point2DD> {
+    let mut adjacent_list = Vec::new();
+    for delta_y in -1..=1 {
+      for delta_x in -1..=1 {
+        if delta_x == 0 && delta_y == 0 {
+          continue;
+        }
+        let diagonal: bool = delta_x != 0 && delta_y != 0;
+        if diagonal && self.connectivity == TileMapConnectivity::Four {
+          continue;
+        }
+        let adjacent_node =
+          Point2DD::new(node.x + delta_x as f64, node.y + delta_y as f64);
+        if !self.is_passable(&adjacent_node) {
+          continue;
+        }
+        // Both of the cells beside a diagonal move must be passable
+        if diagonal
+          && (!self.is_passable(&Point2DD::new(adjacent_node.x, node.y))
+            || !self.is_passable(&Point2DD::new(node.x, adjacent_node.y)))
+        {
+          continue;
+        }
+        adjacent_list.push(adjacent_node);
+      }
+    }
+    adjacent_list
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &Point2DD,
+    to_node: &Point2DD,
+  ) -> f64 {
+    if from_node.x != to_node.x && from_node.y != to_node.y {
+      SQRT_2
+    } else {
+      1.0
+    }
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Point2DD,
+  ) -> bool {
+    self.goal_node_option == Some(*node)
+  }
+}
@@ -0,0 +1,262 @@
+// =============================================================================
+//! - Unit tests for the tile map
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+/// A wall with a gap at the bottom
+#[cfg(test)]
+const TEST_ASCII_GRID: &str = "
+S.#..
+..#..
+....G
+";
+
+#[cfg(test)]
+const TEST_MAP: &str = "type octile
+height 3
+width 5
+map
+..@..
+.T@..
+.....
+";
+
+#[cfg(test)]
+const TEST_SCENARIO: &str = "version 1
+0\ttest.map\t5\t3\t0\t0\t4\t0\t7.41421356
+1\ttest.map\t5\t3\t0\t0\t2\t2\t4.00000000
+";
+
+#[cfg(test)]
+const TEST_TOLERANCE: f64 = 0.000_001;
+
+#[test]
+fn test_check_scenarios() {
+  let test_subject =
+    TileMap::parse_map(TEST_MAP, TileMapConnectivity::Eight).unwrap();
+  let mut scenario_list: Vec<TileMapScenario> =
+    TileMapScenario::parse(TEST_SCENARIO).unwrap();
+  assert_eq!(
+    test_subject.check_scenarios(&scenario_list, TEST_TOLERANCE),
+    []
+  );
+  scenario_list[1].optimal_length = 3.0;
+  scenario_list.push(TileMapScenario {
+    goal_node: Point2DD::new(2.0, 0.0),
+    ..scenario_list[0].clone()
+  });
+  assert_eq!(
+    test_subject.check_scenarios(&scenario_list, TEST_TOLERANCE),
+    [
+      TileMapScenarioMismatch {
+        found_length_option: Some(4.0),
+        optimal_length: 3.0,
+        scenario_index: 1,
+      },
+      TileMapScenarioMismatch {
+        found_length_option: None,
+        optimal_length: scenario_list[0].optimal_length,
+        scenario_index: 2,
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_find_path_length() {
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Eight).unwrap();
+  let start_node: Point2DD = test_subject.start_node_option.unwrap();
+  let goal_node: Point2DD = test_subject.goal_node_option.unwrap();
+  // Cannot cut the corner at the bottom of the wall
+  let length: f64 = test_subject
+    .find_path_length(&start_node, &goal_node)
+    .unwrap();
+  assert!((length - (4.0 + SQRT_2)).abs() < TEST_TOLERANCE);
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Four).unwrap();
+  assert_eq!(
+    test_subject.find_path_length(&start_node, &goal_node),
+    Some(6.0)
+  );
+  assert_eq!(
+    test_subject.find_path_length(&start_node, &Point2DD::new(2.0, 0.0)),
+    None
+  );
+}
+
+#[test]
+fn test_get_adjacent_nodes() {
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Eight).unwrap();
+  // The diagonal moves around the bottom of the wall would cut its corner
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&Point2DD::new(1.0, 1.0)),
+    vec![
+      Point2DD::new(0.0, 0.0),
+      Point2DD::new(1.0, 0.0),
+      Point2DD::new(0.0, 1.0),
+      Point2DD::new(0.0, 2.0),
+      Point2DD::new(1.0, 2.0),
+    ]
+  );
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&Point2DD::new(3.0, 1.0)),
+    vec![
+      Point2DD::new(3.0, 0.0),
+      Point2DD::new(4.0, 0.0),
+      Point2DD::new(4.0, 1.0),
+      Point2DD::new(3.0, 2.0),
+      Point2DD::new(4.0, 2.0),
+    ]
+  );
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Four).unwrap();
+  assert_eq!(
+    test_subject.get_adjacent_nodes(&Point2DD::new(0.0, 0.0)),
+    vec![
+      Point2DD::new(1.0, 0.0),
+      Point2DD::new(0.0, 1.0),
+    ]
+  );
+}
+
+#[test]
+fn test_is_passable() {
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Eight).unwrap();
+  assert!(test_subject.is_passable(&Point2DD::new(0.0, 0.0)));
+  assert!(!test_subject.is_passable(&Point2DD::new(2.0, 1.0)));
+  assert!(!test_subject.is_passable(&Point2DD::new(-1.0, 0.0)));
+  assert!(!test_subject.is_passable(&Point2DD::new(5.0, 0.0)));
+  assert!(!test_subject.is_passable(&Point2DD::new(0.0, 3.0)));
+}
+
+#[test]
+fn test_parse_ascii() {
+  let test_subject =
+    TileMap::parse_ascii(TEST_ASCII_GRID, TileMapConnectivity::Eight).unwrap();
+  assert_eq!(test_subject.width, 5);
+  assert_eq!(test_subject.height, 3);
+  assert_eq!(
+    test_subject.start_node_option,
+    Some(Point2DD::new(0.0, 0.0))
+  );
+  assert_eq!(test_subject.goal_node_option, Some(Point2DD::new(4.0, 2.0)));
+  assert_eq!(
+    test_subject
+      .passable_list
+      .iter()
+      .filter(|passable| !**passable)
+      .count(),
+    2
+  );
+  assert_eq!(
+    TileMap::parse_ascii("S.\nS.", TileMapConnectivity::Eight),
+    Err(TileMapParseError::DuplicateMarker {
+      line_number: 2,
+    })
+  );
+  assert_eq!(
+    TileMap::parse_ascii("..\n.x", TileMapConnectivity::Eight),
+    Err(TileMapParseError::UnknownTile {
+      line_number: 2,
+      tile: 'x',
+    })
+  );
+  assert_eq!(
+    TileMap::parse_ascii("..\n...", TileMapConnectivity::Eight),
+    Err(TileMapParseError::WrongRowLength {
+      line_number: 2,
+    })
+  );
+}
+
+#[test]
+fn test_parse_map() {
+  let test_subject =
+    TileMap::parse_map(TEST_MAP, TileMapConnectivity::Eight).unwrap();
+  assert_eq!(test_subject.width, 5);
+  assert_eq!(test_subject.height, 3);
+  assert!(!test_subject.is_passable(&Point2DD::new(1.0, 1.0)));
+  assert!(!test_subject.is_passable(&Point2DD::new(2.0, 1.0)));
+  assert!(test_subject.is_passable(&Point2DD::new(2.0, 2.0)));
+  assert_eq!(test_subject.start_node_option, None);
+  assert_eq!(test_subject.goal_node_option, None);
+  assert_eq!(
+    TileMap::parse_map("type octile\nheight x", TileMapConnectivity::Eight),
+    Err(TileMapParseError::InvalidNumber {
+      line_number: 2,
+    })
+  );
+  assert_eq!(
+    TileMap::parse_map("height 1\nmap\n.", TileMapConnectivity::Eight),
+    Err(TileMapParseError::InvalidHeader {
+      line_number: 2,
+    })
+  );
+  assert_eq!(
+    TileMap::parse_map("depth 1", TileMapConnectivity::Eight),
+    Err(TileMapParseError::InvalidHeader {
+      line_number: 1,
+    })
+  );
+  assert_eq!(
+    TileMap::parse_map("height 2\nwidth 1\nmap\n.", TileMapConnectivity::Eight),
+    Err(TileMapParseError::WrongRowCount {
+      line_number: 5,
+    })
+  );
+  assert_eq!(
+    TileMap::parse_map(
+      "height 1\nwidth 2\nmap\n.x",
+      TileMapConnectivity::Eight
+    ),
+    Err(TileMapParseError::UnknownTile {
+      line_number: 4,
+      tile: 'x',
+    })
+  );
+}
+
+#[test]
+fn test_parse_scenario() {
+  let scenario_list: Vec<TileMapScenario> =
+    TileMapScenario::parse(TEST_SCENARIO).unwrap();
+  assert_eq!(
+    scenario_list[0],
+    TileMapScenario {
+      bucket: 0,
+      goal_node: Point2DD::new(4.0, 0.0),
+      map_height: 3,
+      map_name: "test.map".to_string(),
+      map_width: 5,
+      optimal_length: 7.414_213_56,
+      start_node: Point2DD::new(0.0, 0.0),
+    }
+  );
+  assert_eq!(scenario_list.len(), 2);
+  assert_eq!(
+    TileMapScenario::parse("0 test.map 5 3 0 0 4 0"),
+    Err(TileMapParseError::WrongFieldCount {
+      line_number: 1,
+    })
+  );
+  assert_eq!(
+    TileMapScenario::parse("0 test.map 5 3 0 -1 4 0 5.0"),
+    Err(TileMapParseError::InvalidNumber {
+      line_number: 1,
+    })
+  );
+}
@@ -2,15 +2,24 @@
 //! - CroftSoft Core Library
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-07-30
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! # Features
 //! - No dependencies on other crates
 //! - Artificial Intelligence
 //!   - A* Algorithm
+//!   - Conflict-Based Search (CBS) for multiple agents
+//!   - Flow fields
+//!   - Hierarchical pathfinding (HPA*)
+//!   - K shortest loopless paths with Yen's algorithm
+//!   - Navigation meshes with the funnel algorithm
+//!   - Path caching for repeated queries
+//!   - Path smoothing and string pulling
+//!   - Space-time and cooperative A*
+//!   - Tile maps in the MovingAI benchmark format
 //! - Mathematics
 //!   - 3D mathematics including axis angles and quaternions
 //!   - Financial calculations
@@ -0,0 +1,147 @@
+// =============================================================================
+//! - HexCoordinate and HexLayout for the geometry module
+//! - Axial coordinates with the implied cube coordinate s = -q - r
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! # History
+//! - Based on the article "Hexagonal Grids" by Amit Patel of Red Blob Games
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use super::point_2dd::Point2DD;
+use crate::math::math_constants::SQRT_3;
+
+/// The offsets to the six adjacent hexes, counterclockwise from the east
+pub const HEX_DIRECTIONS: [HexCoordinate; 6] = [
+  HexCoordinate::new(1, 0),
+  HexCoordinate::new(1, -1),
+  HexCoordinate::new(0, -1),
+  HexCoordinate::new(-1, 0),
+  HexCoordinate::new(-1, 1),
+  HexCoordinate::new(0, 1),
+];
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HexCoordinate {
+  pub q: i64,
+  pub r: i64,
+}
+
+/// Converts between hex coordinates and the pixel centres of the hexes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexLayout {
+  pub hex_orientation: HexOrientation,
+  /// The pixel centre of the hex at the origin
+  pub origin: Point2DD,
+  /// The distance from the centre of a hex to a corner
+  pub size: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HexOrientation {
+  FlatTop,
+  PointyTop,
+}
+
+impl HexCoordinate {
+  pub fn distance_to(
+    &self,
+    other: &HexCoordinate,
+  ) -> i64 {
+    let delta_q: i64 = self.q - other.q;
+    let delta_r: i64 = self.r - other.r;
+    let delta_s: i64 = self.get_s() - other.get_s();
+    (delta_q.abs() + delta_r.abs() + delta_s.abs()) / 2
+  }
+
+  /// The adjacent hexes in the order of the HEX_DIRECTIONS
+  pub fn get_adjacent_hexes(&self) -> [HexCoordinate; 6] {
+    HEX_DIRECTIONS.map(|direction| {
+      HexCoordinate::new(self.q + direction.q, self.r + direction.r)
+    })
+  }
+
+  /// The third cube coordinate
+  pub fn get_s(&self) -> i64 {
+    -self.q - self.r
+  }
+
+  pub const fn new(
+    q: i64,
+    r: i64,
+  ) -> Self {
+    Self {
+      q,
+      r,
+    }
+  }
+
+  /// The hex which contains the fractional cube coordinates
+  pub fn round(
+    q: f64,
+    r: f64,
+  ) -> Self {
+    let s: f64 = -q - r;
+    let mut rounded_q: f64 = q.round();
+    let mut rounded_r: f64 = r.round();
+    let rounded_s: f64 = s.round();
+    let delta_q: f64 = (rounded_q - q).abs();
+    let delta_r: f64 = (rounded_r - r).abs();
+    let delta_s: f64 = (rounded_s - s).abs();
+    // Resets the coordinate with the largest rounding error
+    if delta_q > delta_r && delta_q > delta_s {
+      rounded_q = -rounded_r - rounded_s;
+    } else if delta_r > delta_s {
+      rounded_r = -rounded_q - rounded_s;
+    }
+    Self::new(rounded_q as i64, rounded_r as i64)
+  }
+}
+
+impl HexLayout {
+  /// The distance between the pixel centres of adjacent hexes
+  pub fn get_spacing(&self) -> f64 {
+    SQRT_3 * self.size
+  }
+
+  /// The hex which contains the pixel
+  pub fn to_hex_coordinate(
+    &self,
+    point_2dd: &Point2DD,
+  ) -> HexCoordinate {
+    let x: f64 = (point_2dd.x - self.origin.x) / self.size;
+    let y: f64 = (point_2dd.y - self.origin.y) / self.size;
+    match self.hex_orientation {
+      HexOrientation::FlatTop => {
+        HexCoordinate::round(2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y)
+      },
+      HexOrientation::PointyTop => {
+        HexCoordinate::round(SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y)
+      },
+    }
+  }
+
+  /// The pixel centre of the hex
+  pub fn to_point_2dd(
+    &self,
+    hex_coordinate: &HexCoordinate,
+  ) -> Point2DD {
+    let q: f64 = hex_coordinate.q as f64;
+    let r: f64 = hex_coordinate.r as f64;
+    let (x, y) = match self.hex_orientation {
+      HexOrientation::FlatTop => (1.5 * q, SQRT_3 / 2.0 * q + SQRT_3 * r),
+      HexOrientation::PointyTop => (SQRT_3 * q + SQRT_3 / 2.0 * r, 1.5 * r),
+    };
+    Point2DD::new(self.origin.x + x * self.size, self.origin.y + y * self.size)
+  }
+}
@@ -0,0 +1,123 @@
+// =============================================================================
+//! - Unit tests for HexCoordinate and HexLayout
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+const TEST_ORIGIN: Point2DD = Point2DD {
+  x: 100.0,
+  y: 50.0,
+};
+#[cfg(test)]
+const TEST_SIZE: f64 = 10.0;
+#[cfg(test)]
+const TEST_TOLERANCE: f64 = 0.001;
+
+#[cfg(test)]
+fn make_test_layout(hex_orientation: HexOrientation) -> HexLayout {
+  HexLayout {
+    hex_orientation,
+    origin: TEST_ORIGIN,
+    size: TEST_SIZE,
+  }
+}
+
+#[test]
+fn test_distance_to() {
+  let hex_coordinate = HexCoordinate::new(1, -3);
+  assert_eq!(hex_coordinate.distance_to(&hex_coordinate), 0);
+  assert_eq!(hex_coordinate.distance_to(&HexCoordinate::new(4, -3)), 3);
+  assert_eq!(hex_coordinate.distance_to(&HexCoordinate::new(-2, 0)), 3);
+  assert_eq!(hex_coordinate.distance_to(&HexCoordinate::new(3, 0)), 5);
+  assert_eq!(HexCoordinate::new(3, 0).distance_to(&hex_coordinate), 5);
+}
+
+#[test]
+fn test_get_adjacent_hexes() {
+  let hex_coordinate = HexCoordinate::new(2, -1);
+  let adjacent_hexes = hex_coordinate.get_adjacent_hexes();
+  assert_eq!(adjacent_hexes[0], HexCoordinate::new(3, -1));
+  assert_eq!(adjacent_hexes[5], HexCoordinate::new(2, 0));
+  for adjacent_hex in adjacent_hexes.iter() {
+    assert_eq!(hex_coordinate.distance_to(adjacent_hex), 1);
+  }
+}
+
+#[test]
+fn test_get_s() {
+  assert_eq!(HexCoordinate::new(2, -5).get_s(), 3);
+}
+
+#[test]
+fn test_round() {
+  assert_eq!(HexCoordinate::round(0.1, -0.1), HexCoordinate::new(0, 0));
+  assert_eq!(HexCoordinate::round(1.9, -1.2), HexCoordinate::new(2, -1));
+  // Rounding each coordinate separately would give q + r + s = -1
+  assert_eq!(HexCoordinate::round(0.4, 0.4), HexCoordinate::new(0, 1));
+}
+
+#[test]
+fn test_get_spacing() {
+  for hex_orientation in [
+    HexOrientation::FlatTop,
+    HexOrientation::PointyTop,
+  ] {
+    let test_layout = make_test_layout(hex_orientation);
+    let center = test_layout.to_point_2dd(&HexCoordinate::default());
+    for adjacent_hex in HexCoordinate::default().get_adjacent_hexes() {
+      let distance =
+        center.distance_to(&test_layout.to_point_2dd(&adjacent_hex));
+      assert!((distance - test_layout.get_spacing()).abs() < TEST_TOLERANCE);
+    }
+  }
+}
+
+#[test]
+fn test_to_hex_coordinate() {
+  for hex_orientation in [
+    HexOrientation::FlatTop,
+    HexOrientation::PointyTop,
+  ] {
+    let test_layout = make_test_layout(hex_orientation);
+    for q in -3..=3 {
+      for r in -3..=3 {
+        let hex_coordinate = HexCoordinate::new(q, r);
+        let center = test_layout.to_point_2dd(&hex_coordinate);
+        assert_eq!(test_layout.to_hex_coordinate(&center), hex_coordinate);
+        // A pixel near the centre is within the same hex
+        let offset_point = Point2DD::new(center.x + 3.0, center.y - 3.0);
+        assert_eq!(
+          test_layout.to_hex_coordinate(&offset_point),
+          hex_coordinate
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn test_to_point_2dd() {
+  let flat_top_layout = make_test_layout(HexOrientation::FlatTop);
+  assert_eq!(
+    flat_top_layout.to_point_2dd(&HexCoordinate::default()),
+    TEST_ORIGIN
+  );
+  let point_2dd = flat_top_layout.to_point_2dd(&HexCoordinate::new(2, 0));
+  assert!((point_2dd.x - 130.0).abs() < TEST_TOLERANCE);
+  assert!((point_2dd.y - (50.0 + SQRT_3 * 10.0)).abs() < TEST_TOLERANCE);
+  let pointy_top_layout = make_test_layout(HexOrientation::PointyTop);
+  let point_2dd = pointy_top_layout.to_point_2dd(&HexCoordinate::new(0, 2));
+  assert!((point_2dd.x - (100.0 + SQRT_3 * 10.0)).abs() < TEST_TOLERANCE);
+  assert!((point_2dd.y - 80.0).abs() < TEST_TOLERANCE);
+}
@@ -2,10 +2,10 @@
 //! - Geometry
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-10-23
-//! - Updated: 2023-07-26
+//! - Updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the Java package com.croftsoft.core.math.geom
@@ -17,6 +17,9 @@
 // =============================================================================
 
 pub mod circle;
+pub mod hex_coordinate;
 pub mod point_2dd;
+pub mod point_3dd;
 pub mod point_xy;
+pub mod point_xyz;
 pub mod rectangle;
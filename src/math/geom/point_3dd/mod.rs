@@ -0,0 +1,139 @@
+// =============================================================================
+//! - Point3DD for the geometry module
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+mod test;
+
+use super::point_xyz::PointXYZ;
+use core::fmt::Display;
+use core::hash::Hash;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Point3DD {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64,
+}
+
+impl Point3DD {
+  pub fn distance_to(
+    &self,
+    other: &Point3DD,
+  ) -> f64 {
+    ((other.x - self.x).powi(2)
+      + (other.y - self.y).powi(2)
+      + (other.z - self.z).powi(2))
+    .sqrt()
+  }
+
+  pub fn is_near(
+    &self,
+    other: &Point3DD,
+    tolerance: f64,
+  ) -> bool {
+    self.distance_to(other) <= tolerance
+  }
+
+  pub fn new(
+    x: f64,
+    y: f64,
+    z: f64,
+  ) -> Point3DD {
+    Point3DD {
+      x,
+      y,
+      z,
+    }
+  }
+
+  pub fn set_xyz(
+    &mut self,
+    x: f64,
+    y: f64,
+    z: f64,
+  ) {
+    self.x = x;
+    self.y = y;
+    self.z = z;
+  }
+}
+
+impl Display for Point3DD {
+  fn fmt(
+    &self,
+    f: &mut std::fmt::Formatter<'_>,
+  ) -> std::fmt::Result {
+    write!(f, "({}, {}, {})", self.x, self.y, self.z)
+  }
+}
+
+impl Eq for Point3DD {}
+
+impl Hash for Point3DD {
+  fn hash<H: std::hash::Hasher>(
+    &self,
+    state: &mut H,
+  ) {
+    self.x.to_bits().hash(state);
+    self.y.to_bits().hash(state);
+    self.z.to_bits().hash(state);
+  }
+}
+
+impl PartialEq for Point3DD {
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool {
+    self.x.to_bits() == other.x.to_bits()
+      && self.y.to_bits() == other.y.to_bits()
+      && self.z.to_bits() == other.z.to_bits()
+  }
+}
+
+impl PointXYZ for Point3DD {
+  fn distance(
+    &self,
+    other_x: f64,
+    other_y: f64,
+    other_z: f64,
+  ) -> f64 {
+    ((self.x - other_x).powi(2)
+      + (self.y - other_y).powi(2)
+      + (self.z - other_z).powi(2))
+    .sqrt()
+  }
+
+  fn distance_xyz<P: PointXYZ>(
+    &self,
+    other_pointxyz: &P,
+  ) -> f64 {
+    self.distance(
+      other_pointxyz.get_x(),
+      other_pointxyz.get_y(),
+      other_pointxyz.get_z(),
+    )
+  }
+
+  fn get_x(&self) -> f64 {
+    self.x
+  }
+
+  fn get_y(&self) -> f64 {
+    self.y
+  }
+
+  fn get_z(&self) -> f64 {
+    self.z
+  }
+}
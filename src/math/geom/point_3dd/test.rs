@@ -0,0 +1,70 @@
+// =============================================================================
+//! - Unit tests for Point3DD
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+#[cfg(test)]
+use crate::math::geom::point_3dd::Point3DD;
+#[cfg(test)]
+use crate::math::geom::point_xyz::PointXYZ;
+#[cfg(test)]
+use crate::math::math_constants::SQRT_3;
+
+#[test]
+fn test_distance() {
+  assert!((Point3DD::default().distance(1.0, 1.0, 1.0) - SQRT_3).abs() < 0.001);
+}
+
+#[test]
+fn test_distance_to() {
+  assert_eq!(
+    Point3DD::default().distance_to(&Point3DD::new(2.0, 3.0, 6.0)),
+    7.0
+  );
+}
+
+#[test]
+fn test_distance_xyz() {
+  assert_eq!(
+    Point3DD::new(1.0, 1.0, 1.0).distance_xyz(&Point3DD::new(1.0, 5.0, 4.0)),
+    5.0
+  );
+}
+
+#[test]
+fn test_display() {
+  assert_eq!(Point3DD::new(1.0, -2.5, 3.0).to_string(), "(1, -2.5, 3)");
+}
+
+#[test]
+fn test_eq() {
+  assert_eq!(Point3DD::new(1.0, 2.0, 3.0), Point3DD::new(1.0, 2.0, 3.0));
+  assert_ne!(Point3DD::new(1.0, 2.0, 3.0), Point3DD::new(1.0, 2.0, 4.0));
+}
+
+#[test]
+fn test_get_z() {
+  assert_eq!(Point3DD::new(1.0, 2.0, 3.0).get_z(), 3.0);
+}
+
+#[test]
+fn test_is_near() {
+  let point_3dd = Point3DD::new(1.0, 1.0, 1.0);
+  assert!(point_3dd.is_near(&Point3DD::new(1.0, 1.0, 1.5), 0.5));
+  assert!(!point_3dd.is_near(&Point3DD::new(1.0, 1.0, 1.6), 0.5));
+}
+
+#[test]
+fn test_set_xyz() {
+  let mut point_3dd = Point3DD::default();
+  point_3dd.set_xyz(1.0, 2.0, 3.0);
+  assert_eq!(point_3dd, Point3DD::new(1.0, 2.0, 3.0));
+}
@@ -0,0 +1,35 @@
+// =============================================================================
+//! - PointXYZ for the geometry module
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+use core::hash::Hash;
+
+/// The three dimensional counterpart of PointXY
+pub trait PointXYZ: Clone + Copy + Eq + Hash {
+  fn distance(
+    &self,
+    other_x: f64,
+    other_y: f64,
+    other_z: f64,
+  ) -> f64;
+
+  fn distance_xyz<P: PointXYZ>(
+    &self,
+    other_pointxyz: &P,
+  ) -> f64;
+
+  fn get_x(&self) -> f64;
+
+  fn get_y(&self) -> f64;
+
+  fn get_z(&self) -> f64;
+}
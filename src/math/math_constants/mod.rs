@@ -2,11 +2,11 @@
 //! - A collection of math constants
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java updated: 2002-01-27
 //! - Rust created: 2022-07-30
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the Java class com.croftsoft.core.math.MathConstants
@@ -21,6 +21,8 @@ use core::f64::consts::TAU;
 
 pub const DEGREES_PER_RADIAN: f64 = 360.0 / TAU;
 pub const GOLDEN_RATIO: f64 = 1.618_033_988_749_895;
+/// The square root of three, which is not yet a stable core constant
+pub const SQRT_3: f64 = 1.732_050_807_568_877_2;
 pub const SECONDS_PER_NANOSECOND: f64 = 0.000_000_001;
 pub const MILLISECONDS_PER_DAY: u64 = 1_000 * 60 * 60 * 24;
 pub const MILLISECONDS_PER_SECOND: u64 = 1_000;
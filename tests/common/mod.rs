@@ -0,0 +1,135 @@
+// =============================================================================
+//! - Fixtures shared by the integration tests
+//! - Random maps and tile maps which are reproducible from a seed
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+// Each integration test crate uses only some of the fixtures
+#![allow(dead_code)]
+
+use com_croftsoft_core::ai::tilemap::{TileMap, TileMapConnectivity};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::collections::HashSet;
+
+pub const RANDOM_SEED: u64 = 2026;
+
+/// Linear congruential generator so that the maps are reproducible
+pub fn next_random(seed: &mut u64) -> u64 {
+  *seed = seed
+    .wrapping_mul(6_364_136_223_846_793_005)
+    .wrapping_add(1_442_695_040_888_963_407);
+  *seed >> 33
+}
+
+/// True if the node is on the square map
+pub fn is_in_bounds(
+  map_size: usize,
+  node: &Point2DD,
+) -> bool {
+  node.x >= 0.0
+    && node.y >= 0.0
+    && node.x < map_size as f64
+    && node.y < map_size as f64
+}
+
+/// The blocked cells of a random square map
+pub fn make_blocked_set(
+  seed: &mut u64,
+  map_size: usize,
+  obstacle_percent: u64,
+) -> HashSet<Point2DD> {
+  let mut blocked_set = HashSet::new();
+  for x in 0..map_size {
+    for y in 0..map_size {
+      if next_random(seed) % 100 < obstacle_percent {
+        blocked_set.insert(Point2DD::new(x as f64, y as f64));
+      }
+    }
+  }
+  blocked_set
+}
+
+/// A random cell of the square map which is not blocked
+pub fn make_free_node(
+  seed: &mut u64,
+  map_size: usize,
+  blocked_set: &HashSet<Point2DD>,
+) -> Point2DD {
+  loop {
+    let node = Point2DD::new(
+      (next_random(seed) % map_size as u64) as f64,
+      (next_random(seed) % map_size as u64) as f64,
+    );
+    if !blocked_set.contains(&node) {
+      return node;
+    }
+  }
+}
+
+/// A free cell of the square map which is not in the used set
+pub fn make_unused_node(
+  seed: &mut u64,
+  map_size: usize,
+  blocked_set: &HashSet<Point2DD>,
+  used_set: &mut HashSet<Point2DD>,
+) -> Point2DD {
+  loop {
+    let node = make_free_node(seed, map_size, blocked_set);
+    if used_set.insert(node) {
+      return node;
+    }
+  }
+}
+
+/// A random passable cell of the tile map
+pub fn make_passable_node(
+  seed: &mut u64,
+  tile_map: &TileMap,
+) -> Point2DD {
+  loop {
+    let node = Point2DD::new(
+      (next_random(seed) as usize % tile_map.width) as f64,
+      (next_random(seed) as usize % tile_map.height) as f64,
+    );
+    if tile_map.is_passable(&node) {
+      return node;
+    }
+  }
+}
+
+/// A square tile map parsed from a random ASCII grid of walls and floors
+pub fn make_random_tile_map(
+  seed: &mut u64,
+  map_size: usize,
+  obstacle_percent: u64,
+) -> TileMap {
+  let mut ascii = String::new();
+  for _ in 0..map_size {
+    for _ in 0..map_size {
+      ascii.push(if next_random(seed) % 100 < obstacle_percent {
+        '#'
+      } else {
+        '.'
+      });
+    }
+    ascii.push('\n');
+  }
+  TileMap::parse_ascii(&ascii, TileMapConnectivity::Eight).unwrap()
+}
+
+/// Toggles whether the cell of the node is passable
+pub fn toggle_passable(
+  tile_map: &mut TileMap,
+  node: &Point2DD,
+) {
+  let cell_index: usize = node.y as usize * tile_map.width + node.x as usize;
+  tile_map.passable_list[cell_index] = !tile_map.passable_list[cell_index];
+}
@@ -0,0 +1,101 @@
+// =============================================================================
+//! - A* algorithm multiple goal integration tests
+//! - Searches a random tile map for the nearest of several goals
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GoalSetCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::tilemap::TileMap;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, make_passable_node, make_random_tile_map};
+use core::f64::consts::SQRT_2;
+
+const GOAL_COUNT: usize = 4;
+const GOAL_RADIUS: f64 = 2.0;
+/// The octile distance is at most the square root of two times the distance
+const GOAL_RADIUS_COST: f64 = GOAL_RADIUS * SQRT_2;
+const MAP_SIZE: usize = 24;
+const OBSTACLE_PERCENT: u64 = 20;
+const SEARCH_COUNT: usize = 10;
+const TOLERANCE: f64 = 0.000_001;
+
+fn search(
+  start_node: Point2DD,
+  goal_set_cartographer: &GoalSetCartographer<Point2DD>,
+) -> Option<(f64, usize)> {
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    ..
+  } = astar.search(start_node, goal_set_cartographer, &SearchBudget::default())
+  else {
+    return None;
+  };
+  Some((cost, goal_set_cartographer.get_reached_goal_index(&astar)?))
+}
+
+#[test]
+fn test_ai_astar_goals_random_tile_map() {
+  let mut seed = RANDOM_SEED;
+  // Without a goal marker the tile map itself has no goal
+  let tile_map: TileMap =
+    make_random_tile_map(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let mut found_count: usize = 0;
+  for _ in 0..SEARCH_COUNT {
+    let start_node: Point2DD = make_passable_node(&mut seed, &tile_map);
+    let goal_nodes: Vec<Point2DD> = (0..GOAL_COUNT)
+      .map(|_| make_passable_node(&mut seed, &tile_map))
+      .collect();
+    let length_list: Vec<Option<f64>> = goal_nodes
+      .iter()
+      .map(|goal_node| tile_map.find_path_length(&start_node, goal_node))
+      .collect();
+    let expected_length_option: Option<f64> = length_list
+      .iter()
+      .flatten()
+      .copied()
+      .min_by(|length_0, length_1| length_0.total_cmp(length_1));
+    let goal_set_cartographer = GoalSetCartographer {
+      cartographer: &tile_map,
+      goal_nodes: goal_nodes.clone(),
+      goal_radius: 0.0,
+      goal_radius_cost: 0.0,
+    };
+    let search_result_option: Option<(f64, usize)> =
+      search(start_node, &goal_set_cartographer);
+    let Some(expected_length) = expected_length_option else {
+      assert_eq!(search_result_option, None);
+      continue;
+    };
+    found_count += 1;
+    let (cost, goal_index) = search_result_option.unwrap();
+    assert!((cost - expected_length).abs() < TOLERANCE);
+    // The reached goal is one of the nearest goals
+    assert!(
+      (length_list[goal_index].unwrap() - expected_length).abs() < TOLERANCE
+    );
+    // Arriving within a radius of a goal is never more expensive
+    let goal_region_cartographer = GoalSetCartographer {
+      cartographer: &tile_map,
+      goal_nodes,
+      goal_radius: GOAL_RADIUS,
+      goal_radius_cost: GOAL_RADIUS_COST,
+    };
+    let (region_cost, _) =
+      search(start_node, &goal_region_cartographer).unwrap();
+    assert!(region_cost <= cost + TOLERANCE);
+  }
+  assert!(found_count > 0);
+}
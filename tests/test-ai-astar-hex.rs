@@ -0,0 +1,211 @@
+// =============================================================================
+//! - A* algorithm integration tests using a hexagonal grid
+//! - The costs are compared against a breadth-first search
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, HexCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::math::geom::hex_coordinate::{
+  HexCoordinate, HexLayout, HexOrientation,
+};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, next_random};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+const MAP_RADIUS: i64 = 12;
+const OBSTACLE_PERCENT: u64 = 25;
+const SEARCH_COUNT: usize = 30;
+const TOLERANCE: f64 = 0.001;
+
+fn is_in_bounds(hex_coordinate: &HexCoordinate) -> bool {
+  hex_coordinate.distance_to(&HexCoordinate::default()) <= MAP_RADIUS
+}
+
+fn make_blocked_set(seed: &mut u64) -> HashSet<HexCoordinate> {
+  let mut blocked_set = HashSet::new();
+  for q in -MAP_RADIUS..=MAP_RADIUS {
+    for r in -MAP_RADIUS..=MAP_RADIUS {
+      let hex_coordinate = HexCoordinate::new(q, r);
+      if is_in_bounds(&hex_coordinate)
+        && next_random(seed) % 100 < OBSTACLE_PERCENT
+      {
+        blocked_set.insert(hex_coordinate);
+      }
+    }
+  }
+  blocked_set
+}
+
+fn make_free_hex(
+  seed: &mut u64,
+  blocked_set: &HashSet<HexCoordinate>,
+) -> HexCoordinate {
+  let range = (2 * MAP_RADIUS + 1) as u64;
+  loop {
+    let hex_coordinate = HexCoordinate::new(
+      (next_random(seed) % range) as i64 - MAP_RADIUS,
+      (next_random(seed) % range) as i64 - MAP_RADIUS,
+    );
+    if is_in_bounds(&hex_coordinate) && !blocked_set.contains(&hex_coordinate) {
+      return hex_coordinate;
+    }
+  }
+}
+
+/// The number of steps from the start to each reachable hex
+fn search_breadth_first(
+  start_hex: HexCoordinate,
+  blocked_set: &HashSet<HexCoordinate>,
+) -> HashMap<HexCoordinate, usize> {
+  let mut hex_to_steps_map = HashMap::from([(start_hex, 0)]);
+  let mut open_hex_queue = VecDeque::from([start_hex]);
+  while let Some(hex_coordinate) = open_hex_queue.pop_front() {
+    let steps: usize = hex_to_steps_map[&hex_coordinate];
+    for adjacent_hex in hex_coordinate.get_adjacent_hexes() {
+      if is_in_bounds(&adjacent_hex)
+        && !blocked_set.contains(&adjacent_hex)
+        && !hex_to_steps_map.contains_key(&adjacent_hex)
+      {
+        hex_to_steps_map.insert(adjacent_hex, steps + 1);
+        open_hex_queue.push_back(adjacent_hex);
+      }
+    }
+  }
+  hex_to_steps_map
+}
+
+#[test]
+fn test_ai_astar_hex_corridor() {
+  // A winding corridor of open hexes with no shortcut between its turns
+  let corridor: [HexCoordinate; 6] = [
+    HexCoordinate::new(0, 0),
+    HexCoordinate::new(1, 0),
+    HexCoordinate::new(2, 0),
+    HexCoordinate::new(2, 1),
+    HexCoordinate::new(2, 2),
+    HexCoordinate::new(3, 2),
+  ];
+  let hex_cartographer = HexCartographer {
+    goal_node: corridor[5],
+    is_space_available_fn: |hex_coordinate: &HexCoordinate| {
+      corridor.contains(hex_coordinate)
+    },
+  };
+  let mut astar = AStar::<HexCoordinate>::default();
+  assert_eq!(
+    astar.search(corridor[0], &hex_cartographer, &SearchBudget::default()),
+    SearchOutcome::GoalFound {
+      cost: 5.0,
+      path: VecDeque::from(corridor[1..].to_vec()),
+    }
+  );
+}
+
+#[test]
+fn test_ai_astar_hex_random_map() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed);
+  let mut found_count = 0;
+  for _ in 0..SEARCH_COUNT {
+    let start_hex = make_free_hex(&mut seed, &blocked_set);
+    let goal_hex = make_free_hex(&mut seed, &blocked_set);
+    let hex_cartographer = HexCartographer {
+      goal_node: goal_hex,
+      is_space_available_fn: |hex_coordinate: &HexCoordinate| {
+        is_in_bounds(hex_coordinate) && !blocked_set.contains(hex_coordinate)
+      },
+    };
+    let mut astar = AStar::<HexCoordinate>::default();
+    let search_outcome =
+      astar.search(start_hex, &hex_cartographer, &SearchBudget::default());
+    let expected_steps_option: Option<usize> =
+      search_breadth_first(start_hex, &blocked_set)
+        .get(&goal_hex)
+        .copied();
+    let Some(expected_steps) = expected_steps_option else {
+      assert_eq!(search_outcome, SearchOutcome::Unreachable);
+      continue;
+    };
+    let SearchOutcome::GoalFound {
+      cost,
+      path,
+    } = search_outcome
+    else {
+      panic!("unexpected search outcome {search_outcome:?}");
+    };
+    assert_eq!(cost, expected_steps as f64);
+    assert_eq!(path.len(), expected_steps);
+    let mut previous_hex = start_hex;
+    for hex_coordinate in path.iter() {
+      assert_eq!(previous_hex.distance_to(hex_coordinate), 1);
+      assert!(!blocked_set.contains(hex_coordinate));
+      previous_hex = *hex_coordinate;
+    }
+    assert_eq!(previous_hex, goal_hex);
+    found_count += 1;
+  }
+  assert_ne!(found_count, 0);
+}
+
+#[test]
+fn test_ai_astar_hex_pixel_centres() {
+  let blocked_set: HashSet<HexCoordinate> = [
+    HexCoordinate::new(1, -1),
+    HexCoordinate::new(1, 0),
+    HexCoordinate::new(0, 1),
+  ]
+  .into_iter()
+  .collect();
+  let start_hex = HexCoordinate::default();
+  let goal_hex = HexCoordinate::new(3, 0);
+  let hex_cartographer = HexCartographer {
+    goal_node: goal_hex,
+    is_space_available_fn: |hex_coordinate: &HexCoordinate| {
+      is_in_bounds(hex_coordinate) && !blocked_set.contains(hex_coordinate)
+    },
+  };
+  let mut astar = AStar::<HexCoordinate>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = astar.search(start_hex, &hex_cartographer, &SearchBudget::default())
+  else {
+    panic!("goal not found");
+  };
+  // The wall around the start adds three steps to the hex distance of three
+  assert_eq!(cost, 6.0);
+  for hex_orientation in [
+    HexOrientation::FlatTop,
+    HexOrientation::PointyTop,
+  ] {
+    let hex_layout = HexLayout {
+      hex_orientation,
+      origin: Point2DD::new(320.0, 240.0),
+      size: 16.0,
+    };
+    let mut previous_point = hex_layout.to_point_2dd(&start_hex);
+    for hex_coordinate in path.iter() {
+      let point = hex_layout.to_point_2dd(hex_coordinate);
+      assert!(
+        (previous_point.distance_to(&point) - hex_layout.get_spacing()).abs()
+          < TOLERANCE
+      );
+      assert_eq!(hex_layout.to_hex_coordinate(&point), *hex_coordinate);
+      previous_point = point;
+    }
+  }
+}
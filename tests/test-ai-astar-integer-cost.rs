@@ -0,0 +1,249 @@
+// =============================================================================
+//! - A* algorithm integer cost integration tests
+//! - Searches a random map of terrain move points with exact integer costs
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, SearchBudget, SearchOutcome, SearchStatistics,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use common::{RANDOM_SEED, next_random};
+use std::collections::VecDeque;
+
+type Point = (i64, i64);
+
+const MAP_SIZE: i64 = 32;
+/// The move points of a terrain cell are from one to this
+const MAX_MOVE_POINTS: u32 = 4;
+const SEARCH_COUNT: usize = 20;
+
+/// Four-connected grid where entering a cell costs its move points
+pub struct MovePointCartographer {
+  pub goal_point: Point,
+  pub move_points_grid: Vec<Vec<u32>>,
+}
+
+impl Cartographer<Point, u32> for MovePointCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Point,
+  ) -> u32 {
+    ((node.0 - self.goal_point.0).abs() + (node.1 - self.goal_point.1).abs())
+      as u32
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point,
+  ) -> Vec<Point> {
+    [
+      (node.0 + 1, node.1),
+      (node.0, node.1 + 1),
+      (node.0 - 1, node.1),
+      (node.0, node.1 - 1),
+    ]
+    .into_iter()
+    .filter(|point| {
+      point.0 >= 0 && point.1 >= 0 && point.0 < MAP_SIZE && point.1 < MAP_SIZE
+    })
+    .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &Point,
+    to_node: &Point,
+  ) -> u32 {
+    self.move_points_grid[to_node.1 as usize][to_node.0 as usize]
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Point,
+  ) -> bool {
+    *node == self.goal_point
+  }
+}
+
+/// Wraps a cartographer with no goal and an infinite estimate everywhere
+pub struct NoGoalCartographer<'a> {
+  pub cartographer: &'a MovePointCartographer,
+}
+
+impl Cartographer<Point, u32> for NoGoalCartographer<'_> {
+  fn estimate_cost_to_goal(
+    &self,
+    _node: &Point,
+  ) -> u32 {
+    u32::MAX
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point,
+  ) -> Vec<Point> {
+    self.cartographer.get_adjacent_nodes(node)
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &Point,
+    to_node: &Point,
+  ) -> u32 {
+    self
+      .cartographer
+      .get_cost_to_adjacent_node(from_node, to_node)
+  }
+
+  fn is_goal_node(
+    &self,
+    _node: &Point,
+  ) -> bool {
+    false
+  }
+}
+
+fn make_point(seed: &mut u64) -> Point {
+  (
+    (next_random(seed) % MAP_SIZE as u64) as i64,
+    (next_random(seed) % MAP_SIZE as u64) as i64,
+  )
+}
+
+/// The cheapest cost from the start to every cell by relaxing the edges until
+/// nothing changes, independent of the A* implementation
+fn make_cost_grid(
+  cartographer: &MovePointCartographer,
+  start_point: Point,
+) -> Vec<Vec<u32>> {
+  let mut cost_grid =
+    vec![vec![u32::MAX; MAP_SIZE as usize]; MAP_SIZE as usize];
+  cost_grid[start_point.1 as usize][start_point.0 as usize] = 0;
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for y in 0..MAP_SIZE {
+      for x in 0..MAP_SIZE {
+        let cost = cost_grid[y as usize][x as usize];
+        if cost == u32::MAX {
+          continue;
+        }
+        for adjacent in cartographer.get_adjacent_nodes(&(x, y)) {
+          let adjacent_cost =
+            cost + cartographer.get_cost_to_adjacent_node(&(x, y), &adjacent);
+          if adjacent_cost < cost_grid[adjacent.1 as usize][adjacent.0 as usize]
+          {
+            cost_grid[adjacent.1 as usize][adjacent.0 as usize] = adjacent_cost;
+            changed = true;
+          }
+        }
+      }
+    }
+  }
+  cost_grid
+}
+
+fn search(
+  start_point: Point,
+  cartographer: &MovePointCartographer,
+) -> (u32, VecDeque<Point>) {
+  let mut astar = AStar::<Point, u32>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = astar.search(start_point, cartographer, &SearchBudget::default())
+  else {
+    panic!("no path");
+  };
+  (cost, path)
+}
+
+#[test]
+fn test_ai_astar_integer_cost_no_goal() {
+  let cartographer = MovePointCartographer {
+    goal_point: (0, 0),
+    move_points_grid: vec![vec![1; MAP_SIZE as usize]; MAP_SIZE as usize],
+  };
+  let mut astar = AStar::<Point, u32>::default();
+  // The infinite estimates saturate instead of overflowing the total costs
+  assert_eq!(
+    astar.search(
+      (0, 0),
+      &NoGoalCartographer {
+        cartographer: &cartographer,
+      },
+      &SearchBudget::default()
+    ),
+    SearchOutcome::Unreachable
+  );
+}
+
+#[test]
+fn test_ai_astar_integer_cost_random_terrain() {
+  let mut seed = RANDOM_SEED;
+  let move_points_grid: Vec<Vec<u32>> = (0..MAP_SIZE)
+    .map(|_| {
+      (0..MAP_SIZE)
+        .map(|_| 1 + (next_random(&mut seed) % MAX_MOVE_POINTS as u64) as u32)
+        .collect()
+    })
+    .collect();
+  for _ in 0..SEARCH_COUNT {
+    let start_point = make_point(&mut seed);
+    let cartographer = MovePointCartographer {
+      goal_point: make_point(&mut seed),
+      move_points_grid: move_points_grid.clone(),
+    };
+    let (cost, path) = search(start_point, &cartographer);
+    let expected_cost: u32 = make_cost_grid(&cartographer, start_point)
+      [cartographer.goal_point.1 as usize][cartographer.goal_point.0 as usize];
+    assert_eq!(cost, expected_cost);
+    let path_cost: u32 = core::iter::once(start_point)
+      .chain(path.iter().copied())
+      .zip(path.iter())
+      .map(|(from_point, to_point)| {
+        cartographer.get_cost_to_adjacent_node(&from_point, to_point)
+      })
+      .sum();
+    assert_eq!(path_cost, cost);
+    // The same search always breaks the ties the same way
+    assert_eq!(search(start_point, &cartographer), (cost, path));
+  }
+}
+
+#[test]
+fn test_ai_astar_integer_cost_ties() {
+  // Every shortest path on the open grid has the same total cost
+  let goal_point: Point = (7, 7);
+  let cartographer = MovePointCartographer {
+    goal_point,
+    move_points_grid: vec![vec![1; MAP_SIZE as usize]; MAP_SIZE as usize],
+  };
+  let mut astar = AStar::<Point, u32> {
+    statistics_option: Some(SearchStatistics::default()),
+    ..Default::default()
+  };
+  let mut expected_path: VecDeque<Point> = (1..=7).map(|x| (x, 0)).collect();
+  expected_path.extend((1..=7).map(|y| (7, y)));
+  assert_eq!(
+    astar.search((0, 0), &cartographer, &SearchBudget::default()),
+    SearchOutcome::GoalFound {
+      cost: 14,
+      path: expected_path,
+    }
+  );
+  // The ties go to the lower heuristic so only the path nodes are expanded
+  // instead of every node with the same total cost
+  assert_eq!(astar.statistics_option.unwrap().nodes_expanded, 15);
+}
@@ -0,0 +1,307 @@
+// =============================================================================
+//! - A* algorithm open list benchmark integration tests
+//! - Compares the paths against a copy of the original sorted list version
+//! - The paths are compared by cost since the open lists may break ties
+//!   between nodes with the same total cost differently
+//! - Run with --nocapture to see the elapsed times
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{AStar, NodeInfo};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use common::{RANDOM_SEED, next_random};
+use core::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+type Point = (i64, i64);
+
+const MAP_SIZE: i64 = 64;
+const OBSTACLE_PERCENT: u64 = 25;
+const SEARCH_COUNT: usize = 20;
+const TOLERANCE: f64 = 0.000_001;
+
+pub struct BenchmarkCartographer {
+  pub blocked_set: HashSet<Point>,
+  pub goal_point: Point,
+}
+
+impl Cartographer<Point> for BenchmarkCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Point,
+  ) -> f64 {
+    self.get_cost_to_adjacent_node(node, &self.goal_point)
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point,
+  ) -> Vec<Point> {
+    let mut list = Vec::new();
+    for offset_x in -1..2 {
+      for offset_y in -1..2 {
+        if offset_x == 0 && offset_y == 0 {
+          continue;
+        }
+        let new_point: Point = (node.0 + offset_x, node.1 + offset_y);
+        if is_in_bounds(&new_point) && !self.blocked_set.contains(&new_point) {
+          list.push(new_point);
+        }
+      }
+    }
+    list
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    from_node: &Point,
+    to_node: &Point,
+  ) -> f64 {
+    let delta0 = from_node.0 - to_node.0;
+    let delta1 = from_node.1 - to_node.1;
+    ((delta0.pow(2) + delta1.pow(2)) as f64).sqrt()
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Point,
+  ) -> bool {
+    *node == self.goal_point
+  }
+}
+
+/// A verbatim copy of the original A* loop which re-sorts a VecDeque after
+/// each push
+#[derive(Default)]
+pub struct SortedListAStar {
+  pub best_node_option: Option<Point>,
+  pub best_total_cost: f64,
+  pub goal_node_option: Option<Point>,
+  pub list_empty: bool,
+  pub node_to_node_info_map: HashMap<Point, NodeInfo>,
+  pub node_to_parent_node_map: HashMap<Point, Point>,
+  pub open_node_sorted_list: VecDeque<Point>,
+}
+
+impl SortedListAStar {
+  pub fn get_path(&self) -> VecDeque<Point> {
+    let mut path_list = VecDeque::new();
+    let mut node_option: Option<Point> = self.goal_node_option;
+    if node_option.is_none() {
+      node_option = self.best_node_option;
+    }
+    if node_option.is_none() {
+      return path_list;
+    }
+    let mut node: Point = node_option.unwrap();
+    let mut parent_node_option = self.node_to_parent_node_map.get(&node);
+    while parent_node_option.is_some() {
+      path_list.push_front(node);
+      node_option = parent_node_option.copied();
+      node = node_option.unwrap();
+      parent_node_option = self.node_to_parent_node_map.get(&node);
+    }
+    path_list
+  }
+
+  pub fn is_goal_found(&self) -> bool {
+    self.goal_node_option.is_some()
+  }
+
+  pub fn loop_once(
+    &mut self,
+    cartographer: &dyn Cartographer<Point>,
+  ) -> bool {
+    let Some(node) = self.open_node_sorted_list.pop_front() else {
+      self.list_empty = true;
+      return false;
+    };
+    let node_info: NodeInfo = *self.node_to_node_info_map.get(&node).unwrap();
+    if cartographer.is_goal_node(&node) {
+      if let Some(goal_node) = self.goal_node_option {
+        let goal_node_info =
+          self.node_to_node_info_map.get(&goal_node).unwrap();
+        if goal_node_info.cost_from_start <= node_info.cost_from_start {
+          return false;
+        }
+      }
+      self.goal_node_option = Some(node);
+      return false;
+    }
+    let adjacent_nodes: Vec<Point> = cartographer.get_adjacent_nodes(&node);
+    for adjacent_node in adjacent_nodes {
+      let new_cost_from_start: f64 = node_info.cost_from_start
+        + cartographer.get_cost_to_adjacent_node(&node, &adjacent_node);
+      let adjacent_node_info_option: Option<&NodeInfo> =
+        self.node_to_node_info_map.get(&adjacent_node);
+      if let Some(adjacent_node_info) = adjacent_node_info_option {
+        if adjacent_node_info.cost_from_start <= new_cost_from_start {
+          continue;
+        }
+        // TODO: Do something better here, maybe a sorted set
+        let position_option = self
+          .open_node_sorted_list
+          .iter()
+          .position(|&node| node == adjacent_node);
+        if let Some(position) = position_option {
+          self.open_node_sorted_list.remove(position);
+        }
+      }
+      let total_cost: f64 = new_cost_from_start
+        + cartographer.estimate_cost_to_goal(&adjacent_node);
+      let adjacent_node_info = NodeInfo {
+        cost_from_start: new_cost_from_start,
+        total_cost,
+      };
+      self
+        .node_to_node_info_map
+        .insert(adjacent_node, adjacent_node_info);
+      self.open_node_sorted_list.push_back(adjacent_node);
+      self
+        .open_node_sorted_list
+        .make_contiguous()
+        .sort_by(|a, b| {
+          let node_info_a = self.node_to_node_info_map.get(a).unwrap();
+          let node_info_b = self.node_to_node_info_map.get(b).unwrap();
+          compare_node_info(node_info_a, node_info_b)
+        });
+      self.node_to_parent_node_map.insert(adjacent_node, node);
+      if total_cost < self.best_total_cost {
+        self.best_node_option = Some(adjacent_node);
+        self.best_total_cost = total_cost;
+      }
+    }
+    true
+  }
+
+  pub fn reset(
+    &mut self,
+    start_node: Point,
+  ) {
+    self.goal_node_option = None;
+    self.list_empty = false;
+    self.open_node_sorted_list = VecDeque::new();
+    self.node_to_node_info_map = HashMap::new();
+    self.node_to_parent_node_map = HashMap::new();
+    let start_node_info = NodeInfo::default();
+    self
+      .node_to_node_info_map
+      .insert(start_node, start_node_info);
+    self.open_node_sorted_list.push_front(start_node);
+    self.best_total_cost = f64::INFINITY;
+  }
+}
+
+/// A verbatim copy of the original Ord for NodeInfo which compares the total
+/// costs only
+fn compare_node_info(
+  node_info_a: &NodeInfo,
+  node_info_b: &NodeInfo,
+) -> Ordering {
+  if node_info_a.total_cost < node_info_b.total_cost {
+    return Ordering::Less;
+  } else if node_info_a.total_cost > node_info_b.total_cost {
+    return Ordering::Greater;
+  }
+  Ordering::Equal
+}
+
+fn get_path_cost(
+  cartographer: &dyn Cartographer<Point>,
+  start_point: Point,
+  path: &VecDeque<Point>,
+) -> f64 {
+  let mut cost: f64 = 0.0;
+  let mut from_point: Point = start_point;
+  for to_point in path {
+    cost += cartographer.get_cost_to_adjacent_node(&from_point, to_point);
+    from_point = *to_point;
+  }
+  cost
+}
+
+fn is_in_bounds(point: &Point) -> bool {
+  point.0 >= 0 && point.1 >= 0 && point.0 < MAP_SIZE && point.1 < MAP_SIZE
+}
+
+fn make_blocked_set(seed: &mut u64) -> HashSet<Point> {
+  let mut blocked_set = HashSet::new();
+  for x in 0..MAP_SIZE {
+    for y in 0..MAP_SIZE {
+      if next_random(seed) % 100 < OBSTACLE_PERCENT {
+        blocked_set.insert((x, y));
+      }
+    }
+  }
+  blocked_set
+}
+
+fn make_free_point(
+  seed: &mut u64,
+  blocked_set: &HashSet<Point>,
+) -> Point {
+  loop {
+    let point: Point = (
+      (next_random(seed) % MAP_SIZE as u64) as i64,
+      (next_random(seed) % MAP_SIZE as u64) as i64,
+    );
+    if !blocked_set.contains(&point) {
+      return point;
+    }
+  }
+}
+
+#[test]
+fn test_ai_astar_open_list_benchmark() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed);
+  let mut heap_duration = Duration::ZERO;
+  let mut sorted_list_duration = Duration::ZERO;
+  let mut goal_found_count = 0;
+  for _ in 0..SEARCH_COUNT {
+    let start_point = make_free_point(&mut seed, &blocked_set);
+    let goal_point = make_free_point(&mut seed, &blocked_set);
+    let cartographer = BenchmarkCartographer {
+      blocked_set: blocked_set.clone(),
+      goal_point,
+    };
+    let instant = Instant::now();
+    let mut astar = AStar::<Point>::default();
+    astar.reset(start_point);
+    while astar.loop_once(&cartographer) {}
+    heap_duration += instant.elapsed();
+    let instant = Instant::now();
+    let mut sorted_list_astar = SortedListAStar::default();
+    sorted_list_astar.reset(start_point);
+    while sorted_list_astar.loop_once(&cartographer) {}
+    sorted_list_duration += instant.elapsed();
+    assert_eq!(astar.is_goal_found(), sorted_list_astar.is_goal_found());
+    if astar.is_goal_found() {
+      goal_found_count += 1;
+      let path_cost: f64 =
+        get_path_cost(&cartographer, start_point, &astar.get_path());
+      let sorted_list_path_cost: f64 = get_path_cost(
+        &cartographer,
+        start_point,
+        &sorted_list_astar.get_path(),
+      );
+      assert!((path_cost - sorted_list_path_cost).abs() < TOLERANCE);
+    }
+  }
+  assert_ne!(goal_found_count, 0);
+  println!("binary heap open list: {heap_duration:?}");
+  println!("sorted list open list: {sorted_list_duration:?}");
+}
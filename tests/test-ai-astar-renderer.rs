@@ -0,0 +1,114 @@
+// =============================================================================
+//! - A* search instrumentation integration tests
+//! - Renders a search of a random grid as ASCII art and as an SVG file
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::constants::DEFAULT_GRID_SEARCH_CELL_SIZE;
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GridCartographer, GridSearchCell, GridSearchRenderer, SearchBudget,
+  SearchEvent, SearchStatistics,
+};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, is_in_bounds, make_blocked_set};
+use std::collections::HashSet;
+
+const MAP_SIZE: usize = 20;
+const OBSTACLE_PERCENT: u64 = 20;
+
+#[test]
+fn test_ai_astar_renderer_random_grid() {
+  let mut seed = RANDOM_SEED;
+  let mut blocked_set: HashSet<Point2DD> =
+    make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let start_node = Point2DD::new(0.0, 0.0);
+  let goal_node = Point2DD::new((MAP_SIZE - 1) as f64, (MAP_SIZE - 1) as f64);
+  blocked_set.remove(&start_node);
+  blocked_set.remove(&goal_node);
+  let is_space_available = |node: &Point2DD| {
+    is_in_bounds(MAP_SIZE, node) && !blocked_set.contains(node)
+  };
+  let grid_cartographer = GridCartographer {
+    goal_node,
+    is_space_available_fn: is_space_available,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut astar = AStar::<Point2DD> {
+    statistics_option: Some(SearchStatistics::default()),
+    ..Default::default()
+  };
+  let mut expand_count: usize = 0;
+  let mut pushed_node_set: HashSet<Point2DD> = HashSet::new();
+  astar.search_observed(
+    start_node,
+    &grid_cartographer,
+    &SearchBudget::default(),
+    &mut |search_event| match search_event {
+      SearchEvent::Expand {
+        ..
+      } => expand_count += 1,
+      SearchEvent::Push {
+        node,
+        ..
+      } => {
+        pushed_node_set.insert(*node);
+      },
+    },
+  );
+  assert!(astar.is_goal_found());
+  let statistics: SearchStatistics = astar.statistics_option.unwrap();
+  assert_eq!(statistics.nodes_expanded, expand_count);
+  assert!(statistics.nodes_generated >= pushed_node_set.len() - 1);
+  assert!(statistics.peak_open_list_size <= astar.open_node_sequence as usize);
+  // Every expanded node was pushed first
+  assert!(
+    astar
+      .closed_node_set
+      .iter()
+      .all(|node| pushed_node_set.contains(node))
+  );
+  let grid_search_renderer = GridSearchRenderer {
+    cell_size: DEFAULT_GRID_SEARCH_CELL_SIZE,
+    column_count: MAP_SIZE,
+    is_space_available_fn: is_space_available,
+    make_node_fn: Point2DD::new,
+    origin_node: start_node,
+    row_count: MAP_SIZE,
+    step_size: 1.0,
+  };
+  let ascii: String = grid_search_renderer.to_ascii(&astar, &start_node);
+  let line_list: Vec<&str> = ascii.lines().collect();
+  assert_eq!(line_list.len(), MAP_SIZE);
+  assert!(line_list.iter().all(|line| line.len() == MAP_SIZE));
+  assert_eq!(ascii.matches(GridSearchCell::Start.to_char()).count(), 1);
+  assert_eq!(ascii.matches(GridSearchCell::Goal.to_char()).count(), 1);
+  assert_eq!(
+    ascii.matches(GridSearchCell::Blocked.to_char()).count(),
+    blocked_set.len()
+  );
+  assert_eq!(
+    ascii.matches(GridSearchCell::Path.to_char()).count(),
+    astar.get_path().len() - 1
+  );
+  let file_path = std::env::temp_dir()
+    .join(format!("test-ai-astar-renderer-{}.svg", std::process::id()));
+  grid_search_renderer
+    .write_svg(&astar, &start_node, &file_path)
+    .unwrap();
+  let svg: String = std::fs::read_to_string(&file_path).unwrap();
+  std::fs::remove_file(&file_path).unwrap();
+  assert_eq!(svg, grid_search_renderer.to_svg(&astar, &start_node));
+  assert_eq!(svg.matches("<rect ").count(), MAP_SIZE * MAP_SIZE);
+  assert_eq!(svg.matches("<polyline ").count(), 1);
+}
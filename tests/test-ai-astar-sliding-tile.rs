@@ -0,0 +1,168 @@
+// =============================================================================
+//! - Iterative-deepening A* integration tests using a sliding-tile puzzle
+//! - The costs are compared against the A* search
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, IterativeDeepeningAStar, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use common::{RANDOM_SEED, next_random};
+
+/// The tiles in row-major order with zero as the blank
+type Board = [u8; 9];
+
+const BOARD_WIDTH: usize = 3;
+const GOAL_BOARD: Board = [
+  1, 2, 3, 4, 5, 6, 7, 8, 0,
+];
+const SCRAMBLE_COUNT: usize = 10;
+const SCRAMBLE_MOVES: usize = 40;
+
+pub struct SlidingTileCartographer;
+
+impl Cartographer<Board> for SlidingTileCartographer {
+  /// The sum of the Manhattan distances of the tiles to their goal positions
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Board,
+  ) -> f64 {
+    let mut distance: usize = 0;
+    for (index, tile) in node.iter().enumerate() {
+      if *tile == 0 {
+        continue;
+      }
+      let goal_index = *tile as usize - 1;
+      distance += (index / BOARD_WIDTH).abs_diff(goal_index / BOARD_WIDTH)
+        + (index % BOARD_WIDTH).abs_diff(goal_index % BOARD_WIDTH);
+    }
+    distance as f64
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Board,
+  ) -> Vec<Board> {
+    let blank_index = node.iter().position(|tile| *tile == 0).unwrap();
+    let row = blank_index / BOARD_WIDTH;
+    let column = blank_index % BOARD_WIDTH;
+    let mut tile_indices = Vec::new();
+    if row > 0 {
+      tile_indices.push(blank_index - BOARD_WIDTH);
+    }
+    if row < BOARD_WIDTH - 1 {
+      tile_indices.push(blank_index + BOARD_WIDTH);
+    }
+    if column > 0 {
+      tile_indices.push(blank_index - 1);
+    }
+    if column < BOARD_WIDTH - 1 {
+      tile_indices.push(blank_index + 1);
+    }
+    tile_indices
+      .into_iter()
+      .map(|tile_index| {
+        let mut board = *node;
+        board.swap(blank_index, tile_index);
+        board
+      })
+      .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &Board,
+    _to_node: &Board,
+  ) -> f64 {
+    1.0
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Board,
+  ) -> bool {
+    *node == GOAL_BOARD
+  }
+}
+
+/// Random moves from the goal so that the puzzle is always solvable
+fn scramble(seed: &mut u64) -> Board {
+  let mut board = GOAL_BOARD;
+  for _ in 0..SCRAMBLE_MOVES {
+    let adjacent_boards = SlidingTileCartographer.get_adjacent_nodes(&board);
+    board = adjacent_boards[next_random(seed) as usize % adjacent_boards.len()];
+  }
+  board
+}
+
+#[test]
+fn test_ai_astar_sliding_tile_iterative_deepening() {
+  let mut seed = RANDOM_SEED;
+  for _ in 0..SCRAMBLE_COUNT {
+    let start_board = scramble(&mut seed);
+    let mut iterative_deepening_astar = IterativeDeepeningAStar::default();
+    iterative_deepening_astar.reset(start_board);
+    while iterative_deepening_astar.loop_once(&SlidingTileCartographer) {}
+    assert!(iterative_deepening_astar.is_goal_found());
+    let mut astar = AStar::<Board>::default();
+    let SearchOutcome::GoalFound {
+      cost,
+      ..
+    } = astar.search(
+      start_board,
+      &SlidingTileCartographer,
+      &SearchBudget::default(),
+    )
+    else {
+      panic!("goal not found");
+    };
+    assert_eq!(iterative_deepening_astar.path_cost, cost);
+    let path = iterative_deepening_astar.get_path();
+    assert_eq!(path.len() as f64, cost);
+    assert!(iterative_deepening_astar.iteration_count >= 1);
+    let mut previous_board = start_board;
+    for board in path.iter() {
+      assert!(
+        SlidingTileCartographer
+          .get_adjacent_nodes(&previous_board)
+          .contains(board)
+      );
+      previous_board = *board;
+    }
+    assert_eq!(previous_board, GOAL_BOARD);
+  }
+}
+
+#[test]
+fn test_ai_astar_sliding_tile_iterations() {
+  // The Manhattan distance underestimates a rotation of the top row
+  let start_board: Board = [
+    3, 1, 2, 4, 5, 6, 7, 8, 0,
+  ];
+  assert_eq!(
+    SlidingTileCartographer.estimate_cost_to_goal(&start_board),
+    4.0
+  );
+  let mut iterative_deepening_astar = IterativeDeepeningAStar::default();
+  iterative_deepening_astar.reset(start_board);
+  while iterative_deepening_astar.loop_once(&SlidingTileCartographer) {}
+  assert_eq!(iterative_deepening_astar.path_cost, 16.0);
+  // The threshold grows by two each iteration due to the move parity
+  assert_eq!(iterative_deepening_astar.iteration_count, 7);
+  assert_eq!(iterative_deepening_astar.cost_threshold, 16.0);
+  assert_eq!(
+    iterative_deepening_astar.get_path().back(),
+    Some(&GOAL_BOARD)
+  );
+}
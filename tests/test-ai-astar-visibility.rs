@@ -0,0 +1,166 @@
+// =============================================================================
+//! - Visibility graph cartographer integration tests
+//! - Compares the A* path lengths with the exact shortest paths
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, SearchBudget, SearchOutcome, VisibilityGraphCartographer,
+  VisibilityObstacle,
+};
+use com_croftsoft_core::math::geom::circle::Circle;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_core::math::geom::rectangle::Rectangle;
+use common::{RANDOM_SEED, next_random};
+use core::f64::consts::PI;
+
+const AGENT_RADIUS: f64 = 0.5;
+const CIRCLE_COUNT: usize = 12;
+const MAP_SIZE: f64 = 20.0;
+const SIDE_COUNT: usize = 64;
+/// The distance between the points checked along each path segment
+const SIGHT_STEP: f64 = 0.01;
+const TOLERANCE: f64 = 0.000_001;
+
+/// A random coordinate from zero to the map size
+fn next_coordinate(seed: &mut u64) -> f64 {
+  (next_random(seed) % 1_000) as f64 * MAP_SIZE / 1_000.0
+}
+
+fn search(
+  start_node: Point2DD,
+  visibility_graph_cartographer: &VisibilityGraphCartographer,
+) -> (f64, Vec<Point2DD>) {
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = astar.search(
+    start_node,
+    visibility_graph_cartographer,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("no path");
+  };
+  (cost, path.into_iter().collect())
+}
+
+#[test]
+fn test_ai_astar_visibility_around_circle() {
+  // The exact shortest path wraps around the inflated circle between the two
+  // tangent lines
+  let center_distance = 5.0;
+  let circle = Circle {
+    center_x: center_distance,
+    center_y: 0.0,
+    radius: 1.0,
+  };
+  let inflated_radius = circle.radius + AGENT_RADIUS;
+  let expected_cost = 2.0
+    * (center_distance * center_distance - inflated_radius * inflated_radius)
+      .sqrt()
+    + inflated_radius * (PI - 2.0 * (inflated_radius / center_distance).acos());
+  let visibility_graph_cartographer = VisibilityGraphCartographer::new(
+    AGENT_RADIUS,
+    Point2DD::new(2.0 * center_distance, 0.0),
+    &[VisibilityObstacle::Circle(circle)],
+    SIDE_COUNT,
+  );
+  let (cost, path) =
+    search(Point2DD::new(0.0, 0.0), &visibility_graph_cartographer);
+  // The circumscribed polygon is slightly longer than the circle
+  assert!(cost >= expected_cost - TOLERANCE);
+  assert!(cost <= expected_cost * 1.01);
+  assert!(path.len() > 2);
+}
+
+#[test]
+fn test_ai_astar_visibility_around_rectangle() {
+  // Without inflation the shortest path touches two corners of the wall
+  let visibility_graph_cartographer = VisibilityGraphCartographer::new(
+    0.0,
+    Point2DD::new(6.0, 0.0),
+    &[
+      VisibilityObstacle::Rectangle(Rectangle {
+        x_max: 4.0,
+        x_min: 2.0,
+        y_max: 3.0,
+        y_min: -1.0,
+      }),
+    ],
+    SIDE_COUNT,
+  );
+  let (cost, path) =
+    search(Point2DD::new(0.0, 0.0), &visibility_graph_cartographer);
+  assert_eq!(
+    path,
+    vec![
+      Point2DD::new(2.0, -1.0),
+      Point2DD::new(4.0, -1.0),
+      Point2DD::new(6.0, 0.0),
+    ]
+  );
+  assert!((cost - (2.0 * 5.0_f64.sqrt() + 2.0)).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_ai_astar_visibility_random_circles() {
+  let mut seed = RANDOM_SEED;
+  let circle_list: Vec<Circle> = (0..CIRCLE_COUNT)
+    .map(|_| Circle {
+      center_x: next_coordinate(&mut seed),
+      center_y: next_coordinate(&mut seed),
+      radius: 0.5 + (next_random(&mut seed) % 100) as f64 / 100.0,
+    })
+    .collect();
+  let is_clear = |point: &Point2DD| {
+    circle_list.iter().all(|circle| {
+      point.distance_to(&Point2DD::new(circle.center_x, circle.center_y))
+        >= circle.radius + AGENT_RADIUS - TOLERANCE
+    })
+  };
+  let start_node = Point2DD::new(-2.0, -2.0);
+  let goal_node = Point2DD::new(MAP_SIZE + 2.0, MAP_SIZE + 2.0);
+  let obstacle_list: Vec<VisibilityObstacle> = circle_list
+    .iter()
+    .copied()
+    .map(VisibilityObstacle::Circle)
+    .collect();
+  let visibility_graph_cartographer = VisibilityGraphCartographer::new(
+    AGENT_RADIUS,
+    goal_node,
+    &obstacle_list,
+    SIDE_COUNT,
+  );
+  let (cost, path) = search(start_node, &visibility_graph_cartographer);
+  assert!(cost >= start_node.distance_to(&goal_node) - TOLERANCE);
+  assert_eq!(path.last(), Some(&goal_node));
+  let mut path_length = 0.0;
+  let mut from_node = start_node;
+  for to_node in path {
+    // The agent never overlaps a circle anywhere along the path
+    let segment_length = from_node.distance_to(&to_node);
+    let step_count = (segment_length / SIGHT_STEP).ceil() as usize;
+    for step_index in 0..=step_count {
+      let fraction = step_index as f64 / step_count as f64;
+      assert!(is_clear(&Point2DD::new(
+        from_node.x + (to_node.x - from_node.x) * fraction,
+        from_node.y + (to_node.y - from_node.y) * fraction,
+      )));
+    }
+    path_length += segment_length;
+    from_node = to_node;
+  }
+  assert!((cost - path_length).abs() < TOLERANCE);
+}
@@ -0,0 +1,251 @@
+// =============================================================================
+//! - A* algorithm integration tests using a voxel grid
+//! - Compares the heuristics and the connectivities on random maps
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, VoxelCartographer, VoxelConnectivity, VoxelHeuristic,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::math::geom::point_3dd::Point3DD;
+use common::{RANDOM_SEED, next_random};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+const LOOP_COUNT_MAX: usize = 100_000;
+const MAP_SIZE: usize = 12;
+const OBSTACLE_PERCENT: u64 = 25;
+const SEARCH_COUNT: usize = 20;
+const TOLERANCE: f64 = 0.001;
+
+fn is_in_bounds(node: &Point3DD) -> bool {
+  [
+    node.x, node.y, node.z,
+  ]
+  .iter()
+  .all(|coordinate| *coordinate >= 0.0 && *coordinate < MAP_SIZE as f64)
+}
+
+fn make_blocked_set(seed: &mut u64) -> HashSet<Point3DD> {
+  let mut blocked_set = HashSet::new();
+  for x in 0..MAP_SIZE {
+    for y in 0..MAP_SIZE {
+      for z in 0..MAP_SIZE {
+        if next_random(seed) % 100 < OBSTACLE_PERCENT {
+          blocked_set.insert(Point3DD::new(x as f64, y as f64, z as f64));
+        }
+      }
+    }
+  }
+  blocked_set
+}
+
+fn make_free_node(
+  seed: &mut u64,
+  blocked_set: &HashSet<Point3DD>,
+) -> Point3DD {
+  loop {
+    let node = Point3DD::new(
+      (next_random(seed) % MAP_SIZE as u64) as f64,
+      (next_random(seed) % MAP_SIZE as u64) as f64,
+      (next_random(seed) % MAP_SIZE as u64) as f64,
+    );
+    if !blocked_set.contains(&node) {
+      return node;
+    }
+  }
+}
+
+fn make_voxel_cartographer(
+  goal_node: Point3DD,
+  blocked_set: &HashSet<Point3DD>,
+  voxel_connectivity: VoxelConnectivity,
+  voxel_heuristic: VoxelHeuristic,
+) -> VoxelCartographer<Point3DD, impl Fn(&Point3DD) -> bool> {
+  VoxelCartographer {
+    goal_node,
+    is_space_available_fn: move |node: &Point3DD| {
+      is_in_bounds(node) && !blocked_set.contains(node)
+    },
+    make_node_fn: Point3DD::new,
+    step_size: 1.0,
+    voxel_connectivity,
+    voxel_heuristic,
+  }
+}
+
+/// Runs the search with loop_once and returns the cost and the path
+fn search(
+  start_node: Point3DD,
+  voxel_cartographer: &dyn Cartographer<Point3DD>,
+) -> Option<(f64, VecDeque<Point3DD>)> {
+  let mut astar = AStar::<Point3DD>::default();
+  astar.reset(start_node);
+  for _ in 0..LOOP_COUNT_MAX {
+    if !astar.loop_once(voxel_cartographer) {
+      break;
+    }
+  }
+  if !astar.is_goal_found() {
+    return None;
+  }
+  Some((astar.get_best_cost()?, astar.get_path()))
+}
+
+/// The number of face moves from the start to each reachable voxel
+fn search_breadth_first(
+  start_node: Point3DD,
+  blocked_set: &HashSet<Point3DD>,
+) -> HashMap<Point3DD, usize> {
+  let mut node_to_steps_map = HashMap::from([(start_node, 0)]);
+  let mut open_node_queue = VecDeque::from([start_node]);
+  while let Some(node) = open_node_queue.pop_front() {
+    let steps: usize = node_to_steps_map[&node];
+    for (dx, dy, dz) in [
+      (1.0, 0.0, 0.0),
+      (-1.0, 0.0, 0.0),
+      (0.0, 1.0, 0.0),
+      (0.0, -1.0, 0.0),
+      (0.0, 0.0, 1.0),
+      (0.0, 0.0, -1.0),
+    ] {
+      let adjacent_node = Point3DD::new(node.x + dx, node.y + dy, node.z + dz);
+      if is_in_bounds(&adjacent_node)
+        && !blocked_set.contains(&adjacent_node)
+        && !node_to_steps_map.contains_key(&adjacent_node)
+      {
+        node_to_steps_map.insert(adjacent_node, steps + 1);
+        open_node_queue.push_back(adjacent_node);
+      }
+    }
+  }
+  node_to_steps_map
+}
+
+#[test]
+fn test_ai_astar_voxel_diagonal() {
+  let blocked_set = HashSet::new();
+  let goal_node = Point3DD::new(3.0, 3.0, 3.0);
+  let voxel_cartographer = make_voxel_cartographer(
+    goal_node,
+    &blocked_set,
+    VoxelConnectivity::TwentySix,
+    VoxelHeuristic::Octile,
+  );
+  let (cost, path) =
+    search(Point3DD::new(0.0, 0.0, 0.0), &voxel_cartographer).unwrap();
+  // Only the corner moves reach the goal in three steps
+  assert!((cost - 3.0 * 3f64.sqrt()).abs() < TOLERANCE);
+  assert_eq!(
+    path,
+    VecDeque::from([
+      Point3DD::new(1.0, 1.0, 1.0),
+      Point3DD::new(2.0, 2.0, 2.0),
+      goal_node,
+    ])
+  );
+}
+
+#[test]
+fn test_ai_astar_voxel_open_space() {
+  let blocked_set = HashSet::new();
+  let goal_node = Point3DD::new(7.0, 3.0, 5.0);
+  let voxel_cartographer = make_voxel_cartographer(
+    goal_node,
+    &blocked_set,
+    VoxelConnectivity::TwentySix,
+    VoxelHeuristic::Octile,
+  );
+  let start_node = Point3DD::new(1.0, 1.0, 1.0);
+  let (cost, path) = search(start_node, &voxel_cartographer).unwrap();
+  // The octile heuristic is exact without obstacles
+  assert!(
+    (cost - voxel_cartographer.estimate_cost_to_goal(&start_node)).abs()
+      < TOLERANCE
+  );
+  assert_eq!(path.len(), 6);
+  assert_eq!(path.back(), Some(&goal_node));
+}
+
+#[test]
+fn test_ai_astar_voxel_random_map() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed);
+  let mut found_count = 0;
+  for _ in 0..SEARCH_COUNT {
+    let start_node = make_free_node(&mut seed, &blocked_set);
+    let goal_node = make_free_node(&mut seed, &blocked_set);
+    // Both heuristics are admissible so the costs are the same
+    let euclidean_cartographer = make_voxel_cartographer(
+      goal_node,
+      &blocked_set,
+      VoxelConnectivity::TwentySix,
+      VoxelHeuristic::Euclidean,
+    );
+    let octile_cartographer = make_voxel_cartographer(
+      goal_node,
+      &blocked_set,
+      VoxelConnectivity::TwentySix,
+      VoxelHeuristic::Octile,
+    );
+    let euclidean_option = search(start_node, &euclidean_cartographer);
+    let octile_option = search(start_node, &octile_cartographer);
+    assert_eq!(euclidean_option.is_some(), octile_option.is_some());
+    let (Some((euclidean_cost, _)), Some((octile_cost, octile_path))) =
+      (euclidean_option, octile_option)
+    else {
+      continue;
+    };
+    assert!((euclidean_cost - octile_cost).abs() < TOLERANCE);
+    let mut previous_node = start_node;
+    for node in octile_path.iter() {
+      assert!(previous_node.distance_to(node) < 1.8);
+      assert!(!blocked_set.contains(node));
+      previous_node = *node;
+    }
+    assert_eq!(previous_node, goal_node);
+    found_count += 1;
+  }
+  assert_ne!(found_count, 0);
+}
+
+#[test]
+fn test_ai_astar_voxel_six_connectivity() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed);
+  for _ in 0..SEARCH_COUNT {
+    let start_node = make_free_node(&mut seed, &blocked_set);
+    let goal_node = make_free_node(&mut seed, &blocked_set);
+    let voxel_cartographer = make_voxel_cartographer(
+      goal_node,
+      &blocked_set,
+      VoxelConnectivity::Six,
+      VoxelHeuristic::Octile,
+    );
+    let expected_steps_option: Option<usize> =
+      search_breadth_first(start_node, &blocked_set)
+        .get(&goal_node)
+        .copied();
+    let search_option = search(start_node, &voxel_cartographer);
+    assert_eq!(search_option.is_some(), expected_steps_option.is_some());
+    let (Some((cost, path)), Some(expected_steps)) =
+      (search_option, expected_steps_option)
+    else {
+      continue;
+    };
+    assert!((cost - expected_steps as f64).abs() < TOLERANCE);
+    assert_eq!(path.len(), expected_steps);
+  }
+}
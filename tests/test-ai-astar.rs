@@ -2,12 +2,12 @@
 //! - A* algorithm integration tests
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Java created: 2002-04-21
 //! - Java updated: 2003-05-09
 //! - Rust created: 2022-11-13
-//! - Rust updated: 2023-07-26
+//! - Rust updated: 2026-10-18
 //!
 //! # History
 //! - Adapted from the class in the Java-based [`CroftSoft Core Library`]
@@ -18,8 +18,13 @@
 //! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
 // =============================================================================
 
-use com_croftsoft_core::ai::astar::structures::AStar;
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, BidirectionalAStar, DStarLite, GridCartographer,
+  JumpPointCartographer, SearchBudget, SearchOutcome, TerrainCartographer,
+};
 use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::hash::Hash;
@@ -177,6 +182,97 @@ impl AStarTest<Point> {
   }
 }
 
+// A U-shaped wall which opens away from the goal
+const BLOCKED_U_SHAPE: [Point; 11] = [
+  (START_X + 2, START_Y - 3),
+  (START_X + 2, START_Y - 2),
+  (START_X + 2, START_Y - 1),
+  (START_X + 2, START_Y),
+  (START_X + 2, START_Y + 1),
+  (START_X + 2, START_Y + 2),
+  (START_X + 2, START_Y + 3),
+  (START_X + 1, START_Y - 3),
+  (START_X, START_Y - 3),
+  (START_X + 1, START_Y + 3),
+  (START_X, START_Y + 3),
+];
+
+/// Searches the same bounded map with a GridCartographer and a
+/// JumpPointCartographer and returns both outcomes
+fn search_grid_and_jump_point(
+  blocked_points: &[Point],
+  goal_point: Point,
+) -> (SearchOutcome<Point2DD>, SearchOutcome<Point2DD>) {
+  let blocked_set: HashSet<Point2DD> =
+    blocked_points.iter().map(to_point_2dd).collect();
+  let is_space_available_fn = |node: &Point2DD| {
+    node.x >= MIN_X as f64
+      && node.x <= MAX_X as f64
+      && node.y >= MIN_Y as f64
+      && node.y <= MAX_Y as f64
+      && !blocked_set.contains(node)
+  };
+  let grid_cartographer = GridCartographer {
+    goal_node: to_point_2dd(&goal_point),
+    is_space_available_fn,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let jump_point_cartographer = JumpPointCartographer {
+    goal_node: to_point_2dd(&goal_point),
+    is_space_available_fn,
+    jump_limit: LOOP_COUNT_MAX,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let search_budget = SearchBudget {
+    max_expansions: LOOP_COUNT_MAX,
+    ..Default::default()
+  };
+  let mut astar = AStar::<Point2DD>::default();
+  let grid_search_outcome =
+    astar.search(to_point_2dd(&START), &grid_cartographer, &search_budget);
+  let jump_point_search_outcome = astar.search(
+    to_point_2dd(&START),
+    &jump_point_cartographer,
+    &search_budget,
+  );
+  if let SearchOutcome::GoalFound {
+    path,
+    ..
+  } = &jump_point_search_outcome
+  {
+    let cell_path =
+      jump_point_cartographer.expand_jump_points(&to_point_2dd(&START), path);
+    let mut previous_node = to_point_2dd(&START);
+    for node in cell_path {
+      assert!(is_space_available_fn(&node));
+      assert!(previous_node.distance_to(&node) < 1.5);
+      previous_node = node;
+    }
+    assert_eq!(previous_node, to_point_2dd(&goal_point));
+  }
+  (grid_search_outcome, jump_point_search_outcome)
+}
+
+fn search_cost(
+  start_point: Point,
+  cartographer: &dyn Cartographer<Point>,
+) -> Option<f64> {
+  let mut astar = AStar::<Point>::default();
+  match astar.search(start_point, cartographer, &SearchBudget::default()) {
+    SearchOutcome::GoalFound {
+      cost,
+      ..
+    } => Some(cost),
+    _ => None,
+  }
+}
+
+fn to_point_2dd(point: &Point) -> Point2DD {
+  Point2DD::new(point.0 as f64, point.1 as f64)
+}
+
 #[test]
 fn test_ai_astar_enclosed_goal() {
   let astar_test =
@@ -225,6 +321,34 @@ fn test_ai_astar_enclosed_start() {
   assert!(!astar.is_goal_found());
 }
 
+#[test]
+fn test_ai_astar_grid_cartographer_closure() {
+  let blocked_set: HashSet<Point2DD> =
+    BLOCKED_OBSTACLE.iter().map(to_point_2dd).collect();
+  let grid_cartographer = GridCartographer {
+    goal_node: to_point_2dd(&GOAL_4),
+    is_space_available_fn: |node: &Point2DD| !blocked_set.contains(node),
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut astar = AStar::<Point2DD>::default();
+  let search_budget = SearchBudget {
+    max_expansions: LOOP_COUNT_MAX,
+    ..Default::default()
+  };
+  let search_outcome =
+    astar.search(to_point_2dd(&START), &grid_cartographer, &search_budget);
+  let SearchOutcome::GoalFound {
+    path,
+    ..
+  } = search_outcome
+  else {
+    panic!("unexpected search outcome {search_outcome:?}");
+  };
+  assert_eq!(path.len(), 5);
+  assert!(path.iter().all(|node| !blocked_set.contains(node)));
+}
+
 #[test]
 fn test_ai_astar_obstacle() {
   let astar_test = AStarTest::<Point>::new(&BLOCKED_OBSTACLE, GOAL_4, None);
@@ -257,3 +381,336 @@ fn test_ai_astar_teleport_nearby() {
   assert_eq!(path.len(), 3);
   assert_eq!(path[2], GOAL_3);
 }
+
+#[test]
+fn test_ai_astar_search_budget_exhausted() {
+  let astar_test = AStarTest::<Point>::new(&BLOCKED_OBSTACLE, GOAL_4, None);
+  let mut astar = AStar::<Point>::default();
+  let search_budget = SearchBudget {
+    max_nodes: 10,
+    ..Default::default()
+  };
+  let search_outcome = astar.search(START, &astar_test, &search_budget);
+  let SearchOutcome::BudgetExhausted {
+    path,
+    ..
+  } = search_outcome
+  else {
+    panic!("unexpected search outcome {search_outcome:?}");
+  };
+  assert_eq!(path.front(), astar.get_first_step().as_ref());
+}
+
+#[test]
+fn test_ai_astar_search_enclosed_goal() {
+  let astar_test =
+    AStarTest::<Point>::new(&BLOCKED_ENCLOSED_GOAL, GOAL_5, None);
+  let mut astar = AStar::<Point>::default();
+  let search_outcome =
+    astar.search(START, &astar_test, &SearchBudget::default());
+  assert_eq!(search_outcome, SearchOutcome::Unreachable);
+}
+
+#[test]
+fn test_ai_astar_search_obstacle() {
+  let astar_test = AStarTest::<Point>::new(&BLOCKED_OBSTACLE, GOAL_4, None);
+  let mut astar = AStar::<Point>::default();
+  let search_outcome =
+    astar.search(START, &astar_test, &SearchBudget::default());
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = search_outcome
+  else {
+    panic!("unexpected search outcome {search_outcome:?}");
+  };
+  assert!((cost - (2.0 + 3.0 * 2_f64.sqrt())).abs() < 0.001);
+  assert_eq!(path.len(), 5);
+  assert_eq!(path[4], GOAL_4);
+}
+
+#[test]
+fn test_ai_astar_jump_point_same_costs() {
+  let maps: [(&[Point], Point); 6] = [
+    (&BLOCKED_ENCLOSED_GOAL, GOAL_5),
+    (&BLOCKED_ENCLOSED_START, GOAL_4),
+    (&BLOCKED_NO_OBSTACLES, GOAL_3),
+    (&BLOCKED_NO_OBSTACLES, GOAL_5),
+    (&BLOCKED_OBSTACLE, GOAL_4),
+    (&BLOCKED_U_SHAPE, GOAL_5),
+  ];
+  for (blocked_points, goal_point) in maps {
+    let (grid_search_outcome, jump_point_search_outcome) =
+      search_grid_and_jump_point(blocked_points, goal_point);
+    match (grid_search_outcome, jump_point_search_outcome) {
+      (
+        SearchOutcome::GoalFound {
+          cost: grid_cost,
+          ..
+        },
+        SearchOutcome::GoalFound {
+          cost: jump_point_cost,
+          ..
+        },
+      ) => assert!((grid_cost - jump_point_cost).abs() < 0.001),
+      (SearchOutcome::Unreachable, SearchOutcome::Unreachable) => (),
+      outcomes => panic!("different search outcomes {outcomes:?}"),
+    }
+  }
+}
+
+#[test]
+fn test_ai_astar_theta_star_any_angle() {
+  let goal_point: Point = (START_X + 7, START_Y + 3);
+  let blocked_set: HashSet<Point2DD> =
+    BLOCKED_OBSTACLE.iter().map(to_point_2dd).collect();
+  let grid_cartographer = GridCartographer {
+    goal_node: to_point_2dd(&goal_point),
+    is_space_available_fn: |node: &Point2DD| !blocked_set.contains(node),
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let search_budget = SearchBudget {
+    max_expansions: LOOP_COUNT_MAX,
+    ..Default::default()
+  };
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost: grid_cost,
+    path: grid_path,
+  } = astar.search(to_point_2dd(&START), &grid_cartographer, &search_budget)
+  else {
+    panic!("goal not found");
+  };
+  let mut theta_star = AStar::<Point2DD> {
+    any_angle: true,
+    ..Default::default()
+  };
+  let SearchOutcome::GoalFound {
+    cost: any_angle_cost,
+    path: any_angle_path,
+  } =
+    theta_star.search(to_point_2dd(&START), &grid_cartographer, &search_budget)
+  else {
+    panic!("goal not found");
+  };
+  assert!(any_angle_cost < grid_cost);
+  assert!(any_angle_path.len() < grid_path.len());
+  let mut previous_node = to_point_2dd(&START);
+  for node in any_angle_path {
+    assert!(
+      previous_node.distance_to(&node) < 1.5
+        || grid_cartographer.has_line_of_sight(&previous_node, &node)
+    );
+    previous_node = node;
+  }
+}
+
+#[test]
+fn test_ai_astar_terrain_road_detour() {
+  // A road runs along the bottom of a field of mud
+  let road_y: f64 = (START_Y - 2) as f64;
+  let goal_node = Point2DD::new(MAX_X as f64, START_Y as f64);
+  let is_space_available_fn = |node: &Point2DD| {
+    node.x >= MIN_X as f64
+      && node.x <= MAX_X as f64
+      && node.y >= road_y
+      && node.y <= MAX_Y as f64
+  };
+  let terrain_multiplier_fn = |_: &Point2DD, to_node: &Point2DD| {
+    if to_node.y == road_y {
+      1.0
+    } else {
+      4.0
+    }
+  };
+  let search_budget = SearchBudget {
+    max_expansions: LOOP_COUNT_MAX,
+    ..Default::default()
+  };
+  let search = |min_terrain_multiplier: f64| {
+    let grid_cartographer = GridCartographer {
+      goal_node,
+      is_space_available_fn,
+      make_node_fn: Point2DD::new,
+      step_size: 1.0,
+    };
+    let terrain_cartographer = TerrainCartographer {
+      cartographer: &grid_cartographer,
+      min_terrain_multiplier,
+      terrain_multiplier_fn,
+    };
+    let mut astar = AStar::<Point2DD>::default();
+    let SearchOutcome::GoalFound {
+      cost,
+      path,
+    } =
+      astar.search(to_point_2dd(&START), &terrain_cartographer, &search_budget)
+    else {
+      panic!("goal not found");
+    };
+    (cost, path)
+  };
+  let (cost, path) = search(1.0);
+  // Crossing the mud directly would cost four per step
+  assert!(cost < DISTANCE_FROM_START as f64 * 4.0);
+  let road_step_count = path.iter().filter(|node| node.y == road_y).count();
+  assert!(road_step_count >= DISTANCE_FROM_START / 2);
+  assert_eq!(path.back(), Some(&goal_node));
+  // The heuristic scaled by the minimum multiplier is admissible so the cost
+  // is the same as for a uniform-cost search without a heuristic
+  let (uniform_cost, _) = search(0.0);
+  assert!((cost - uniform_cost).abs() < 0.001);
+}
+
+#[test]
+fn test_ai_astar_anytime() {
+  let astar_test = AStarTest::<Point>::new(&BLOCKED_U_SHAPE, GOAL_5, None);
+  let optimal_cost = search_cost(START, &astar_test).unwrap();
+  let mut astar = AStar::<Point> {
+    anytime: true,
+    heuristic_weight: 5.0,
+    heuristic_weight_decrement: 1.0,
+    ..Default::default()
+  };
+  astar.reset(START);
+  let mut loop_count = 0;
+  let mut previous_cost = f64::INFINITY;
+  while loop_count < LOOP_COUNT_MAX && astar.loop_once(&astar_test) {
+    loop_count += 1;
+    let Some(best_cost) = astar.get_best_cost() else {
+      continue;
+    };
+    assert!(best_cost <= previous_cost);
+    assert!(best_cost <= astar.suboptimality_bound * optimal_cost + 0.001);
+    previous_cost = best_cost;
+  }
+  assert!(loop_count < LOOP_COUNT_MAX);
+  assert_eq!(astar.suboptimality_bound, 1.0);
+  assert!((astar.get_best_cost().unwrap() - optimal_cost).abs() < 0.001);
+  assert_eq!(astar.get_path().back(), Some(&GOAL_5));
+}
+
+#[test]
+fn test_ai_astar_bidirectional_same_costs() {
+  for blocked_points in [
+    &BLOCKED_NO_OBSTACLES[..],
+    &BLOCKED_OBSTACLE,
+    &BLOCKED_U_SHAPE,
+  ] {
+    let astar_test = AStarTest::<Point>::new(blocked_points, GOAL_5, None);
+    let mut bidirectional_astar = BidirectionalAStar::<Point>::default();
+    bidirectional_astar.reset(START, GOAL_5);
+    let mut loop_count = 0;
+    while loop_count < LOOP_COUNT_MAX
+      && bidirectional_astar.loop_once(&astar_test)
+    {
+      loop_count += 1;
+    }
+    assert!(bidirectional_astar.is_goal_found());
+    let expected_cost = search_cost(START, &astar_test).unwrap();
+    assert!((bidirectional_astar.best_path_cost - expected_cost).abs() < 0.001);
+    let path = bidirectional_astar.get_path();
+    assert_eq!(path.back(), Some(&GOAL_5));
+    let mut previous_point = START;
+    let mut path_cost = 0.0;
+    for point in path.iter() {
+      assert!(
+        astar_test
+          .get_adjacent_nodes(&previous_point)
+          .contains(point)
+      );
+      path_cost += astar_test.get_cost_to_adjacent_node(&previous_point, point);
+      previous_point = *point;
+    }
+    assert!((path_cost - expected_cost).abs() < 0.001);
+    assert_eq!(bidirectional_astar.get_first_step(), path.front().copied());
+  }
+}
+
+#[test]
+fn test_ai_astar_bidirectional_enclosed_goal() {
+  let astar_test =
+    AStarTest::<Point>::new(&BLOCKED_ENCLOSED_GOAL, GOAL_5, None);
+  let mut bidirectional_astar = BidirectionalAStar::<Point>::default();
+  bidirectional_astar.reset(START, GOAL_5);
+  let mut loop_count = 0;
+  while loop_count < LOOP_COUNT_MAX
+    && bidirectional_astar.loop_once(&astar_test)
+  {
+    loop_count += 1;
+  }
+  assert!(bidirectional_astar.list_empty);
+  assert!(!bidirectional_astar.is_goal_found());
+  assert_eq!(bidirectional_astar.get_first_step(), None);
+}
+
+#[test]
+fn test_ai_astar_d_star_lite_edges_changed() {
+  let astar_test = AStarTest::<Point>::new(&BLOCKED_NO_OBSTACLES, GOAL_5, None);
+  let mut d_star_lite = DStarLite::new(START, GOAL_5);
+  assert!(d_star_lite.compute_shortest_path(&astar_test, LOOP_COUNT_MAX));
+  assert_eq!(d_star_lite.get_path_cost(), 5.0);
+  assert_eq!(d_star_lite.get_path(&astar_test).len(), 5);
+  let astar_test = AStarTest::<Point>::new(&BLOCKED_U_SHAPE, GOAL_5, None);
+  d_star_lite.edges_changed(&astar_test, &BLOCKED_U_SHAPE);
+  assert!(d_star_lite.compute_shortest_path(&astar_test, LOOP_COUNT_MAX));
+  let expected_cost = search_cost(START, &astar_test).unwrap();
+  assert!((d_star_lite.get_path_cost() - expected_cost).abs() < 0.001);
+  let path = d_star_lite.get_path(&astar_test);
+  assert_eq!(path.back(), Some(&GOAL_5));
+  assert!(path.iter().all(|point| !BLOCKED_U_SHAPE.contains(point)));
+  let first_step = d_star_lite.get_first_step(&astar_test).unwrap();
+  d_star_lite.move_start(&astar_test, first_step);
+  assert!(d_star_lite.compute_shortest_path(&astar_test, LOOP_COUNT_MAX));
+  let expected_cost = search_cost(first_step, &astar_test).unwrap();
+  assert!((d_star_lite.get_path_cost() - expected_cost).abs() < 0.001);
+  let astar_test =
+    AStarTest::<Point>::new(&BLOCKED_ENCLOSED_GOAL, GOAL_5, None);
+  d_star_lite.edges_changed(&astar_test, &BLOCKED_ENCLOSED_GOAL);
+  d_star_lite.edges_changed(&astar_test, &BLOCKED_U_SHAPE);
+  assert!(d_star_lite.compute_shortest_path(&astar_test, LOOP_COUNT_MAX));
+  assert!(!d_star_lite.is_path_found());
+  assert_eq!(d_star_lite.get_first_step(&astar_test), None);
+}
+
+#[test]
+fn test_ai_astar_d_star_lite_grid_cartographer() {
+  let blocked_set: RefCell<HashSet<Point2DD>> = RefCell::new(HashSet::new());
+  let grid_cartographer = GridCartographer {
+    goal_node: to_point_2dd(&GOAL_5),
+    is_space_available_fn: |node: &Point2DD| {
+      node.x >= MIN_X as f64
+        && node.x <= MAX_X as f64
+        && node.y >= MIN_Y as f64
+        && node.y <= MAX_Y as f64
+        && !blocked_set.borrow().contains(node)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut d_star_lite =
+    DStarLite::new(to_point_2dd(&START), to_point_2dd(&GOAL_5));
+  d_star_lite.compute_shortest_path(&grid_cartographer, LOOP_COUNT_MAX);
+  assert_eq!(d_star_lite.get_path_cost(), 5.0);
+  let changed_nodes: Vec<Point2DD> =
+    BLOCKED_U_SHAPE.iter().map(to_point_2dd).collect();
+  blocked_set.borrow_mut().extend(changed_nodes.iter());
+  d_star_lite.edges_changed(&grid_cartographer, &changed_nodes);
+  d_star_lite.compute_shortest_path(&grid_cartographer, LOOP_COUNT_MAX);
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    ..
+  } = astar.search(
+    to_point_2dd(&START),
+    &grid_cartographer,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("goal not found");
+  };
+  assert!((d_star_lite.get_path_cost() - cost).abs() < 0.001);
+  let path = d_star_lite.get_path(&grid_cartographer);
+  assert!(path.iter().all(|node| !changed_nodes.contains(node)));
+}
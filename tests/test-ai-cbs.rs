@@ -0,0 +1,181 @@
+// =============================================================================
+//! - Conflict-Based Search integration tests
+//! - Compares the paths with single-agent A* and cooperative A*
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GoalCartographer, GridCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::ai::cbs::{
+  ConflictBasedSearch, ConflictBasedSearchOutcome,
+};
+use com_croftsoft_core::ai::spacetime::CooperativeAStar;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, is_in_bounds, make_blocked_set, make_unused_node};
+use std::collections::HashSet;
+
+const AGENT_COUNT: usize = 4;
+const MAP_SIZE: usize = 8;
+const OBSTACLE_PERCENT: u64 = 15;
+const TOLERANCE: f64 = 0.001;
+
+/// The cost of the moves and the waits along a path
+fn get_path_cost(path: &[Point2DD]) -> f64 {
+  path
+    .iter()
+    .zip(path.iter().skip(1))
+    .map(|(node, next_node)| {
+      if node == next_node {
+        1.0
+      } else {
+        node.distance_to(next_node)
+      }
+    })
+    .sum()
+}
+
+#[test]
+fn test_ai_cbs_random_agents() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let mut used_set = HashSet::new();
+  let agent_list: Vec<(Point2DD, Point2DD)> = (0..AGENT_COUNT)
+    .map(|_| {
+      (
+        make_unused_node(&mut seed, MAP_SIZE, &blocked_set, &mut used_set),
+        make_unused_node(&mut seed, MAP_SIZE, &blocked_set, &mut used_set),
+      )
+    })
+    .collect();
+  let grid_cartographer = GridCartographer {
+    goal_node: Point2DD::default(),
+    is_space_available_fn: |node: &Point2DD| {
+      is_in_bounds(MAP_SIZE, node) && !blocked_set.contains(node)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let conflict_based_search = ConflictBasedSearch::default();
+  let ConflictBasedSearchOutcome::Solved {
+    cost,
+    path_list,
+  } = conflict_based_search.search(&grid_cartographer, &agent_list)
+  else {
+    panic!("not solved");
+  };
+  assert_eq!(ConflictBasedSearch::get_first_conflict(&path_list), None);
+  let mut path_cost_sum = 0.0;
+  let mut solo_cost_sum = 0.0;
+  for ((start_node, goal_node), path) in agent_list.iter().zip(&path_list) {
+    assert_eq!(path.first(), Some(start_node));
+    assert_eq!(path.last(), Some(goal_node));
+    for (node, next_node) in path.iter().zip(path.iter().skip(1)) {
+      assert!(node == next_node || node.distance_to(next_node) < 1.5);
+      assert!(!blocked_set.contains(next_node));
+    }
+    path_cost_sum += get_path_cost(path);
+    let goal_cartographer = GoalCartographer {
+      cartographer: &grid_cartographer,
+      goal_node: *goal_node,
+    };
+    let mut astar = AStar::<Point2DD>::default();
+    let SearchOutcome::GoalFound {
+      cost: solo_cost,
+      ..
+    } = astar.search(*start_node, &goal_cartographer, &SearchBudget::default())
+    else {
+      panic!("no single-agent path");
+    };
+    solo_cost_sum += solo_cost;
+  }
+  assert!((cost - path_cost_sum).abs() < TOLERANCE);
+  // Avoiding the other agents never makes the sum of costs lower
+  assert!(cost >= solo_cost_sum - TOLERANCE);
+  // Prioritized planning is never better than the optimal sum of costs
+  let goal_cartographer_list: Vec<GoalCartographer<Point2DD>> = agent_list
+    .iter()
+    .map(|(_, goal_node)| GoalCartographer {
+      cartographer: &grid_cartographer,
+      goal_node: *goal_node,
+    })
+    .collect();
+  let agents: Vec<(Point2DD, &dyn Cartographer<Point2DD>)> = agent_list
+    .iter()
+    .zip(goal_cartographer_list.iter())
+    .map(|((start_node, _), goal_cartographer)| {
+      (
+        *start_node,
+        goal_cartographer as &dyn Cartographer<Point2DD>,
+      )
+    })
+    .collect();
+  let mut cooperative_astar = CooperativeAStar::default();
+  let path_option_list = cooperative_astar.find_paths(&agents);
+  if path_option_list.iter().all(Option::is_some) {
+    let cooperative_cost: f64 = path_option_list
+      .iter()
+      .flatten()
+      .map(|path| get_path_cost(path))
+      .sum();
+    assert!(cost <= cooperative_cost + TOLERANCE);
+  }
+}
+
+#[test]
+fn test_ai_cbs_solves_where_prioritized_planning_fails() {
+  // A corridor with a single passing bay.
+  // The first agent parks at its goal in front of the second agent.
+  // The goal of the shared cartographer in the corridor is ignored.
+  let grid_cartographer = GridCartographer {
+    goal_node: Point2DD::new(3.0, 0.0),
+    is_space_available_fn: |node: &Point2DD| {
+      (node.y == 0.0 && node.x >= 0.0 && node.x <= 6.0)
+        || (node.x == 3.0 && node.y == 1.0)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let agent_list = [
+    (Point2DD::new(4.0, 0.0), Point2DD::new(5.0, 0.0)),
+    (Point2DD::new(6.0, 0.0), Point2DD::new(0.0, 0.0)),
+  ];
+  let goal_cartographer_list: Vec<GoalCartographer<Point2DD>> = agent_list
+    .iter()
+    .map(|(_, goal_node)| GoalCartographer {
+      cartographer: &grid_cartographer,
+      goal_node: *goal_node,
+    })
+    .collect();
+  let mut cooperative_astar = CooperativeAStar::default();
+  let path_option_list = cooperative_astar.find_paths(&[
+    (agent_list[0].0, &goal_cartographer_list[0]),
+    (agent_list[1].0, &goal_cartographer_list[1]),
+  ]);
+  assert!(path_option_list[0].is_some());
+  assert_eq!(path_option_list[1], None);
+  let conflict_based_search = ConflictBasedSearch::default();
+  let ConflictBasedSearchOutcome::Solved {
+    path_list,
+    ..
+  } = conflict_based_search.search(&grid_cartographer, &agent_list)
+  else {
+    panic!("not solved");
+  };
+  assert_eq!(ConflictBasedSearch::get_first_conflict(&path_list), None);
+  assert_eq!(path_list[0].last(), Some(&agent_list[0].1));
+  assert_eq!(path_list[1].last(), Some(&agent_list[1].1));
+  // One of the agents steps into the passing bay
+  assert!(path_list.iter().flatten().any(|node| node.y == 1.0));
+}
@@ -0,0 +1,211 @@
+// =============================================================================
+//! - Flow field integration tests
+//! - Compares the costs against A* searches and against recomputed fields
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GridCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::ai::flowfield::FlowField;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{
+  RANDOM_SEED, is_in_bounds, make_blocked_set, make_free_node, next_random,
+};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+const AGENT_COUNT: usize = 200;
+const CHANGE_COUNT: usize = 20;
+const CORRIDOR_LENGTH: usize = 8;
+const GOAL_COUNT: usize = 3;
+const MAP_SIZE: usize = 32;
+const OBSTACLE_PERCENT: u64 = 20;
+const TOLERANCE: f64 = 0.001;
+
+fn make_grid_cartographer(
+  goal_node: Point2DD,
+  blocked_set: &RefCell<HashSet<Point2DD>>,
+) -> GridCartographer<Point2DD, impl Fn(&Point2DD) -> bool> {
+  GridCartographer {
+    goal_node,
+    is_space_available_fn: move |node: &Point2DD| {
+      is_in_bounds(MAP_SIZE, node) && !blocked_set.borrow().contains(node)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  }
+}
+
+fn assert_same_costs(
+  flow_field: &FlowField<Point2DD>,
+  expected_flow_field: &FlowField<Point2DD>,
+) {
+  assert_eq!(
+    flow_field.node_to_cost_map.len(),
+    expected_flow_field.node_to_cost_map.len()
+  );
+  for (node, expected_cost) in expected_flow_field.node_to_cost_map.iter() {
+    let cost = flow_field.get_cost(node).unwrap();
+    assert!((cost - expected_cost).abs() < TOLERANCE);
+  }
+}
+
+#[test]
+fn test_ai_flowfield_agents() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set =
+    RefCell::new(make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT));
+  let goal_nodes: Vec<Point2DD> = (0..GOAL_COUNT)
+    .map(|_| make_free_node(&mut seed, MAP_SIZE, &blocked_set.borrow()))
+    .collect();
+  // The goal is outside of the grid so that there are no shortcuts to it
+  let flow_cartographer =
+    make_grid_cartographer(Point2DD::new(-2.0, -2.0), &blocked_set);
+  let mut flow_field = FlowField::default();
+  flow_field.reset(&goal_nodes);
+  flow_field.compute(&flow_cartographer);
+  let mut reached_count = 0;
+  for _ in 0..AGENT_COUNT {
+    let agent_node = make_free_node(&mut seed, MAP_SIZE, &blocked_set.borrow());
+    let expected_cost_option = goal_nodes
+      .iter()
+      .filter_map(|goal_node| {
+        let grid_cartographer =
+          make_grid_cartographer(*goal_node, &blocked_set);
+        let mut astar = AStar::<Point2DD>::default();
+        match astar.search(
+          agent_node,
+          &grid_cartographer,
+          &SearchBudget::default(),
+        ) {
+          SearchOutcome::GoalFound {
+            cost,
+            ..
+          } => Some(cost),
+          _ => None,
+        }
+      })
+      .reduce(f64::min);
+    let cost_option = flow_field.get_cost(&agent_node);
+    assert_eq!(cost_option.is_some(), expected_cost_option.is_some());
+    let (Some(cost), Some(expected_cost)) = (cost_option, expected_cost_option)
+    else {
+      continue;
+    };
+    assert!((cost - expected_cost).abs() < TOLERANCE);
+    let mut node = agent_node;
+    let mut path_cost = 0.0;
+    while let Some(next_node) = flow_field.get_next_node(&node) {
+      path_cost +=
+        flow_cartographer.get_cost_to_adjacent_node(&node, &next_node);
+      node = next_node;
+    }
+    assert!(goal_nodes.contains(&node));
+    assert!((path_cost - expected_cost).abs() < TOLERANCE);
+    reached_count += 1;
+  }
+  assert_ne!(reached_count, 0);
+}
+
+#[test]
+fn test_ai_flowfield_corridor_blocked() {
+  // A corridor one cell high with a goal at each end
+  let goal_nodes = [
+    Point2DD::new(0.0, 0.0),
+    Point2DD::new(7.0, 0.0),
+  ];
+  let agent_node = Point2DD::new(2.0, 0.0);
+  let blocked_set: RefCell<HashSet<Point2DD>> = RefCell::new(HashSet::new());
+  let corridor_cartographer = GridCartographer {
+    goal_node: Point2DD::new(-2.0, -2.0),
+    is_space_available_fn: |node: &Point2DD| {
+      node.y == 0.0
+        && node.x >= 0.0
+        && node.x < CORRIDOR_LENGTH as f64
+        && !blocked_set.borrow().contains(node)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut flow_field = FlowField::default();
+  flow_field.reset(&goal_nodes);
+  flow_field.compute(&corridor_cartographer);
+  assert_eq!(
+    flow_field.get_path(&agent_node),
+    VecDeque::from([
+      Point2DD::new(1.0, 0.0),
+      Point2DD::new(0.0, 0.0),
+    ])
+  );
+  // Blocks the way to the nearer goal
+  let changed_node = Point2DD::new(1.0, 0.0);
+  blocked_set.borrow_mut().insert(changed_node);
+  flow_field.invalidate(&corridor_cartographer, &[changed_node]);
+  assert_eq!(flow_field.get_cost(&agent_node), Some(5.0));
+  assert_eq!(
+    flow_field.get_path(&agent_node),
+    VecDeque::from([
+      Point2DD::new(3.0, 0.0),
+      Point2DD::new(4.0, 0.0),
+      Point2DD::new(5.0, 0.0),
+      Point2DD::new(6.0, 0.0),
+      Point2DD::new(7.0, 0.0),
+    ])
+  );
+}
+
+#[test]
+fn test_ai_flowfield_invalidate() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set =
+    RefCell::new(make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT));
+  let goal_nodes: Vec<Point2DD> = (0..GOAL_COUNT)
+    .map(|_| make_free_node(&mut seed, MAP_SIZE, &blocked_set.borrow()))
+    .collect();
+  let flow_cartographer =
+    make_grid_cartographer(Point2DD::new(-2.0, -2.0), &blocked_set);
+  let mut flow_field = FlowField::default();
+  flow_field.reset(&goal_nodes);
+  flow_field.compute(&flow_cartographer);
+  for _ in 0..CHANGE_COUNT {
+    // Toggles a two by two region of cells
+    let corner_node = Point2DD::new(
+      (next_random(&mut seed) % MAP_SIZE as u64) as f64,
+      (next_random(&mut seed) % MAP_SIZE as u64) as f64,
+    );
+    let changed_nodes: Vec<Point2DD> = [
+      (0.0, 0.0),
+      (1.0, 0.0),
+      (0.0, 1.0),
+      (1.0, 1.0),
+    ]
+    .iter()
+    .map(|(x, y)| Point2DD::new(corner_node.x + x, corner_node.y + y))
+    .filter(|node| is_in_bounds(MAP_SIZE, node) && !goal_nodes.contains(node))
+    .collect();
+    for changed_node in changed_nodes.iter() {
+      let mut blocked_set = blocked_set.borrow_mut();
+      if !blocked_set.remove(changed_node) {
+        blocked_set.insert(*changed_node);
+      }
+    }
+    flow_field.invalidate(&flow_cartographer, &changed_nodes);
+    let mut expected_flow_field = FlowField::default();
+    expected_flow_field.reset(&goal_nodes);
+    expected_flow_field.compute(&flow_cartographer);
+    assert_same_costs(&flow_field, &expected_flow_field);
+  }
+}
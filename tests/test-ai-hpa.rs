@@ -0,0 +1,189 @@
+// =============================================================================
+//! - Hierarchical pathfinding integration tests
+//! - Compares the paths against an A* search of the whole grid
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GridCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::hpa::HierarchicalPathfinder;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, is_in_bounds, make_blocked_set, make_free_node};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+const CLUSTER_SIZE: usize = 8;
+const MAP_SIZE: usize = 48;
+/// The hierarchical paths are near optimal
+const MAX_COST_RATIO: f64 = 1.5;
+const OBSTACLE_PERCENT: u64 = 20;
+const SEARCH_COUNT: usize = 30;
+const WALL_CLUSTER_SIZE: usize = 4;
+const WALL_GOAL: Point2DD = Point2DD {
+  x: 7.0,
+  y: 0.0,
+};
+const WALL_MAP_SIZE: usize = 8;
+const WALL_START: Point2DD = Point2DD {
+  x: 0.0,
+  y: 0.0,
+};
+
+// A wall two cells thick across the corridor along y = 0
+const WALL_X_VALUES: [f64; 2] = [
+  3.0, 4.0,
+];
+
+fn make_path(points: &[(f64, f64)]) -> VecDeque<Point2DD> {
+  points.iter().map(|(x, y)| Point2DD::new(*x, *y)).collect()
+}
+
+fn search_grid(
+  start_node: Point2DD,
+  goal_node: Point2DD,
+  blocked_set: &HashSet<Point2DD>,
+) -> Option<f64> {
+  let grid_cartographer = GridCartographer {
+    goal_node,
+    is_space_available_fn: |node: &Point2DD| {
+      is_in_bounds(MAP_SIZE, node) && !blocked_set.contains(node)
+    },
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut astar = AStar::<Point2DD>::default();
+  match astar.search(start_node, &grid_cartographer, &SearchBudget::default()) {
+    SearchOutcome::GoalFound {
+      cost,
+      ..
+    } => Some(cost),
+    _ => None,
+  }
+}
+
+fn check_path(
+  hierarchical_pathfinder: &HierarchicalPathfinder<
+    Point2DD,
+    impl Fn(&Point2DD) -> bool,
+    impl Fn(f64, f64) -> Point2DD,
+  >,
+  start_node: Point2DD,
+  goal_node: Point2DD,
+  blocked_set: &HashSet<Point2DD>,
+) {
+  let expected_cost_option = search_grid(start_node, goal_node, blocked_set);
+  let path_option = hierarchical_pathfinder.find_path(start_node, goal_node);
+  assert_eq!(path_option.is_some(), expected_cost_option.is_some());
+  let (Some(path), Some(expected_cost)) = (path_option, expected_cost_option)
+  else {
+    return;
+  };
+  let mut previous_node = start_node;
+  let mut cost = 0.0;
+  for node in path.iter() {
+    assert!(previous_node.distance_to(node) < 1.5);
+    assert!(!blocked_set.contains(node));
+    cost += previous_node.distance_to(node);
+    previous_node = *node;
+  }
+  assert_eq!(previous_node, goal_node);
+  assert!(cost >= expected_cost - 0.001);
+  assert!(cost <= expected_cost * MAX_COST_RATIO + 0.001);
+}
+
+#[test]
+fn test_ai_hpa_random_map() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let hierarchical_pathfinder = HierarchicalPathfinder::new(
+    CLUSTER_SIZE,
+    MAP_SIZE,
+    MAP_SIZE,
+    1.0,
+    |node: &Point2DD| !blocked_set.contains(node),
+    Point2DD::new,
+  );
+  for _ in 0..SEARCH_COUNT {
+    let start_node = make_free_node(&mut seed, MAP_SIZE, &blocked_set);
+    let goal_node = make_free_node(&mut seed, MAP_SIZE, &blocked_set);
+    check_path(
+      &hierarchical_pathfinder,
+      start_node,
+      goal_node,
+      &blocked_set,
+    );
+  }
+}
+
+#[test]
+fn test_ai_hpa_wall_gap_moved() {
+  let gap_set: RefCell<HashSet<Point2DD>> = RefCell::new(HashSet::from([
+    Point2DD::new(3.0, 1.0),
+    Point2DD::new(4.0, 1.0),
+  ]));
+  let mut hierarchical_pathfinder = HierarchicalPathfinder::new(
+    WALL_CLUSTER_SIZE,
+    WALL_MAP_SIZE,
+    WALL_MAP_SIZE,
+    1.0,
+    |node: &Point2DD| {
+      if !is_in_bounds(WALL_MAP_SIZE, node) {
+        return false;
+      }
+      if WALL_X_VALUES.contains(&node.x) {
+        return gap_set.borrow().contains(node);
+      }
+      node.y == 0.0
+    },
+    Point2DD::new,
+  );
+  // Around the wall through the gap at y = 1, the only way across
+  assert_eq!(
+    hierarchical_pathfinder.find_path(WALL_START, WALL_GOAL),
+    Some(make_path(&[
+      (1.0, 0.0),
+      (2.0, 0.0),
+      (3.0, 1.0),
+      (4.0, 1.0),
+      (5.0, 0.0),
+      (6.0, 0.0),
+      (7.0, 0.0),
+    ]))
+  );
+  // Moves the gap to y = 0
+  let changed_nodes = [
+    Point2DD::new(3.0, 0.0),
+    Point2DD::new(3.0, 1.0),
+    Point2DD::new(4.0, 0.0),
+    Point2DD::new(4.0, 1.0),
+  ];
+  gap_set.replace(HashSet::from([
+    changed_nodes[0],
+    changed_nodes[2],
+  ]));
+  hierarchical_pathfinder.spaces_changed(&changed_nodes);
+  assert_eq!(
+    hierarchical_pathfinder.find_path(WALL_START, WALL_GOAL),
+    Some(make_path(&[
+      (1.0, 0.0),
+      (2.0, 0.0),
+      (3.0, 0.0),
+      (4.0, 0.0),
+      (5.0, 0.0),
+      (6.0, 0.0),
+      (7.0, 0.0),
+    ]))
+  );
+}
@@ -0,0 +1,250 @@
+// =============================================================================
+//! - K shortest paths integration tests
+//! - Compares Yen's algorithm to enumerating every loopless path of a small
+//!   random grid
+//! - Limits the candidate paths of a larger random grid
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::SearchBudget;
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::ai::kshortest::{KShortestPathfinder, RankedPath};
+use common::{RANDOM_SEED, next_random};
+use std::collections::HashSet;
+
+type Point = (i64, i64);
+
+const LARGE_MAP_SIZE: i64 = 16;
+const LARGE_PATH_COUNT: usize = 12;
+const MAP_SIZE: i64 = 4;
+const MAX_CANDIDATE_COUNT: usize = 20;
+const MAX_MOVE_POINTS: u32 = 9;
+const MAX_OVERLAP_RATIO: f64 = 0.5;
+const PATH_COUNT: usize = 12;
+const TRIAL_COUNT: usize = 5;
+
+/// Four-connected grid where entering a cell costs its move points
+pub struct MovePointCartographer {
+  pub goal_point: Point,
+  pub move_points_grid: Vec<Vec<u32>>,
+}
+
+impl Cartographer<Point, u32> for MovePointCartographer {
+  fn estimate_cost_to_goal(
+    &self,
+    node: &Point,
+  ) -> u32 {
+    ((node.0 - self.goal_point.0).abs() + (node.1 - self.goal_point.1).abs())
+      as u32
+  }
+
+  fn get_adjacent_nodes(
+    &self,
+    node: &Point,
+  ) -> Vec<Point> {
+    [
+      (node.0 + 1, node.1),
+      (node.0, node.1 + 1),
+      (node.0 - 1, node.1),
+      (node.0, node.1 - 1),
+    ]
+    .into_iter()
+    .filter(|point| {
+      let map_size: i64 = self.move_points_grid.len() as i64;
+      point.0 >= 0 && point.1 >= 0 && point.0 < map_size && point.1 < map_size
+    })
+    .collect()
+  }
+
+  fn get_cost_to_adjacent_node(
+    &self,
+    _from_node: &Point,
+    to_node: &Point,
+  ) -> u32 {
+    self.move_points_grid[to_node.1 as usize][to_node.0 as usize]
+  }
+
+  fn is_goal_node(
+    &self,
+    node: &Point,
+  ) -> bool {
+    *node == self.goal_point
+  }
+}
+
+/// The costs of every loopless path to the goal by depth-first search,
+/// independent of the A* implementation
+fn enumerate_path_costs(
+  cartographer: &MovePointCartographer,
+  node_list: &mut Vec<Point>,
+  cost: u32,
+  path_costs: &mut Vec<u32>,
+) {
+  let node: Point = *node_list.last().unwrap();
+  if cartographer.is_goal_node(&node) {
+    path_costs.push(cost);
+    return;
+  }
+  for adjacent_node in cartographer.get_adjacent_nodes(&node) {
+    if node_list.contains(&adjacent_node) {
+      continue;
+    }
+    node_list.push(adjacent_node);
+    enumerate_path_costs(
+      cartographer,
+      node_list,
+      cost + cartographer.get_cost_to_adjacent_node(&node, &adjacent_node),
+      path_costs,
+    );
+    node_list.pop();
+  }
+}
+
+fn make_cartographer(
+  map_size: i64,
+  seed: &mut u64,
+) -> MovePointCartographer {
+  MovePointCartographer {
+    goal_point: (map_size - 1, map_size - 1),
+    move_points_grid: (0..map_size)
+      .map(|_| {
+        (0..map_size)
+          .map(|_| 1 + (next_random(seed) % MAX_MOVE_POINTS as u64) as u32)
+          .collect()
+      })
+      .collect(),
+  }
+}
+
+fn get_path_cost(
+  cartographer: &MovePointCartographer,
+  node_list: &[Point],
+) -> u32 {
+  node_list
+    .windows(2)
+    .map(|step| cartographer.get_cost_to_adjacent_node(&step[0], &step[1]))
+    .sum()
+}
+
+#[test]
+fn test_ai_kshortest_random_grid() {
+  let mut seed = RANDOM_SEED;
+  let start_point: Point = (0, 0);
+  for _ in 0..TRIAL_COUNT {
+    let cartographer: MovePointCartographer =
+      make_cartographer(MAP_SIZE, &mut seed);
+    let mut path_costs: Vec<u32> = Vec::new();
+    enumerate_path_costs(
+      &cartographer,
+      &mut vec![start_point],
+      0,
+      &mut path_costs,
+    );
+    path_costs.sort();
+    let k_shortest_pathfinder = KShortestPathfinder {
+      max_candidate_count: usize::MAX,
+      max_overlap_ratio_option: None,
+      path_count: PATH_COUNT,
+      search_budget: SearchBudget::default(),
+    };
+    let ranked_paths: Vec<RankedPath<Point, u32>> =
+      k_shortest_pathfinder.find_paths(start_point, &cartographer);
+    assert_eq!(
+      ranked_paths
+        .iter()
+        .map(|ranked_path| ranked_path.cost)
+        .collect::<Vec<u32>>(),
+      path_costs[..PATH_COUNT]
+    );
+    let mut node_list_set: HashSet<&Vec<Point>> = HashSet::new();
+    for ranked_path in &ranked_paths {
+      let node_list: &Vec<Point> = &ranked_path.node_list;
+      assert!(node_list_set.insert(node_list));
+      assert_eq!(node_list.first(), Some(&start_point));
+      assert_eq!(node_list.last(), Some(&cartographer.goal_point));
+      assert_eq!(
+        node_list.iter().collect::<HashSet<&Point>>().len(),
+        node_list.len()
+      );
+      assert_eq!(get_path_cost(&cartographer, node_list), ranked_path.cost);
+    }
+    // The diverse paths are in ascending order and pairwise diverse
+    let diverse_pathfinder = KShortestPathfinder {
+      max_overlap_ratio_option: Some(MAX_OVERLAP_RATIO),
+      ..k_shortest_pathfinder
+    };
+    let diverse_paths: Vec<RankedPath<Point, u32>> =
+      diverse_pathfinder.find_paths(start_point, &cartographer);
+    assert_eq!(diverse_paths.first(), ranked_paths.first());
+    assert!(diverse_paths.len() > 1);
+    for (path_index, diverse_path) in diverse_paths.iter().enumerate() {
+      assert_eq!(
+        get_path_cost(&cartographer, &diverse_path.node_list),
+        diverse_path.cost
+      );
+      assert!(
+        diverse_pathfinder
+          .is_diverse(&diverse_path.node_list, &diverse_paths[..path_index])
+      );
+    }
+    assert!(
+      diverse_paths
+        .windows(2)
+        .all(|pair| pair[0].cost <= pair[1].cost)
+    );
+  }
+}
+
+#[test]
+fn test_ai_kshortest_max_candidate_count() {
+  let mut seed = RANDOM_SEED;
+  let start_point: Point = (0, 0);
+  let cartographer: MovePointCartographer =
+    make_cartographer(LARGE_MAP_SIZE, &mut seed);
+  let k_shortest_pathfinder = KShortestPathfinder {
+    max_candidate_count: usize::MAX,
+    max_overlap_ratio_option: None,
+    path_count: LARGE_PATH_COUNT,
+    search_budget: SearchBudget::default(),
+  };
+  let ranked_paths: Vec<RankedPath<Point, u32>> =
+    k_shortest_pathfinder.find_paths(start_point, &cartographer);
+  assert_eq!(ranked_paths.len(), LARGE_PATH_COUNT);
+  // Without diversity each candidate is a path so the limit truncates them
+  let limited_pathfinder = KShortestPathfinder {
+    max_candidate_count: LARGE_PATH_COUNT / 2,
+    ..k_shortest_pathfinder
+  };
+  assert_eq!(
+    limited_pathfinder.find_paths(start_point, &cartographer),
+    ranked_paths[..LARGE_PATH_COUNT / 2]
+  );
+  // Only two paths which share no steps can leave the corner so the search
+  // for a third would not end without the limit
+  let diverse_pathfinder = KShortestPathfinder {
+    max_candidate_count: MAX_CANDIDATE_COUNT,
+    max_overlap_ratio_option: Some(0.0),
+    path_count: 3,
+    ..k_shortest_pathfinder
+  };
+  let diverse_paths: Vec<RankedPath<Point, u32>> =
+    diverse_pathfinder.find_paths(start_point, &cartographer);
+  assert_eq!(diverse_paths.first(), ranked_paths.first());
+  assert!(diverse_paths.len() <= 2);
+  for (path_index, diverse_path) in diverse_paths.iter().enumerate() {
+    assert!(
+      diverse_pathfinder
+        .is_diverse(&diverse_path.node_list, &diverse_paths[..path_index])
+    );
+  }
+}
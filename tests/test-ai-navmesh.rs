@@ -0,0 +1,135 @@
+// =============================================================================
+//! - Navigation mesh integration tests
+//! - Loads a random level of square cells from the text format
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::navmesh::NavMesh;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, next_random};
+use std::fmt::Write;
+
+const MAP_SIZE: usize = 16;
+const OBSTACLE_PERCENT: u64 = 25;
+const PAIR_COUNT: usize = 20;
+/// The distance between the points checked along each path segment
+const SIGHT_STEP: f64 = 0.01;
+const TOLERANCE: f64 = 0.000_001;
+
+/// A square polygon for each free cell of a random grid
+fn make_nav_mesh_text(seed: &mut u64) -> String {
+  let mut text = String::from("# Random level\n");
+  for y in 0..=MAP_SIZE {
+    for x in 0..=MAP_SIZE {
+      writeln!(text, "v {x} {y}").unwrap();
+    }
+  }
+  let get_vertex_index = |x: usize, y: usize| y * (MAP_SIZE + 1) + x;
+  for y in 0..MAP_SIZE {
+    for x in 0..MAP_SIZE {
+      if next_random(seed) % 100 < OBSTACLE_PERCENT {
+        continue;
+      }
+      writeln!(
+        text,
+        "p {} {} {} {}",
+        get_vertex_index(x, y),
+        get_vertex_index(x + 1, y),
+        get_vertex_index(x + 1, y + 1),
+        get_vertex_index(x, y + 1),
+      )
+      .unwrap();
+    }
+  }
+  text
+}
+
+/// The center of a random cell which is in the mesh
+fn make_point(
+  seed: &mut u64,
+  nav_mesh: &NavMesh,
+) -> Point2DD {
+  loop {
+    let point = Point2DD::new(
+      (next_random(seed) % MAP_SIZE as u64) as f64 + 0.5,
+      (next_random(seed) % MAP_SIZE as u64) as f64 + 0.5,
+    );
+    if nav_mesh.find_polygon_index(&point).is_some() {
+      return point;
+    }
+  }
+}
+
+fn get_path_length(path: &[Point2DD]) -> f64 {
+  path
+    .iter()
+    .zip(path.iter().skip(1))
+    .map(|(point, next_point)| point.distance_to(next_point))
+    .sum()
+}
+
+#[test]
+fn test_ai_navmesh_random_level() {
+  let mut seed = RANDOM_SEED;
+  let nav_mesh = NavMesh::parse(&make_nav_mesh_text(&mut seed)).unwrap();
+  let mut found_count = 0;
+  for _ in 0..PAIR_COUNT {
+    let start_point = make_point(&mut seed, &nav_mesh);
+    let goal_point = make_point(&mut seed, &nav_mesh);
+    let Some(corridor) = nav_mesh.find_corridor(&start_point, &goal_point)
+    else {
+      assert_eq!(nav_mesh.find_path(&start_point, &goal_point), None);
+      continue;
+    };
+    found_count += 1;
+    let path = nav_mesh.find_path(&start_point, &goal_point).unwrap();
+    assert_eq!(path.first(), Some(&start_point));
+    assert_eq!(path.last(), Some(&goal_point));
+    // The funnel path is never longer than the path through the portal
+    // midpoints of the same corridor
+    let mut midpoint_path = vec![start_point];
+    for (from_polygon_index, to_polygon_index) in
+      corridor.iter().zip(corridor.iter().skip(1))
+    {
+      let portal = nav_mesh
+        .get_portal(*from_polygon_index, *to_polygon_index)
+        .unwrap();
+      midpoint_path.push(Point2DD::new(
+        (portal.left_point.x + portal.right_point.x) / 2.0,
+        (portal.left_point.y + portal.right_point.y) / 2.0,
+      ));
+    }
+    midpoint_path.push(goal_point);
+    let path_length = get_path_length(&path);
+    assert!(path_length <= get_path_length(&midpoint_path) + TOLERANCE);
+    assert!(path_length >= start_point.distance_to(&goal_point) - TOLERANCE);
+    // Every point along the path is inside of the mesh
+    for (point, next_point) in path.iter().zip(path.iter().skip(1)) {
+      assert_ne!(point, next_point);
+      let step_count =
+        (point.distance_to(next_point) / SIGHT_STEP).ceil() as usize;
+      for step_index in 0..=step_count {
+        let fraction = step_index as f64 / step_count as f64;
+        assert!(
+          nav_mesh
+            .find_polygon_index(&Point2DD::new(
+              point.x + (next_point.x - point.x) * fraction,
+              point.y + (next_point.y - point.y) * fraction,
+            ))
+            .is_some()
+        );
+      }
+    }
+  }
+  assert!(found_count > PAIR_COUNT / 2);
+}
@@ -0,0 +1,118 @@
+// =============================================================================
+//! - Path caching integration tests
+//! - Repeats the queries of a hovering mouse over a random tile map
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::pathcache::{CachingCartographer, PathCache};
+use com_croftsoft_core::ai::tilemap::TileMap;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{
+  RANDOM_SEED, make_passable_node, make_random_tile_map, toggle_passable,
+};
+
+const HOVER_COUNT: usize = 5;
+const MAP_SIZE: usize = 20;
+const OBSTACLE_PERCENT: u64 = 20;
+const PATH_CACHE_CAPACITY: usize = 4;
+const QUERY_COUNT: usize = 10;
+
+#[test]
+fn test_ai_pathcache_hover() {
+  let mut seed = RANDOM_SEED;
+  let tile_map: TileMap =
+    make_random_tile_map(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let start_node: Point2DD = make_passable_node(&mut seed, &tile_map);
+  let hover_nodes: Vec<Point2DD> = (0..HOVER_COUNT)
+    .map(|_| make_passable_node(&mut seed, &tile_map))
+    .collect();
+  let mut caching_cartographer = CachingCartographer::new(tile_map);
+  let mut path_cache = PathCache::<Point2DD>::new(PATH_CACHE_CAPACITY);
+  let mut astar = AStar::<Point2DD>::default();
+  for query_index in 0..QUERY_COUNT {
+    let goal_node: Point2DD = hover_nodes[query_index % HOVER_COUNT];
+    caching_cartographer.change_goal(|tile_map| {
+      tile_map.goal_node_option = Some(goal_node);
+    });
+    let search_outcome: SearchOutcome<Point2DD> = path_cache.search(
+      &mut astar,
+      start_node,
+      goal_node,
+      &caching_cartographer,
+      &SearchBudget::default(),
+    );
+    let expected_search_outcome: SearchOutcome<Point2DD> = AStar::default()
+      .search(
+        start_node,
+        caching_cartographer.get_cartographer(),
+        &SearchBudget::default(),
+      );
+    assert_eq!(search_outcome, expected_search_outcome);
+  }
+  // Cycling through more goals than the capacity evicts each before reuse
+  assert_eq!(path_cache.hit_count, 0);
+  assert_eq!(path_cache.len(), PATH_CACHE_CAPACITY);
+  // The searches toward the different goals share the cached costs
+  assert!(caching_cartographer.hit_count.get() > 0);
+  // The most recent goal is answered by the path cache
+  let last_goal_node: Point2DD = hover_nodes[(QUERY_COUNT - 1) % HOVER_COUNT];
+  let miss_count: usize = caching_cartographer.miss_count.get();
+  let cached_search_outcome: SearchOutcome<Point2DD> = path_cache.search(
+    &mut astar,
+    start_node,
+    last_goal_node,
+    &caching_cartographer,
+    &SearchBudget::default(),
+  );
+  assert_eq!(path_cache.hit_count, 1);
+  assert_eq!(caching_cartographer.miss_count.get(), miss_count);
+  let SearchOutcome::GoalFound {
+    path,
+    ..
+  } = cached_search_outcome
+  else {
+    panic!("no path to the hover goal");
+  };
+  // Blocks the cached path and invalidates the caches
+  let blocked_node: Point2DD = path[path.len() / 2];
+  assert_ne!(blocked_node, last_goal_node);
+  caching_cartographer.change_world(|tile_map| {
+    toggle_passable(tile_map, &blocked_node);
+  });
+  path_cache.clear();
+  let search_outcome: SearchOutcome<Point2DD> = path_cache.search(
+    &mut astar,
+    start_node,
+    last_goal_node,
+    &caching_cartographer,
+    &SearchBudget::default(),
+  );
+  if let SearchOutcome::GoalFound {
+    path,
+    ..
+  } = &search_outcome
+  {
+    assert!(!path.contains(&blocked_node));
+  }
+  assert_eq!(
+    search_outcome,
+    AStar::default().search(
+      start_node,
+      caching_cartographer.get_cartographer(),
+      &SearchBudget::default(),
+    )
+  );
+}
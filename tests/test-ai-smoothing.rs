@@ -0,0 +1,122 @@
+// =============================================================================
+//! - Path smoothing integration tests
+//! - Smooths the A* path around a wall on a grid
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GridCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::smoothing::{
+  DEFAULT_COLLINEAR_TOLERANCE, DEFAULT_CURVE_SEGMENT_COUNT, PathCurve,
+  PathSmoother,
+};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::collections::VecDeque;
+
+const GOAL: Point2DD = Point2DD {
+  x: 9.0,
+  y: 0.0,
+};
+const MAP_SIZE: f64 = 10.0;
+/// The distance between the points checked along a line of sight
+const SIGHT_STEP: f64 = 0.05;
+const SAMPLE_SPACING: f64 = 0.25;
+const START: Point2DD = Point2DD {
+  x: 0.0,
+  y: 0.0,
+};
+const TOLERANCE: f64 = 0.001;
+
+/// A wall at x = 5 from y = 0 to y = 6
+fn is_space_available(node: &Point2DD) -> bool {
+  node.x >= 0.0
+    && node.y >= 0.0
+    && node.x < MAP_SIZE
+    && node.y < MAP_SIZE
+    && !(node.x == 5.0 && node.y <= 6.0)
+}
+
+/// True if every point along the segment is in an available grid cell
+fn has_line_of_sight(
+  from_point: &Point2DD,
+  to_point: &Point2DD,
+) -> bool {
+  let step_count: usize =
+    (from_point.distance_to(to_point) / SIGHT_STEP).ceil() as usize;
+  (0..=step_count).all(|step_index| {
+    let fraction: f64 = step_index as f64 / step_count.max(1) as f64;
+    is_space_available(&Point2DD::new(
+      (from_point.x + (to_point.x - from_point.x) * fraction).round(),
+      (from_point.y + (to_point.y - from_point.y) * fraction).round(),
+    ))
+  })
+}
+
+fn find_path() -> VecDeque<Point2DD> {
+  let grid_cartographer = GridCartographer {
+    goal_node: GOAL,
+    is_space_available_fn: is_space_available,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    path,
+    ..
+  } = astar.search(START, &grid_cartographer, &SearchBudget::default())
+  else {
+    panic!("no path around the wall");
+  };
+  path
+}
+
+#[test]
+fn test_ai_smoothing_around_wall() {
+  let path: VecDeque<Point2DD> = find_path();
+  for curve_option in [
+    None,
+    Some(PathCurve::Bezier),
+    Some(PathCurve::CatmullRom),
+  ] {
+    let path_smoother = PathSmoother {
+      collinear_tolerance: DEFAULT_COLLINEAR_TOLERANCE,
+      curve_option,
+      curve_segment_count: DEFAULT_CURVE_SEGMENT_COUNT,
+      has_line_of_sight_fn: has_line_of_sight,
+      sample_spacing: SAMPLE_SPACING,
+    };
+    let waypoints: Vec<Point2DD> = path_smoother.pull_string(
+      &core::iter::once(START)
+        .chain(path.iter().copied())
+        .collect::<Vec<Point2DD>>(),
+    );
+    // String pulling keeps only the corners at the end of the wall
+    assert!(waypoints.len() < path.len());
+    let sample_list: Vec<Point2DD> = path_smoother.smooth(&START, &path);
+    assert_eq!(sample_list.first(), Some(&START));
+    assert_eq!(sample_list.last(), Some(&GOAL));
+    for (point, next_point) in
+      sample_list.iter().zip(sample_list.iter().skip(1))
+    {
+      assert!(point.distance_to(next_point) <= SAMPLE_SPACING + TOLERANCE);
+    }
+    if curve_option != Some(PathCurve::CatmullRom) {
+      // Without overshoot the smoothed path never enters the wall
+      for point in &sample_list {
+        assert!(is_space_available(&Point2DD::new(
+          point.x.round(),
+          point.y.round()
+        )));
+      }
+    }
+  }
+}
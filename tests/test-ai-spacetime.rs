@@ -0,0 +1,155 @@
+// =============================================================================
+//! - Space-time A* integration tests
+//! - Plans many agents on a random map and checks for collisions
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, GridCartographer, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::astar::traits::Cartographer;
+use com_croftsoft_core::ai::spacetime::CooperativeAStar;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, is_in_bounds, make_blocked_set, make_unused_node};
+use std::collections::HashSet;
+
+const AGENT_COUNT: usize = 12;
+const MAP_SIZE: usize = 16;
+const OBSTACLE_PERCENT: u64 = 15;
+const TOLERANCE: f64 = 0.001;
+
+/// The node occupied at the time step, including after arriving at the goal
+fn get_node_at(
+  path: &[Point2DD],
+  time_step: usize,
+) -> Point2DD {
+  path[time_step.min(path.len() - 1)]
+}
+
+fn assert_no_collisions(path_list: &[Vec<Point2DD>]) {
+  let time_step_count: usize =
+    path_list.iter().map(|path| path.len()).max().unwrap_or(0);
+  for time_step in 0..time_step_count {
+    for (index, path) in path_list.iter().enumerate() {
+      for other_path in path_list.iter().skip(index + 1) {
+        let node = get_node_at(path, time_step);
+        let other_node = get_node_at(other_path, time_step);
+        assert_ne!(node, other_node);
+        let next_node = get_node_at(path, time_step + 1);
+        let other_next_node = get_node_at(other_path, time_step + 1);
+        assert!(!(node == other_next_node && next_node == other_node));
+      }
+    }
+  }
+}
+
+#[test]
+fn test_ai_spacetime_cooperative_agents() {
+  let mut seed = RANDOM_SEED;
+  let blocked_set = make_blocked_set(&mut seed, MAP_SIZE, OBSTACLE_PERCENT);
+  let mut used_set = HashSet::new();
+  let start_and_goal_list: Vec<(Point2DD, Point2DD)> = (0..AGENT_COUNT)
+    .map(|_| {
+      (
+        make_unused_node(&mut seed, MAP_SIZE, &blocked_set, &mut used_set),
+        make_unused_node(&mut seed, MAP_SIZE, &blocked_set, &mut used_set),
+      )
+    })
+    .collect();
+  let grid_cartographer_list: Vec<GridCartographer<Point2DD, _, _>> =
+    start_and_goal_list
+      .iter()
+      .map(|(_, goal_node)| GridCartographer {
+        goal_node: *goal_node,
+        is_space_available_fn: |node: &Point2DD| {
+          is_in_bounds(MAP_SIZE, node) && !blocked_set.contains(node)
+        },
+        make_node_fn: Point2DD::new,
+        step_size: 1.0,
+      })
+      .collect();
+  let agents: Vec<(Point2DD, &dyn Cartographer<Point2DD>)> =
+    start_and_goal_list
+      .iter()
+      .zip(grid_cartographer_list.iter())
+      .map(|((start_node, _), grid_cartographer)| {
+        (
+          *start_node,
+          grid_cartographer as &dyn Cartographer<Point2DD>,
+        )
+      })
+      .collect();
+  let mut cooperative_astar = CooperativeAStar::default();
+  let path_option_list = cooperative_astar.find_paths(&agents);
+  let mut path_list: Vec<Vec<Point2DD>> = Vec::new();
+  for ((start_node, cartographer), path_option) in
+    agents.iter().zip(path_option_list)
+  {
+    let mut astar = AStar::<Point2DD>::default();
+    let search_outcome =
+      astar.search(*start_node, *cartographer, &SearchBudget::default());
+    let Some(path) = path_option else {
+      path_list.push(vec![*start_node]);
+      continue;
+    };
+    let SearchOutcome::GoalFound {
+      cost: solo_cost,
+      ..
+    } = search_outcome
+    else {
+      panic!("a space-time path was found without a spatial path");
+    };
+    assert_eq!(path[0], *start_node);
+    assert!(cartographer.is_goal_node(path.last().unwrap()));
+    let mut moving_cost = 0.0;
+    for (node, next_node) in path.iter().zip(path.iter().skip(1)) {
+      assert!(node == next_node || node.distance_to(next_node) < 1.5);
+      assert!(!blocked_set.contains(next_node));
+      moving_cost += node.distance_to(next_node);
+    }
+    // Avoiding the other agents never makes a path shorter
+    assert!(moving_cost >= solo_cost - TOLERANCE);
+    path_list.push(path);
+  }
+  assert_no_collisions(&path_list);
+  assert!(path_list.iter().filter(|path| path.len() > 1).count() > 1);
+}
+
+#[test]
+fn test_ai_spacetime_head_on_corridor() {
+  // Two agents meet head on in a corridor with a single passing bay
+  let is_space_available_fn = |node: &Point2DD| {
+    (node.y == 0.0 && node.x >= 0.0 && node.x <= 6.0)
+      || (node.x == 3.0 && node.y == 1.0)
+  };
+  let make_cartographer = |goal_node: Point2DD| GridCartographer {
+    goal_node,
+    is_space_available_fn,
+    make_node_fn: Point2DD::new,
+    step_size: 1.0,
+  };
+  let east_cartographer = make_cartographer(Point2DD::new(6.0, 0.0));
+  let west_cartographer = make_cartographer(Point2DD::new(0.0, 0.0));
+  let mut cooperative_astar = CooperativeAStar::default();
+  let path_option_list = cooperative_astar.find_paths(&[
+    (Point2DD::new(0.0, 0.0), &east_cartographer),
+    (Point2DD::new(6.0, 0.0), &west_cartographer),
+  ]);
+  let path_list: Vec<Vec<Point2DD>> =
+    path_option_list.into_iter().map(Option::unwrap).collect();
+  assert_no_collisions(&path_list);
+  // The first agent has priority and goes straight through
+  assert_eq!(path_list[0].len(), 7);
+  // The second agent steps into the passing bay
+  assert!(path_list[1].contains(&Point2DD::new(3.0, 1.0)));
+}
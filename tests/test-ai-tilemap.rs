@@ -0,0 +1,171 @@
+// =============================================================================
+//! - Tile map integration tests
+//! - Checks the A* path lengths against scenarios for a random map
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-18
+//! - Updated: 2026-10-18
+//!
+//! [`CroftSoft Inc`]: https://www.CroftSoft.com/
+//! [`David Wallace Croft`]: https://www.CroftSoft.com/people/david/
+// =============================================================================
+
+mod common;
+
+use com_croftsoft_core::ai::astar::structures::{
+  AStar, SearchBudget, SearchOutcome,
+};
+use com_croftsoft_core::ai::tilemap::{
+  TileMap, TileMapConnectivity, TileMapScenario,
+};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use common::{RANDOM_SEED, next_random};
+use core::f64::consts::SQRT_2;
+use std::fmt::Write;
+
+const MAP_SIZE: usize = 24;
+const OBSTACLE_PERCENT: u64 = 25;
+const SCENARIO_COUNT: usize = 20;
+/// The precision of the optimal lengths in the scenario files
+const TOLERANCE: f64 = 0.000_01;
+
+fn make_map_text(seed: &mut u64) -> String {
+  let mut text =
+    format!("type octile\nheight {MAP_SIZE}\nwidth {MAP_SIZE}\nmap\n");
+  for _ in 0..MAP_SIZE {
+    for _ in 0..MAP_SIZE {
+      let tile: char = match next_random(seed) % 100 {
+        value if value < OBSTACLE_PERCENT / 2 => '@',
+        value if value < OBSTACLE_PERCENT => 'T',
+        _ => '.',
+      };
+      text.push(tile);
+    }
+    text.push('\n');
+  }
+  text
+}
+
+/// The shortest path lengths from the start by relaxing the edges until
+/// nothing changes, independent of the A* implementation
+fn make_length_grid(
+  passable_grid: &[Vec<bool>],
+  start: (usize, usize),
+) -> Vec<Vec<f64>> {
+  let is_passable = |x: i64, y: i64| {
+    x >= 0
+      && y >= 0
+      && x < MAP_SIZE as i64
+      && y < MAP_SIZE as i64
+      && passable_grid[y as usize][x as usize]
+  };
+  let mut length_grid = vec![vec![f64::INFINITY; MAP_SIZE]; MAP_SIZE];
+  length_grid[start.1][start.0] = 0.0;
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for y in 0..MAP_SIZE as i64 {
+      for x in 0..MAP_SIZE as i64 {
+        if !is_passable(x, y) {
+          continue;
+        }
+        for delta_y in -1..=1 {
+          for delta_x in -1..=1 {
+            let (from_x, from_y) = (x + delta_x, y + delta_y);
+            if !is_passable(from_x, from_y)
+              || !is_passable(from_x, y)
+              || !is_passable(x, from_y)
+            {
+              continue;
+            }
+            let step = if delta_x != 0 && delta_y != 0 {
+              SQRT_2
+            } else {
+              1.0
+            };
+            let length = length_grid[from_y as usize][from_x as usize] + step;
+            if length < length_grid[y as usize][x as usize] {
+              length_grid[y as usize][x as usize] = length;
+              changed = true;
+            }
+          }
+        }
+      }
+    }
+  }
+  length_grid
+}
+
+#[test]
+fn test_ai_tilemap_ascii_cartographer() {
+  // Finds its way around a wall without cutting the corners at its ends
+  let tile_map = TileMap::parse_ascii(
+    "
+.....
+..#..
+.S#G.
+..#..
+.....
+",
+    TileMapConnectivity::Eight,
+  )
+  .unwrap();
+  let mut astar = AStar::<Point2DD>::default();
+  let SearchOutcome::GoalFound {
+    cost,
+    path,
+  } = astar.search(
+    tile_map.start_node_option.unwrap(),
+    &tile_map,
+    &SearchBudget::default(),
+  )
+  else {
+    panic!("no path around the wall");
+  };
+  assert_eq!(cost, 6.0);
+  assert_eq!(path.back(), tile_map.goal_node_option.as_ref());
+  assert!(path.iter().all(|node| tile_map.is_passable(node)));
+}
+
+#[test]
+fn test_ai_tilemap_random_scenarios() {
+  let mut seed = RANDOM_SEED;
+  let map_text: String = make_map_text(&mut seed);
+  let tile_map =
+    TileMap::parse_map(&map_text, TileMapConnectivity::Eight).unwrap();
+  let passable_grid: Vec<Vec<bool>> = map_text
+    .lines()
+    .skip(4)
+    .map(|line| line.chars().map(|tile| tile == '.').collect())
+    .collect();
+  let mut scenario_text = String::from("version 1\n");
+  let mut written_count: usize = 0;
+  while written_count < SCENARIO_COUNT {
+    let start_x = next_random(&mut seed) as usize % MAP_SIZE;
+    let start_y = next_random(&mut seed) as usize % MAP_SIZE;
+    let goal_x = next_random(&mut seed) as usize % MAP_SIZE;
+    let goal_y = next_random(&mut seed) as usize % MAP_SIZE;
+    if !passable_grid[start_y][start_x] || !passable_grid[goal_y][goal_x] {
+      continue;
+    }
+    let length: f64 =
+      make_length_grid(&passable_grid, (start_x, start_y))[goal_y][goal_x];
+    if length.is_infinite() {
+      continue;
+    }
+    writeln!(
+      scenario_text,
+      "{}\trandom.map\t{MAP_SIZE}\t{MAP_SIZE}\t{start_x}\t{start_y}\t\
+        {goal_x}\t{goal_y}\t{length:.8}",
+      written_count / 10,
+    )
+    .unwrap();
+    written_count += 1;
+  }
+  let scenario_list: Vec<TileMapScenario> =
+    TileMapScenario::parse(&scenario_text).unwrap();
+  assert_eq!(scenario_list.len(), SCENARIO_COUNT);
+  assert_eq!(tile_map.check_scenarios(&scenario_list, TOLERANCE), []);
+}